@@ -212,6 +212,9 @@ fn to_rust_type_uncached(full_ty: &GodotTy, ctx: &mut Context) -> RustTy {
                 elem_class: elem_ty.to_string(),
             }
         };
+    } else if ty.starts_with("typeddictionary::") {
+        // Typed dictionaries in the engine API (Godot 4.4+) are exposed as untyped ones for now.
+        return RustTy::BuiltinIdent(ident("Dictionary"));
     }
 
     // Note: do not check if it's a known engine class, because that will not work in minimal mode (since not all classes are stored)
@@ -104,6 +104,39 @@ fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
             unimplemented!()
         }
 
+        /// Called whenever Godot retrieves the list of properties, e.g. to display them in the inspector.
+        ///
+        /// The returned properties are added to those registered with `#[var]` and `#[export]`. Their values are usually provided
+        /// through [`get_property()`](Self::get_property) and [`set_property()`](Self::set_property).
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_get_property_list`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get-property-list).
+        fn get_property_list(&mut self) -> Vec<crate::builtin::meta::PropertyInfo> {
+            unimplemented!()
+        }
+
+        /// Called by Godot to determine the value a `property` reverts to, e.g. when clicking the revert arrow in the inspector.
+        ///
+        /// Should return `Some(value)` if the property has a custom revert value, or `None` if it should be handled normally.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_property_get_revert`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-property-get-revert).
+        /// * [`Object::_property_can_revert`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-property-can-revert).
+        fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+            unimplemented!()
+        }
+
+        /// Called by Godot for each property of the object, allowing to modify how it is presented (hint, usage flags, ...).
+        ///
+        /// This can be used to conditionally hide or disable properties in the inspector, based on the object's state.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_validate_property`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-validate-property).
+        #[cfg(since_api = "4.2")]
+        fn validate_property(&self, property: &mut crate::builtin::meta::PropertyInfo) {
+            unimplemented!()
+        }
+
     }
 }
 
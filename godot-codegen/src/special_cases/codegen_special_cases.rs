@@ -135,6 +135,8 @@ const SELECTED_CLASSES: &[&str] = &[
     "CollisionObject2D",
     "CollisionShape2D",
     "Control",
+    "DisplayServer",
+    "EditorInspectorPlugin",
     "EditorPlugin",
     "Engine",
     "FileAccess",
@@ -149,6 +151,8 @@ const SELECTED_CLASSES: &[&str] = &[
     "MainLoop",
     "Marker2D",
     "Mesh",
+    "MultiplayerAPI",
+    "MultiplayerPeer",
     "Node",
     "Node2D",
     "Node3D",
@@ -156,6 +160,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "Object",
     "OS",
     "PackedScene",
+    "PacketPeer",
     "PathFollow2D",
     "PhysicsBody2D",
     "PrimitiveMesh",
@@ -163,6 +168,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "RenderingServer",
     "Resource",
     "ResourceFormatLoader",
+    "ResourceFormatSaver",
     "ResourceLoader",
     "ResourceSaver",
     "RigidBody2D",
@@ -14,8 +14,8 @@ use std::marker::PhantomData;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 use super::meta::{
-    ConvertError, FromGodot, FromGodotError, FromVariantError, GodotConvert, GodotFfiVariant,
-    GodotType, ToGodot,
+    ConvertError, ElementArg, FromGodot, FromGodotError, FromVariantError, GodotConvert,
+    GodotFfiVariant, GodotType, ToGodot,
 };
 
 /// Godot's `Array` type.
@@ -234,6 +234,21 @@ impl<T: GodotType> Array<T> {
         ptr
     }
 
+    /// Returns the element at the given index as a `Variant`, without converting it to `T`.
+    ///
+    /// Allows fallible conversions of the elements, e.g. to a narrower type than `T`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub(crate) fn get_variant(&self, index: usize) -> Variant {
+        let ptr = self.ptr(index);
+
+        // SAFETY: `ptr()` panics for out-of-bounds indices, so `ptr` points to a live variant. It is cloned immediately.
+        let variant = unsafe { Variant::borrow_var_sys(ptr) };
+        variant.clone()
+    }
+
     /// Returns a pointer to the element at the given index, or null if out of bounds.
     fn ptr_or_null(&self, index: usize) -> sys::GDExtensionConstVariantPtr {
         // SAFETY: array_operator_index_const returns null for invalid indexes.
@@ -913,6 +928,8 @@ impl<T: GodotType> GodotType for Array<T> {
     }
 }
 
+impl<T: GodotType, A: ToGodot<Via = Array<T>>> ElementArg<Array<T>> for A {}
+
 impl<T: GodotType> GodotFfiVariant for Array<T> {
     fn ffi_to_variant(&self) -> Variant {
         unsafe {
@@ -1160,7 +1177,16 @@ pub(crate) struct TypeInfo {
 }
 
 impl TypeInfo {
-    fn of<T: GodotType>() -> Self {
+    /// Type info as reported by the engine, e.g. for the keys or values of a typed dictionary.
+    #[cfg(since_api = "4.4")]
+    pub(crate) fn new(variant_type: VariantType, class_name: StringName) -> Self {
+        Self {
+            variant_type,
+            class_name,
+        }
+    }
+
+    pub(crate) fn of<T: GodotType>() -> Self {
         Self {
             variant_type: <T::Via as GodotType>::Ffi::variant_type(),
             class_name: T::Via::class_name().to_string_name(),
@@ -94,16 +94,94 @@ impl Callable {
         F: 'static + Send + Sync + FnMut(&[&Variant]) -> Result<Variant, ()>,
         S: Into<crate::builtin::GString>,
     {
-        let userdata = CallableUserdata {
-            inner: FnWrapper {
-                rust_function,
-                name: name.into(),
-            },
-        };
+        Self::from_fn_wrapper(
+            FnWrapper::new(rust_function, name.into()),
+            Some(rust_callable_call_fn::<F>),
+        )
+    }
+
+    /// Create a callable from a Rust function or closure, which may only be invoked on the current thread.
+    ///
+    /// Unlike [`from_fn()`][Self::from_fn], the closure does not need to be `Send + Sync`, so it can capture `Gd<T>` pointers and
+    /// other thread-bound values. Invoking the callable from a different thread (e.g. through a deferred call on a worker thread)
+    /// fails with an error, without calling the closure. If the last reference to the callable is released on a different thread,
+    /// the closure is leaked instead of dropped, and an error is printed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let node = Node::new_alloc();
+    /// let callable = Callable::from_local_fn("get_name", move |_args: &[&Variant]| {
+    ///     Ok(node.get_name().to_variant())
+    /// });
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn from_local_fn<F, S>(name: S, rust_function: F) -> Self
+    where
+        F: 'static + FnMut(&[&Variant]) -> Result<Variant, ()>,
+        S: Into<crate::builtin::GString>,
+    {
+        Self::from_fn_wrapper(
+            FnWrapper::new(ThreadConfined::new(rust_function), name.into()),
+            Some(rust_callable_call_local_fn::<F>),
+        )
+    }
+
+    /// Create a callable from a Rust function or closure with statically typed parameters and return type.
+    ///
+    /// Arguments are converted with [`FromGodot`][crate::builtin::meta::FromGodot] and the return value with
+    /// [`ToGodot`]. If the number of arguments does not match or an argument cannot be converted, the closure is not invoked;
+    /// instead, the call fails with a [`CallError`][crate::builtin::meta::CallError], which is printed to Godot's console.
+    ///
+    /// `name` is used for the string representation of the closure and in error messages.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let callable = Callable::from_typed_fn("scale", |v: Vector2, factor: f32| -> Vector2 {
+    ///     v * factor
+    /// });
+    ///
+    /// let result = callable.callv(varray![Vector2::new(1.0, 2.0), 2.0]);
+    /// assert_eq!(result, Vector2::new(2.0, 4.0).to_variant());
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn from_typed_fn<F, Ps, S>(name: S, rust_function: F) -> Self
+    where
+        F: TypedCallableFn<Ps> + Send + Sync,
+        S: Into<crate::builtin::GString>,
+    {
+        Self::from_fn_wrapper(
+            FnWrapper::new(rust_function, name.into()),
+            Some(rust_callable_call_typed::<F, Ps>),
+        )
+    }
+
+    /// Like [`from_typed_fn()`][Self::from_typed_fn], but the closure does not need to be `Send + Sync`.
+    ///
+    /// The callable may only be invoked on the current thread, see [`from_local_fn()`][Self::from_local_fn].
+    #[cfg(since_api = "4.2")]
+    pub fn from_local_typed_fn<F, Ps, S>(name: S, rust_function: F) -> Self
+    where
+        F: TypedCallableFn<Ps>,
+        S: Into<crate::builtin::GString>,
+    {
+        Self::from_fn_wrapper(
+            FnWrapper::new(ThreadConfined::new(rust_function), name.into()),
+            Some(rust_callable_call_local_typed::<F, Ps>),
+        )
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn from_fn_wrapper<F>(
+        wrapper: FnWrapper<F>,
+        call_func: sys::GDExtensionCallableCustomCall,
+    ) -> Self {
+        let userdata = CallableUserdata { inner: wrapper };
 
         let info = sys::GDExtensionCallableCustomInfo {
             callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
-            call_func: Some(rust_callable_call_fn::<F>),
+            call_func,
             free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
             to_string_func: Some(rust_callable_to_string_named::<F>),
             ..Self::default_callable_custom_info()
@@ -337,13 +415,16 @@ impl fmt::Display for Callable {
 use custom_callable::*;
 
 #[cfg(since_api = "4.2")]
-pub use custom_callable::RustCallable;
+pub use custom_callable::{RustCallable, TypedCallableFn};
 
 #[cfg(since_api = "4.2")]
 mod custom_callable {
     use super::*;
+    use crate::builtin::meta::{CallContext, CallError, ParamTuple};
     use crate::builtin::GString;
+    use crate::private::ThreadConfined;
     use std::hash::Hash;
+    use std::panic::AssertUnwindSafe;
 
     pub struct CallableUserdata<T> {
         pub inner: T,
@@ -363,6 +444,64 @@ mod custom_callable {
         pub(crate) name: GString,
     }
 
+    impl<F> FnWrapper<F> {
+        pub(crate) fn new(rust_function: F, name: GString) -> Self {
+            Self {
+                rust_function,
+                name,
+            }
+        }
+    }
+
+    /// Rust function or closure with statically typed parameters, which can be turned into a [`Callable`].
+    ///
+    /// Implemented for all `FnMut(P0, P1, ...) -> R` with up to 10 parameters, where each parameter implements
+    /// [`FromGodot`][crate::builtin::meta::FromGodot] and `R` implements [`ToGodot`]. The type parameter `Params` is the tuple
+    /// of parameter types; it is inferred and only needed to disambiguate between arities.
+    ///
+    /// See [`Callable::from_typed_fn()`] and [`Callable::from_local_typed_fn()`].
+    pub trait TypedCallableFn<Params>: 'static {
+        #[doc(hidden)]
+        fn invoke_typed(
+            &mut self,
+            args: &[&Variant],
+            call_ctx: &CallContext,
+        ) -> Result<Variant, CallError>;
+    }
+
+    macro_rules! impl_typed_callable_fn {
+        ($( $Pn:ident ),*) => {
+            impl<F, R, $($Pn,)*> TypedCallableFn<($($Pn,)*)> for F
+            where
+                F: 'static + FnMut($($Pn),*) -> R,
+                R: ToGodot,
+                ($($Pn,)*): ParamTuple,
+            {
+                #[allow(non_snake_case)]
+                fn invoke_typed(
+                    &mut self,
+                    args: &[&Variant],
+                    call_ctx: &CallContext,
+                ) -> Result<Variant, CallError> {
+                    let ($($Pn,)*) = <($($Pn,)*)>::from_variant_args(args, call_ctx)?;
+                    Ok(self($($Pn),*).to_variant())
+                }
+            }
+        };
+    }
+
+    impl_typed_callable_fn!();
+    impl_typed_callable_fn!(P0);
+    impl_typed_callable_fn!(P0, P1);
+    impl_typed_callable_fn!(P0, P1, P2);
+    impl_typed_callable_fn!(P0, P1, P2, P3);
+    impl_typed_callable_fn!(P0, P1, P2, P3, P4);
+    impl_typed_callable_fn!(P0, P1, P2, P3, P4, P5);
+    impl_typed_callable_fn!(P0, P1, P2, P3, P4, P5, P6);
+    impl_typed_callable_fn!(P0, P1, P2, P3, P4, P5, P6, P7);
+    impl_typed_callable_fn!(P0, P1, P2, P3, P4, P5, P6, P7, P8);
+    impl_typed_callable_fn!(P0, P1, P2, P3, P4, P5, P6, P7, P8, P9);
+
     /// Represents a custom callable object defined in Rust.
     ///
     /// This trait has a single method, `invoke`, which is called upon invocation.
@@ -411,6 +550,98 @@ mod custom_callable {
         crate::builtin::meta::varcall_return_checked(result, r_return, r_error);
     }
 
+    pub unsafe extern "C" fn rust_callable_call_local_fn<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: FnMut(&[&Variant]) -> Result<Variant, ()>,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        let w: &mut FnWrapper<ThreadConfined<F>> =
+            CallableUserdata::inner_from_raw(callable_userdata);
+
+        let Some(rust_function) = w.rust_function.get_mut() else {
+            report_wrong_thread(&w.name, r_error);
+            return;
+        };
+
+        let result = rust_function(arg_refs);
+        crate::builtin::meta::varcall_return_checked(result, r_return, r_error);
+    }
+
+    pub unsafe extern "C" fn rust_callable_call_typed<F, Ps>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: TypedCallableFn<Ps>,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+        let name = w.name.to_string();
+
+        *r_error = sys::default_call_error();
+        crate::private::handle_varcall_panic(
+            CallContext::custom_callable(&name),
+            &mut *r_error,
+            AssertUnwindSafe(|| {
+                let call_ctx = CallContext::custom_callable(&name);
+                let result = w.rust_function.invoke_typed(arg_refs, &call_ctx)?;
+                *(r_return as *mut Variant) = result;
+                Ok(())
+            }),
+        );
+    }
+
+    pub unsafe extern "C" fn rust_callable_call_local_typed<F, Ps>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: TypedCallableFn<Ps>,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        let w: &mut FnWrapper<ThreadConfined<F>> =
+            CallableUserdata::inner_from_raw(callable_userdata);
+
+        let Some(rust_function) = w.rust_function.get_mut() else {
+            report_wrong_thread(&w.name, r_error);
+            return;
+        };
+        let name = w.name.to_string();
+
+        *r_error = sys::default_call_error();
+        crate::private::handle_varcall_panic(
+            CallContext::custom_callable(&name),
+            &mut *r_error,
+            AssertUnwindSafe(|| {
+                let call_ctx = CallContext::custom_callable(&name);
+                let result = rust_function.invoke_typed(arg_refs, &call_ctx)?;
+                *(r_return as *mut Variant) = result;
+                Ok(())
+            }),
+        );
+    }
+
+    /// Signals that a thread-local callable was invoked on a different thread, without calling the Rust function.
+    unsafe fn report_wrong_thread(name: &GString, r_error: *mut sys::GDExtensionCallError) {
+        let name = name.to_string();
+        let call_ctx = CallContext::custom_callable(&name);
+
+        *r_error = sys::default_call_error();
+        crate::private::report_call_error(CallError::failed_wrong_thread(&call_ctx), &mut *r_error);
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
@@ -7,37 +7,71 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::meta::{FromGodot, ToGodot};
-use crate::builtin::{inner, Variant, VariantArray};
+#[cfg(since_api = "4.4")]
+use crate::builtin::meta::FromGodotError;
+use crate::builtin::meta::{
+    ConvertError, ElementArg, FromGodot, FromVariantError, GodotConvert, GodotFfiVariant,
+    GodotType, ToGodot,
+};
+use crate::builtin::{inner, Variant, VariantArray, VariantType};
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use std::marker::PhantomData;
 use std::{fmt, ptr};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use super::meta::impl_godot_as_self;
-
 /// Godot's `Dictionary` type.
 ///
-/// The keys and values of the dictionary are all `Variant`s, so they can be of different types.
-/// Variants are designed to be generally cheap to clone.
+/// # Typed dictionaries
+///
+/// Like [`Array`][crate::builtin::Array], dictionaries can be untyped or typed.
+///
+/// An untyped dictionary can contain any kind of [`Variant`] as keys and values, even different types in the same dictionary.
+/// We represent this in Rust as `VariantDictionary`, which is a type alias for `Dictionary<Variant, Variant>`. Since the type parameters
+/// default to `Variant`, it can also be spelled as just `Dictionary`.
+///
+/// `Dictionary<K, V>` only holds keys of type `K` and values of type `V`. Accessors such as [`get()`][Self::get] and
+/// [`iter_shared()`][Self::iter_shared] return these types directly, while [`set()`][Self::set] and [`insert()`][Self::insert]
+/// only accept arguments that convert to them (see [`ElementArg`]).
+///
+/// Godot supports typed dictionaries natively starting with version 4.4. There, a `Dictionary<K, V>` created in Rust is also typed in
+/// the engine, so that GDScript and the editor enforce its types. Like for arrays, conversions from Godot (for example when a dictionary
+/// is passed to a `#[func]` or read with [`Variant::try_to()`]) then require the engine-side key and value types to match exactly.
+/// The exception is `Variant` as key or value type, which accepts any engine-side type; in particular, every dictionary converts to
+/// `VariantDictionary`.
+/// In earlier versions, the engine stores an untyped dictionary, and each entry is validated during conversion instead.
+///
+/// Untyped dictionaries are created with [`Dictionary::new()`] or the [`dict!`][crate::builtin::dict] macro. Typed dictionaries are created
+/// with [`Default::default()`], e.g. `Dictionary::<GString, i64>::default()`, or collected from an iterator.
 ///
 /// # Thread safety
 ///
 /// The same principles apply as for [`VariantArray`]. Consult its documentation for details.
-pub struct Dictionary {
+pub struct Dictionary<K: GodotType = Variant, V: GodotType = Variant> {
+    // Safety invariant: all keys in `opaque` can be converted to `K`, and all values to `V`.
     opaque: OpaqueDictionary,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl Dictionary {
-    fn from_opaque(opaque: OpaqueDictionary) -> Self {
-        Self { opaque }
-    }
+/// A Godot `Dictionary` without assigned key and value types.
+pub type VariantDictionary = Dictionary<Variant, Variant>;
 
-    /// Constructs an empty `Dictionary`.
+impl Dictionary {
+    /// Constructs an empty untyped `Dictionary`.
+    ///
+    /// To create a typed dictionary, use [`Default::default()`] instead.
     pub fn new() -> Self {
         Self::default()
     }
+}
+
+impl<K: GodotType, V: GodotType> Dictionary<K, V> {
+    fn from_opaque(opaque: OpaqueDictionary) -> Self {
+        Self {
+            opaque,
+            _phantom: PhantomData,
+        }
+    }
 
     /// Removes all key-value pairs from the dictionary.
     pub fn clear(&mut self) {
@@ -53,7 +87,10 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(true)`_
     pub fn duplicate_deep(&self) -> Self {
-        self.as_inner().duplicate(true)
+        let duplicate = self.as_inner().duplicate(true);
+
+        // SAFETY: duplicate() returns a dictionary with the same entries (and type, if any) as `self`.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Returns a shallow copy of the dictionary. All dictionary keys and values are copied, but
@@ -65,7 +102,10 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(false)`_
     pub fn duplicate_shallow(&self) -> Self {
-        self.as_inner().duplicate(false)
+        let duplicate = self.as_inner().duplicate(false);
+
+        // SAFETY: duplicate() returns a dictionary with the same entries (and type, if any) as `self`.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Removes a key from the map, and returns the value associated with
@@ -73,11 +113,11 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `erase`_
     #[doc(alias = "erase")]
-    pub fn remove<K: ToGodot>(&mut self, key: K) -> Option<Variant> {
+    pub fn remove<Q: ElementArg<K>>(&mut self, key: Q) -> Option<V> {
         let key = key.to_variant();
-        let old_value = self.get(key.clone());
+        let old_value = self.get_variant(key.clone());
         self.as_inner().erase(key);
-        old_value
+        old_value.map(|value| V::from_variant(&value))
     }
 
     /// Reverse-search a key by its value.
@@ -89,11 +129,11 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `find_key`_
     #[doc(alias = "find_key")]
-    pub fn find_key_by_value<V: ToGodot>(&self, value: V) -> Option<Variant> {
+    pub fn find_key_by_value<Q: ElementArg<V>>(&self, value: Q) -> Option<K> {
         let key = self.as_inner().find_key(value.to_variant());
 
-        if !key.is_nil() || self.contains_key(key.clone()) {
-            Some(key)
+        if !key.is_nil() || self.as_inner().has(key.clone()) {
+            Some(K::from_variant(&key))
         } else {
             None
         }
@@ -103,13 +143,9 @@ impl Dictionary {
     ///
     /// Note that `NIL` values are returned as `Some(Variant::nil())`, while absent values are returned as `None`.
     /// If you want to treat both as `NIL`, use [`Self::get_or_nil`].
-    pub fn get<K: ToGodot>(&self, key: K) -> Option<Variant> {
-        let key = key.to_variant();
-        if !self.contains_key(key.clone()) {
-            return None;
-        }
-
-        Some(self.get_or_nil(key))
+    pub fn get<Q: ElementArg<K>>(&self, key: Q) -> Option<V> {
+        self.get_variant(key.to_variant())
+            .map(|value| V::from_variant(&value))
     }
 
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
@@ -119,7 +155,7 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.get(key, null)`_
     #[doc(alias = "get")]
-    pub fn get_or_nil<K: ToGodot>(&self, key: K) -> Variant {
+    pub fn get_or_nil<Q: ElementArg<K>>(&self, key: Q) -> Variant {
         self.as_inner().get(key.to_variant(), Variant::nil())
     }
 
@@ -127,7 +163,7 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `has`_
     #[doc(alias = "has")]
-    pub fn contains_key<K: ToGodot>(&self, key: K) -> bool {
+    pub fn contains_key<Q: ElementArg<K>>(&self, key: Q) -> bool {
         let key = key.to_variant();
         self.as_inner().has(key)
     }
@@ -174,6 +210,8 @@ impl Dictionary {
     /// _Godot equivalent: `merge`_
     #[doc(alias = "merge")]
     pub fn extend_dictionary(&mut self, other: Self, overwrite: bool) {
+        // SAFETY: `merge` only writes entries taken from `other`, which have the same types as `self`.
+        let other = unsafe { other.assume_type::<Variant, Variant>() };
         self.as_inner().merge(other, overwrite)
     }
 
@@ -188,11 +226,11 @@ impl Dictionary {
     /// Insert a value at the given key, returning the previous value for that key (if available).
     ///
     /// If you don't need the previous value, use [`Self::set`] instead.
-    pub fn insert<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) -> Option<Variant> {
+    pub fn insert<Q: ElementArg<K>, R: ElementArg<V>>(&mut self, key: Q, value: R) -> Option<V> {
         let key = key.to_variant();
-        let old_value = self.get(key.clone());
-        self.set(key, value);
-        old_value
+        let old_value = self.get_variant(key.clone());
+        self.set_variant(key, value.to_variant());
+        old_value.map(|value| V::from_variant(&value))
     }
 
     /// Set a key to a given value.
@@ -200,52 +238,185 @@ impl Dictionary {
     /// If you are interested in the previous value, use [`Self::insert`] instead.
     ///
     /// _Godot equivalent: `dict[key] = value`_
-    pub fn set<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) {
-        let key = key.to_variant();
-
-        // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
-        unsafe {
-            value.to_variant().move_into_var_ptr(self.get_ptr_mut(key));
-        }
+    pub fn set<Q: ElementArg<K>, R: ElementArg<V>>(&mut self, key: Q, value: R) {
+        self.set_variant(key.to_variant(), value.to_variant());
     }
 
-    /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(Variant, Variant)`.
+    /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(K, V)`.
     /// Each pair references the original `Dictionary`, but instead of a `&`-reference to key-value pairs as
     /// you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating
     /// over it. This will not result in unsoundness or crashes, but will cause the iterator to
     /// behave in an unspecified way.
-    pub fn iter_shared(&self) -> Iter<'_> {
+    pub fn iter_shared(&self) -> Iter<'_, K, V> {
         Iter::new(self)
     }
 
-    /// Returns an iterator over the keys `Dictionary`. The keys are each of type `Variant`. Each key references
+    /// Returns an iterator over the keys `Dictionary`. The keys are each of type `K`. Each key references
     /// the original `Dictionary`, but instead of a `&`-reference to keys pairs as you might expect, the
     /// iterator returns a (cheap, shallow) copy of each key pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating
     /// over it. This will not result in unsoundness or crashes, but will cause the iterator to
     /// behave in an unspecified way.
-    pub fn keys_shared(&self) -> Keys<'_> {
+    pub fn keys_shared(&self) -> Keys<'_, K, V> {
         Keys::new(self)
     }
 
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerDictionary {
-        inner::InnerDictionary::from_outer(self)
+        // The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        inner::InnerDictionary::from_outer_typed(self)
+    }
+
+    /// Iterates over all entries without converting them.
+    fn iter_variants(&self) -> impl Iterator<Item = (Variant, Variant)> + '_ {
+        let mut iter = DictionaryIter::new(self);
+        std::iter::from_fn(move || iter.next_key_value())
+    }
+
+    /// Returns the value for the given key without converting it, or `None` if the key is absent.
+    fn get_variant(&self, key: Variant) -> Option<Variant> {
+        if !self.as_inner().has(key.clone()) {
+            return None;
+        }
+
+        Some(self.as_inner().get(key, Variant::nil()))
+    }
+
+    /// Sets the entry for `key`; callers must ensure key and value match `K` and `V`.
+    ///
+    /// Since Godot 4.4, the engine additionally validates them against the dictionary's runtime type.
+    #[cfg(since_api = "4.4")]
+    fn set_variant(&mut self, key: Variant, value: Variant) {
+        let is_set = self.as_inner().set(key.clone(), value);
+        assert!(
+            is_set,
+            "value for key {key} does not match the runtime type of the dictionary"
+        );
+    }
+
+    /// Sets the entry for `key`; callers must ensure key and value match `K` and `V`.
+    #[cfg(not(since_api = "4.4"))]
+    fn set_variant(&mut self, key: Variant, value: Variant) {
+        // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
+        unsafe {
+            value.move_into_var_ptr(self.get_ptr_mut(key));
+        }
     }
 
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
     /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
-    fn get_ptr_mut<K: ToGodot>(&mut self, key: K) -> sys::GDExtensionVariantPtr {
-        let key = key.to_variant();
-
+    #[cfg(not(since_api = "4.4"))]
+    fn get_ptr_mut(&mut self, key: Variant) -> sys::GDExtensionVariantPtr {
         // Never a null pointer, since entry either existed already or was inserted above.
         // SAFETY: accessing an unknown key _mutably_ creates that entry in the dictionary, with value `NIL`.
         unsafe { interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys()) }
     }
+
+    /// Changes the generic types of this dictionary, without changing its contents.
+    ///
+    /// # Safety
+    ///
+    /// All keys must be convertible to `K2` and all values to `V2`, and any entries written through the result must match the
+    /// runtime type of the dictionary (if it is typed).
+    unsafe fn assume_type<K2: GodotType, V2: GodotType>(self) -> Dictionary<K2, V2> {
+        // SAFETY: The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Checks that the dictionary holds keys of type `K` and values of type `V`, upholding the type invariant for dictionaries from Godot.
+    ///
+    /// Since Godot 4.4, this compares the key and value types stored in the engine, like `Array::with_checked_type()` does. A `Variant`
+    /// key or value type accepts any engine-side type, so that typed dictionaries (e.g. from engine APIs or GDScript) can be used as
+    /// `VariantDictionary`. Writes are still validated by the engine, see `set_variant()`.
+    #[cfg(since_api = "4.4")]
+    fn with_checked_type(self) -> Result<Self, ConvertError> {
+        use crate::builtin::array_inner::TypeInfo;
+
+        let inner = self.as_inner();
+        let actual_key = TypeInfo::new(
+            VariantType::from_sys(inner.get_typed_key_builtin() as sys::GDExtensionVariantType),
+            inner.get_typed_key_class_name(),
+        );
+        let actual_value = TypeInfo::new(
+            VariantType::from_sys(inner.get_typed_value_builtin() as sys::GDExtensionVariantType),
+            inner.get_typed_value_class_name(),
+        );
+
+        let expected_key = TypeInfo::of::<K>();
+        let expected_value = TypeInfo::of::<V>();
+
+        let key_matches = !expected_key.is_typed() || actual_key == expected_key;
+        let value_matches = !expected_value.is_typed() || actual_value == expected_value;
+
+        if key_matches && value_matches {
+            Ok(self)
+        } else {
+            Err(FromGodotError::BadDictionaryType {
+                expected: (expected_key, expected_value),
+                actual: (actual_key, actual_value),
+            }
+            .into_error(self))
+        }
+    }
+
+    /// Checks that the dictionary holds keys of type `K` and values of type `V`, upholding the type invariant for dictionaries from Godot.
+    ///
+    /// Before Godot 4.4, the engine has no typed dictionaries, so every entry is checked for convertibility instead.
+    #[cfg(not(since_api = "4.4"))]
+    fn with_checked_type(self) -> Result<Self, ConvertError> {
+        let check_keys = K::Ffi::variant_type() != VariantType::Nil;
+        let check_values = V::Ffi::variant_type() != VariantType::Nil;
+        if !check_keys && !check_values {
+            return Ok(self);
+        }
+
+        for (key, value) in self.iter_variants() {
+            if check_keys {
+                K::try_from_variant(&key).map_err(|err| err.nested(format!("key {key}")))?;
+            }
+            if check_values {
+                V::try_from_variant(&value)
+                    .map_err(|err| err.nested(format!("value for key {key}")))?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Marks the dictionary as typed in the engine, if `K` or `V` is not `Variant`.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called once, directly after creation.
+    #[cfg(since_api = "4.4")]
+    unsafe fn init_inner_type(&mut self) {
+        use crate::builtin::array_inner::TypeInfo;
+
+        debug_assert!(self.is_empty());
+
+        let key_info = TypeInfo::of::<K>();
+        let value_info = TypeInfo::of::<V>();
+        if key_info.is_typed() || value_info.is_typed() {
+            let script = Variant::nil();
+
+            // SAFETY: The dictionary is a newly created empty untyped dictionary.
+            unsafe {
+                interface_fn!(dictionary_set_typed)(
+                    self.sys_mut(),
+                    key_info.variant_type().sys(),
+                    key_info.class_name().string_sys(),
+                    script.var_sys(),
+                    value_info.variant_type().sys(),
+                    value_info.class_name().string_sys(),
+                    script.var_sys(),
+                );
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -260,7 +431,7 @@ impl Dictionary {
 //   Dictionaries are properly initialized through a `from_sys` call, but the ref-count should be
 //   incremented as that is the callee's responsibility. Which we do by calling
 //   `std::mem::forget(dictionary.clone())`.
-unsafe impl GodotFfi for Dictionary {
+unsafe impl<K: GodotType, V: GodotType> GodotFfi for Dictionary<K, V> {
     fn variant_type() -> sys::VariantType {
         sys::VariantType::Dictionary
     }
@@ -268,25 +439,138 @@ unsafe impl GodotFfi for Dictionary {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque; .. }
 }
 
-impl_godot_as_self!(Dictionary);
+impl<K: GodotType, V: GodotType> GodotConvert for Dictionary<K, V> {
+    type Via = Self;
+}
 
-impl_builtin_traits! {
-    for Dictionary {
-        Default => dictionary_construct_default;
-        Drop => dictionary_destroy;
-        PartialEq => dictionary_operator_equal;
-        // No < operator for dictionaries.
-        // Hash could be added, but without Eq it's not that useful.
+impl<K: GodotType, V: GodotType> ToGodot for Dictionary<K, V> {
+    fn to_godot(&self) -> Self::Via {
+        self.clone()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self
+    }
+
+    fn to_variant(&self) -> Variant {
+        self.ffi_to_variant()
     }
 }
 
-impl fmt::Debug for Dictionary {
+impl<K: GodotType, V: GodotType> FromGodot for Dictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via)
+    }
+}
+
+impl<K: GodotType, V: GodotType> GodotType for Dictionary<K, V> {
+    type Ffi = Self;
+
+    fn to_ffi(&self) -> Self::Ffi {
+        self.clone()
+    }
+
+    fn into_ffi(self) -> Self::Ffi {
+        self
+    }
+
+    fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+        ffi.with_checked_type()
+    }
+
+    fn godot_type_name() -> String {
+        "Dictionary".into()
+    }
+}
+
+impl<K: GodotType, V: GodotType, A: ToGodot<Via = Dictionary<K, V>>> ElementArg<Dictionary<K, V>>
+    for A
+{
+}
+
+impl<K: GodotType, V: GodotType> GodotFfiVariant for Dictionary<K, V> {
+    fn ffi_to_variant(&self) -> Variant {
+        unsafe {
+            Variant::new_with_var_uninit(|variant_ptr| {
+                let dictionary_to_variant = sys::builtin_fn!(dictionary_to_variant);
+                dictionary_to_variant(variant_ptr, sys::SysPtr::force_mut(self.sys()));
+            })
+        }
+    }
+
+    fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        if variant.get_type() != Self::variant_type() {
+            return Err(FromVariantError::BadType {
+                expected: Self::variant_type(),
+                actual: variant.get_type(),
+            }
+            .into_error(variant.clone()));
+        }
+
+        let dictionary = unsafe {
+            sys::new_with_uninit_or_init::<Self>(|self_ptr| {
+                let dictionary_from_variant = sys::builtin_fn!(dictionary_from_variant);
+                dictionary_from_variant(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
+            })
+        };
+
+        dictionary.with_checked_type()
+    }
+}
+
+impl<K: GodotType, V: GodotType> Default for Dictionary<K, V> {
+    #[inline]
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut dictionary = unsafe {
+            Self::new_with_uninit(|self_ptr| {
+                let ctor = sys::builtin_fn!(dictionary_construct_default);
+                ctor(self_ptr, std::ptr::null_mut())
+            })
+        };
+
+        // SAFETY: We just created this dictionary, and haven't called `init_inner_type` before.
+        #[cfg(since_api = "4.4")]
+        unsafe {
+            dictionary.init_inner_type()
+        };
+
+        dictionary
+    }
+}
+
+impl<K: GodotType, V: GodotType> Drop for Dictionary<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let dictionary_destroy = sys::builtin_fn!(dictionary_destroy);
+            dictionary_destroy(self.sys_mut());
+        }
+    }
+}
+
+// No < operator for dictionaries.
+// Hash could be added, but without Eq it's not that useful.
+impl<K: GodotType, V: GodotType> PartialEq for Dictionary<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            sys::builtin_call! {
+                dictionary_operator_equal(self.sys(), other.sys(), result.sys_mut())
+            }
+            result
+        }
+    }
+}
+
+impl<K: GodotType, V: GodotType> fmt::Debug for Dictionary<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.to_variant().stringify())
     }
 }
 
-impl fmt::Display for Dictionary {
+impl<K: GodotType + fmt::Display, V: GodotType + fmt::Display> fmt::Display for Dictionary<K, V> {
     /// Formats `Dictionary` to match Godot's string representation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{{ ")?;
@@ -305,7 +589,7 @@ impl fmt::Display for Dictionary {
 ///
 /// To create a (mostly) independent copy instead, see [`Dictionary::duplicate_shallow()`] and
 /// [`Dictionary::duplicate_deep()`].
-impl Clone for Dictionary {
+impl<K: GodotType, V: GodotType> Clone for Dictionary<K, V> {
     fn clone(&self) -> Self {
         // SAFETY: `self` is a valid dictionary, since we have a reference that keeps it alive.
         unsafe {
@@ -318,7 +602,7 @@ impl Clone for Dictionary {
     }
 }
 
-impl Var for Dictionary {
+impl<K: GodotType, V: GodotType> Var for Dictionary<K, V> {
     fn get_property(&self) -> Self::Via {
         self.to_godot()
     }
@@ -326,15 +610,44 @@ impl Var for Dictionary {
     fn set_property(&mut self, value: Self::Via) {
         *self = FromGodot::from_godot(value)
     }
+
+    #[cfg(since_api = "4.4")]
+    fn property_hint() -> PropertyHintInfo {
+        if K::Ffi::variant_type() == VariantType::Nil && V::Ffi::variant_type() == VariantType::Nil
+        {
+            return PropertyHintInfo::with_hint_none("");
+        }
+
+        PropertyHintInfo {
+            hint: crate::engine::global::PropertyHint::DICTIONARY_TYPE,
+            hint_string: format!("{};{}", K::godot_type_name(), V::godot_type_name()).into(),
+        }
+    }
 }
 
-impl TypeStringHint for Dictionary {
+impl TypeStringHint for VariantDictionary {
     fn type_string() -> String {
         format!("{}:Dictionary", sys::VariantType::Dictionary as i32)
     }
 }
 
-impl Export for Dictionary {
+impl<K: GodotType + TypeStringHint, V: GodotType + TypeStringHint> Export for Dictionary<K, V> {
+    #[cfg(since_api = "4.4")]
+    fn default_export_info() -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint: crate::engine::global::PropertyHint::TYPE_STRING,
+            hint_string: format!("{};{}", K::type_string(), V::type_string()).into(),
+        }
+    }
+
+    // Before Godot 4.4, the editor has no notion of typed dictionaries.
+    #[cfg(not(since_api = "4.4"))]
+    fn default_export_info() -> PropertyHintInfo {
+        PropertyHintInfo::with_hint_none("Dictionary")
+    }
+}
+
+impl Export for VariantDictionary {
     fn default_export_info() -> PropertyHintInfo {
         PropertyHintInfo::with_hint_none("Dictionary")
     }
@@ -364,17 +677,29 @@ where
 ///
 /// Inserts all key-value pairs from the iterator into the dictionary. Previous values for keys appearing
 /// in `iter` will be overwritten.
-impl<K: ToGodot, V: ToGodot> Extend<(K, V)> for Dictionary {
-    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+impl<K, V, Q, R> Extend<(Q, R)> for Dictionary<K, V>
+where
+    K: GodotType,
+    V: GodotType,
+    Q: ElementArg<K>,
+    R: ElementArg<V>,
+{
+    fn extend<I: IntoIterator<Item = (Q, R)>>(&mut self, iter: I) {
         for (k, v) in iter.into_iter() {
-            self.set(k.to_variant(), v.to_variant())
+            self.set(k, v)
         }
     }
 }
 
-impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut dict = Dictionary::new();
+impl<K, V, Q, R> FromIterator<(Q, R)> for Dictionary<K, V>
+where
+    K: GodotType,
+    V: GodotType,
+    Q: ElementArg<K>,
+    R: ElementArg<V>,
+{
+    fn from_iter<I: IntoIterator<Item = (Q, R)>>(iter: I) -> Self {
+        let mut dict = Self::default();
         dict.extend(iter);
         dict
     }
@@ -383,15 +708,15 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Internal helper for different iterator impls -- not an iterator itself
-struct DictionaryIter<'a> {
+struct DictionaryIter<'a, K: GodotType = Variant, V: GodotType = Variant> {
     last_key: Option<Variant>,
-    dictionary: &'a Dictionary,
+    dictionary: &'a Dictionary<K, V>,
     is_first: bool,
     next_idx: usize,
 }
 
-impl<'a> DictionaryIter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: GodotType, V: GodotType> DictionaryIter<'a, K, V> {
+    fn new(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             last_key: None,
             dictionary,
@@ -418,7 +743,7 @@ impl<'a> DictionaryIter<'a> {
 
     fn next_key_value(&mut self) -> Option<(Variant, Variant)> {
         let key = self.next_key()?;
-        if !self.dictionary.contains_key(key.clone()) {
+        if !self.dictionary.as_inner().has(key.clone()) {
             return None;
         }
 
@@ -434,7 +759,7 @@ impl<'a> DictionaryIter<'a> {
         (remaining, Some(remaining))
     }
 
-    fn call_init(dictionary: &Dictionary) -> Option<Variant> {
+    fn call_init(dictionary: &Dictionary<K, V>) -> Option<Variant> {
         let variant: Variant = Variant::nil();
         let iter_fn = |dictionary, next_value: sys::GDExtensionVariantPtr, valid| unsafe {
             interface_fn!(variant_iter_init)(dictionary, sys::SysPtr::as_uninit(next_value), valid)
@@ -443,7 +768,7 @@ impl<'a> DictionaryIter<'a> {
         Self::ffi_iterate(iter_fn, dictionary, variant)
     }
 
-    fn call_next(dictionary: &Dictionary, last_key: Variant) -> Option<Variant> {
+    fn call_next(dictionary: &Dictionary<K, V>, last_key: Variant) -> Option<Variant> {
         let iter_fn = |dictionary, next_value, valid| unsafe {
             interface_fn!(variant_iter_next)(dictionary, next_value, valid)
         };
@@ -461,7 +786,7 @@ impl<'a> DictionaryIter<'a> {
             sys::GDExtensionVariantPtr,
             *mut sys::GDExtensionBool,
         ) -> sys::GDExtensionBool,
-        dictionary: &Dictionary,
+        dictionary: &Dictionary<K, V>,
         mut next_value: Variant,
     ) -> Option<Variant> {
         let dictionary = dictionary.to_variant();
@@ -495,29 +820,35 @@ impl<'a> DictionaryIter<'a> {
 /// An iterator over key-value pairs from a `Dictionary`.
 ///
 /// See [Dictionary::iter_shared()] for more information about iteration over dictionaries.
-pub struct Iter<'a> {
-    iter: DictionaryIter<'a>,
+pub struct Iter<'a, K: GodotType = Variant, V: GodotType = Variant> {
+    iter: DictionaryIter<'a, K, V>,
 }
 
-impl<'a> Iter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: GodotType, V: GodotType> Iter<'a, K, V> {
+    fn new(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             iter: DictionaryIter::new(dictionary),
         }
     }
+}
 
+impl<'a> Iter<'a> {
     /// Creates an iterator that converts each `(Variant, Variant)` key-value pair into a `(K, V)` key-value
     /// pair, panicking upon conversion failure.
+    ///
+    /// If all entries have the same types, consider using a typed [`Dictionary<K, V>`] instead.
     pub fn typed<K: FromGodot, V: FromGodot>(self) -> TypedIter<'a, K, V> {
         TypedIter::from_untyped(self)
     }
 }
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = (Variant, Variant);
+impl<'a, K: GodotType, V: GodotType> Iterator for Iter<'a, K, V> {
+    type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key_value()
+        self.iter
+            .next_key_value()
+            .map(|(key, value)| (K::from_variant(&key), V::from_variant(&value)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -530,23 +861,17 @@ impl<'a> Iterator for Iter<'a> {
 /// An iterator over keys from a `Dictionary`.
 ///
 /// See [Dictionary::keys_shared()] for more information about iteration over dictionaries.
-pub struct Keys<'a> {
-    iter: DictionaryIter<'a>,
+pub struct Keys<'a, K: GodotType = Variant, V: GodotType = Variant> {
+    iter: DictionaryIter<'a, K, V>,
 }
 
-impl<'a> Keys<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: GodotType, V: GodotType> Keys<'a, K, V> {
+    fn new(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             iter: DictionaryIter::new(dictionary),
         }
     }
 
-    /// Creates an iterator that will convert each `Variant` key into a key of type `K`,
-    /// panicking upon failure to convert.
-    pub fn typed<K: FromGodot>(self) -> TypedKeys<'a, K> {
-        TypedKeys::from_untyped(self)
-    }
-
     /// Returns an array of the keys
     pub fn array(self) -> VariantArray {
         // Can only be called
@@ -555,11 +880,19 @@ impl<'a> Keys<'a> {
     }
 }
 
-impl<'a> Iterator for Keys<'a> {
-    type Item = Variant;
+impl<'a> Keys<'a> {
+    /// Creates an iterator that will convert each `Variant` key into a key of type `K`,
+    /// panicking upon failure to convert.
+    pub fn typed<K: FromGodot>(self) -> TypedKeys<'a, K> {
+        TypedKeys::from_untyped(self)
+    }
+}
+
+impl<'a, K: GodotType, V: GodotType> Iterator for Keys<'a, K, V> {
+    type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key()
+        self.iter.next_key().map(|key| K::from_variant(&key))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
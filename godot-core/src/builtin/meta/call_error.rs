@@ -111,7 +111,7 @@ impl CallError {
         arg_count: usize,
         param_count: usize,
     ) -> Result<(), Self> {
-        // This will need to be adjusted once optional parameters are supported in #[func].
+        // Default arguments of #[func] parameters are appended before this check, see `private::varcall_with_defaults()`.
         if arg_count == param_count {
             return Ok(());
         }
@@ -121,6 +121,30 @@ impl CallError {
         Err(call_error)
     }
 
+    /// For variadic `#[func]` methods: checks that at least `param_count` arguments were passed.
+    pub(crate) fn check_arg_count_varargs(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count >= param_count {
+            return Ok(());
+        }
+
+        let param_plural = plural(param_count);
+        let arg_plural = plural(arg_count);
+
+        let call_error = Self::new(
+            call_ctx,
+            format!(
+                "function has at least {param_count} parameter{param_plural}, but received {arg_count} argument{arg_plural}"
+            ),
+            None,
+        );
+
+        Err(call_error)
+    }
+
     /// Checks the Godot side of a varcall (low-level `sys::GDExtensionCallError`).
     pub(crate) fn check_out_varcall<T: ToGodot>(
         call_ctx: &CallContext,
@@ -299,6 +323,15 @@ impl CallError {
         call_error
     }
 
+    /// Returns an error for a thread-bound callable that was invoked from another thread.
+    pub(crate) fn failed_wrong_thread(call_ctx: &CallContext) -> Self {
+        Self::new(
+            call_ctx,
+            "callable was invoked on a different thread than the one it was created on",
+            None,
+        )
+    }
+
     #[doc(hidden)]
     pub fn failed_by_user_panic(call_ctx: &CallContext, reason: String) -> Self {
         Self::new(call_ctx, reason, None)
@@ -7,7 +7,7 @@
 
 use godot_ffi as sys;
 use std::collections::HashMap;
-use std::ffi::CStr;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
@@ -18,6 +18,9 @@ use sys::Global;
 // but we don't know how many classes).
 static CACHED_STRING_NAMES: Global<HashMap<ClassName, Box<StringName>>> = Global::default();
 
+// Names built at runtime, like types nested in classes (`MyClass.MyEnum`). Each one is leaked exactly once.
+static RUNTIME_NAMES: Global<HashMap<String, &'static CStr>> = Global::default();
+
 /// Name of a class registered with Godot.
 ///
 /// Holds the Godot name, not the Rust name (they sometimes differ, e.g. Godot `CSGMesh3D` vs Rust `CsgMesh3D`).
@@ -55,6 +58,31 @@ impl ClassName {
         Self::from_ascii_cstr(b"\0")
     }
 
+    /// Name of a type nested in this class, such as an enum: `Class.Nested`.
+    ///
+    /// # Panics
+    /// If the nested name is not ASCII or contains null bytes.
+    #[doc(hidden)]
+    pub fn nested(&self, nested_name: &str) -> Self {
+        Self::from_runtime_name(format!("{}.{}", self.as_str(), nested_name))
+    }
+
+    /// Construct from a name only known at runtime, e.g. one received from Godot.
+    ///
+    /// # Panics
+    /// If the name is not ASCII or contains null bytes.
+    pub(crate) fn from_runtime_name(name: String) -> Self {
+        assert!(name.is_ascii(), "string must be ASCII");
+
+        let mut map = RUNTIME_NAMES.lock();
+        let c_str = *map.entry(name).or_insert_with_key(|name| {
+            let c_string = CString::new(name.as_str()).expect("name must not contain null bytes");
+            Box::leak(c_string.into_boxed_c_str())
+        });
+
+        Self { c_str }
+    }
+
     #[doc(hidden)]
     pub fn is_empty(&self) -> bool {
         // From Rust 1.71 onward:
@@ -72,12 +72,22 @@ impl ConvertError {
         }
     }
 
+    /// Wraps an error that occurred while converting a part of a value, e.g. an element of a collection.
+    ///
+    /// `context` describes the part, and is prepended to the error message. The value that failed to convert is kept.
+    pub(crate) fn nested(self, context: impl Into<String>) -> Self {
+        Self {
+            kind: ErrorKind::Nested {
+                context: context.into(),
+                inner: Box::new(self.kind),
+            },
+            value: self.value,
+        }
+    }
+
     /// Returns the rust-error that caused this error, if one exists.
     pub fn cause(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
-        match &self.kind {
-            ErrorKind::Custom(Some(cause)) => Some(&**cause),
-            _ => None,
-        }
+        self.kind.cause()
     }
 
     /// Returns a reference of the value that failed to convert, if one exists.
@@ -156,6 +166,21 @@ pub(crate) enum ErrorKind {
     FromFfi(FromFfiError),
     FromVariant(FromVariantError),
     Custom(Option<Cause>),
+    /// Error converting a part of a value, e.g. an element of a collection.
+    Nested {
+        context: String,
+        inner: Box<ErrorKind>,
+    },
+}
+
+impl ErrorKind {
+    fn cause(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
+        match self {
+            Self::Custom(Some(cause)) => Some(&**cause),
+            Self::Nested { inner, .. } => inner.cause(),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ErrorKind {
@@ -165,6 +190,7 @@ impl fmt::Display for ErrorKind {
             Self::FromVariant(from_variant) => write!(f, "{from_variant}"),
             Self::FromFfi(from_ffi) => write!(f, "{from_ffi}"),
             Self::Custom(cause) => write!(f, "{cause:?}"),
+            Self::Nested { context, inner } => write!(f, "{context}: {inner}"),
         }
     }
 }
@@ -176,6 +202,16 @@ pub(crate) enum FromGodotError {
         expected: array_inner::TypeInfo,
         actual: array_inner::TypeInfo,
     },
+    BadArrayLength {
+        expected: usize,
+        actual: usize,
+    },
+    /// Key and value types of a typed dictionary, in that order.
+    #[cfg(since_api = "4.4")]
+    BadDictionaryType {
+        expected: (array_inner::TypeInfo, array_inner::TypeInfo),
+        actual: (array_inner::TypeInfo, array_inner::TypeInfo),
+    },
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
     ZeroInstanceId,
@@ -224,6 +260,20 @@ impl fmt::Display for FromGodotError {
                     actual.class_name()
                 )
             }
+            #[cfg(since_api = "4.4")]
+            Self::BadDictionaryType { expected, actual } => {
+                write!(
+                    f,
+                    "expected Dictionary[{:?}, {:?}], got Dictionary[{:?}, {:?}]",
+                    expected.0, expected.1, actual.0, actual.1
+                )
+            }
+            Self::BadArrayLength { expected, actual } => {
+                write!(
+                    f,
+                    "expected array of length {expected}, got length {actual}"
+                )
+            }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
         }
@@ -5,10 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
 use crate::builtin::meta::{
-    impl_godot_as_self, ConvertError, FromGodot, GodotConvert, GodotType, ToGodot,
+    impl_godot_as_self, ConvertError, ElementArg, FromGodot, FromGodotError, GodotConvert,
+    GodotType, ToGodot,
 };
-use crate::builtin::Variant;
+use crate::builtin::{Array, Dictionary, Variant, VariantArray};
 use godot_ffi as sys;
 
 // The following ToGodot/FromGodot/Convert impls are auto-generated for each engine type, co-located with their definitions:
@@ -84,6 +88,190 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust collections
+
+// Sequences are converted via typed arrays of the element's `Via` type, so that Godot sees e.g. `Array[int]` for `Vec<i32>`.
+
+/// Converts each element of a typed array, reporting the index of the first element that fails.
+fn try_from_typed_array<T: FromGodot>(via: Array<T::Via>) -> Result<Vec<T>, ConvertError> {
+    // Elements are converted from `Variant`, as converting to `T::Via` may already fail (e.g. `u8` out of range).
+    (0..via.len())
+        .map(|index| {
+            T::try_from_variant(&via.get_variant(index))
+                .map_err(|err| err.nested(format!("element {index}")))
+        })
+        .collect()
+}
+
+impl<T: GodotConvert> GodotConvert for Vec<T> {
+    type Via = Array<T::Via>;
+}
+
+impl<T: ToGodot> ToGodot for Vec<T> {
+    fn to_godot(&self) -> Self::Via {
+        self.iter().map(ToGodot::to_godot).collect()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.into_iter().map(ToGodot::into_godot).collect()
+    }
+}
+
+impl<T: FromGodot> FromGodot for Vec<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        try_from_typed_array(via)
+    }
+}
+
+impl<T: GodotConvert, const N: usize> GodotConvert for [T; N] {
+    type Via = Array<T::Via>;
+}
+
+impl<T: ToGodot, const N: usize> ToGodot for [T; N] {
+    fn to_godot(&self) -> Self::Via {
+        self.iter().map(ToGodot::to_godot).collect()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.into_iter().map(ToGodot::into_godot).collect()
+    }
+}
+
+impl<T: FromGodot, const N: usize> FromGodot for [T; N] {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        if via.len() != N {
+            let err = FromGodotError::BadArrayLength {
+                expected: N,
+                actual: via.len(),
+            };
+            return Err(err.into_error(via));
+        }
+
+        let elements = try_from_typed_array(via)?;
+
+        // Length was checked above.
+        Ok(elements
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("array length mismatch")))
+    }
+}
+
+impl<T: GodotConvert, S> GodotConvert for HashSet<T, S> {
+    type Via = Array<T::Via>;
+}
+
+impl<T: ToGodot, S> ToGodot for HashSet<T, S> {
+    fn to_godot(&self) -> Self::Via {
+        self.iter().map(ToGodot::to_godot).collect()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.into_iter().map(ToGodot::into_godot).collect()
+    }
+}
+
+/// Duplicate elements in the array are merged.
+impl<T, S> FromGodot for HashSet<T, S>
+where
+    T: FromGodot + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        try_from_typed_array(via).map(|elements| elements.into_iter().collect())
+    }
+}
+
+// Maps are converted via untyped dictionaries; each key and value is converted individually.
+
+impl<K: GodotConvert, V: GodotConvert, S> GodotConvert for HashMap<K, V, S> {
+    type Via = Dictionary;
+}
+
+impl<K: ToGodot, V: ToGodot, S> ToGodot for HashMap<K, V, S> {
+    fn to_godot(&self) -> Self::Via {
+        self.iter()
+            .map(|(key, value)| (key.to_variant(), value.to_variant()))
+            .collect()
+    }
+}
+
+impl<K, V, S> FromGodot for HashMap<K, V, S>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+    S: BuildHasher + Default,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        via.iter_shared()
+            .map(|(key, value)| {
+                let rust_key =
+                    K::try_from_variant(&key).map_err(|err| err.nested(format!("key {key}")))?;
+                let rust_value = V::try_from_variant(&value)
+                    .map_err(|err| err.nested(format!("value for key {key}")))?;
+
+                Ok((rust_key, rust_value))
+            })
+            .collect()
+    }
+}
+
+// Tuples are converted via untyped arrays, since their elements can have different types.
+
+macro_rules! impl_godot_convert_for_tuple {
+    (
+        $LEN:literal
+        $(, $n:tt : $Tn:ident)* // $n cannot be literal if substituted as tuple index .0
+    ) => {
+        impl<$($Tn,)*> GodotConvert for ($($Tn,)*)
+        where
+            $( $Tn: GodotConvert, )*
+        {
+            type Via = VariantArray;
+        }
+
+        impl<$($Tn,)*> ToGodot for ($($Tn,)*)
+        where
+            $( $Tn: ToGodot, )*
+        {
+            fn to_godot(&self) -> Self::Via {
+                let mut array = VariantArray::new();
+                $( array.push(self.$n.to_variant()); )*
+                array
+            }
+        }
+
+        impl<$($Tn,)*> FromGodot for ($($Tn,)*)
+        where
+            $( $Tn: FromGodot, )*
+        {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                if via.len() != $LEN {
+                    let err = FromGodotError::BadArrayLength {
+                        expected: $LEN,
+                        actual: via.len(),
+                    };
+                    return Err(err.into_error(via));
+                }
+
+                Ok(($(
+                    $Tn::try_from_variant(&via.get($n))
+                        .map_err(|err| err.nested(format!("element {}", $n)))?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_godot_convert_for_tuple!(1, 0: T0);
+impl_godot_convert_for_tuple!(2, 0: T0, 1: T1);
+impl_godot_convert_for_tuple!(3, 0: T0, 1: T1, 2: T2);
+impl_godot_convert_for_tuple!(4, 0: T0, 1: T1, 2: T2, 3: T3);
+impl_godot_convert_for_tuple!(5, 0: T0, 1: T1, 2: T2, 3: T3, 4: T4);
+impl_godot_convert_for_tuple!(6, 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5);
+impl_godot_convert_for_tuple!(7, 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6);
+impl_godot_convert_for_tuple!(8, 0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Builtin Godot types
 
@@ -158,6 +346,8 @@ macro_rules! impl_godot_scalar {
             }
         }
 
+        impl<A: ToGodot<Via = $T>> ElementArg<$T> for A {}
+
         impl GodotConvert for $T {
             type Via = $T;
         }
@@ -202,6 +392,8 @@ macro_rules! impl_godot_scalar {
             }
         }
 
+        impl<A: ToGodot<Via = $T>> ElementArg<$T> for A {}
+
         impl GodotConvert for $T {
             type Via = $T;
         }
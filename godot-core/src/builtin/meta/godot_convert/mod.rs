@@ -12,7 +12,7 @@ pub use convert_error::ConvertError;
 
 use crate::builtin::Variant;
 
-use super::{GodotFfiVariant, GodotType};
+use super::{GodotFfiVariant, GodotType, PropertyInfo};
 
 /// Indicates that a type can be passed to/from Godot, either directly or through an intermediate "via" type.
 ///
@@ -21,9 +21,22 @@ use super::{GodotFfiVariant, GodotType};
 ///
 /// [`GodotType`] is a stronger bound than [`GodotConvert`], since it expresses that a type is _directly_ representable
 /// in Godot (without intermediate "via"). Every `GodotType` also implements `GodotConvert` with `Via = Self`.
+///
+/// Some standard Rust collections are converted out of the box:
+/// - `Vec<T>`, `[T; N]` and `HashSet<T>` via a typed `Array<T::Via>`.
+/// - `HashMap<K, V>` via `Dictionary`.
+/// - Tuples with up to 8 elements via `VariantArray`.
 pub trait GodotConvert {
     /// The type through which `Self` is represented in Godot.
     type Via: GodotType;
+
+    /// Type information for parameters, return values and properties of this type.
+    ///
+    /// Defaults to the information of `Via`. Enums registered in a class override this, to carry their qualified name `Class.Enum`.
+    #[doc(hidden)]
+    fn godot_property_info(property_name: &str) -> PropertyInfo {
+        <Self::Via as GodotType>::property_info(property_name)
+    }
 }
 
 /// Defines the canonical conversion to Godot for a type.
@@ -10,12 +10,15 @@ pub mod registration;
 mod call_error;
 mod class_name;
 mod godot_convert;
+mod param_tuple;
 mod signature;
 
 pub use call_error::*;
 pub use class_name::*;
 pub use godot_convert::*;
 #[doc(hidden)]
+pub use param_tuple::*;
+#[doc(hidden)]
 pub use signature::*;
 
 pub(crate) use godot_convert::convert_error::*;
@@ -75,7 +78,7 @@ mod sealed {
     impl Sealed for Signal {}
     impl Sealed for Transform2D {}
     impl Sealed for Transform3D {}
-    impl Sealed for Dictionary {}
+    impl<K: GodotType, V: GodotType> Sealed for Dictionary<K, V> {}
     impl Sealed for bool {}
     impl Sealed for i64 {}
     impl Sealed for i32 {}
@@ -238,6 +241,25 @@ where
     }
 }
 
+impl<T, A> ElementArg<Option<T>> for A
+where
+    T: GodotType,
+    T::Ffi: GodotNullableFfi,
+    A: ToGodot<Via = Option<T>>,
+{
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Argument that can be stored as an element of type `T` in a typed container, e.g. as key or value of a [`Dictionary<K, V>`].
+///
+/// For `T = Variant`, any [`ToGodot`] type is accepted, so untyped containers keep working with arbitrary values. For every other `T`,
+/// the argument's [`Via`][GodotConvert::Via] type must be exactly `T`. This allows passing `&str` where a `GString` is expected, or
+/// a `#[derive(GodotConvert)]` enum where an `i64` is expected.
+pub trait ElementArg<T: GodotType>: ToGodot {}
+
+impl<A: ToGodot> ElementArg<Variant> for A {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Rusty abstraction of `sys::GDExtensionPropertyInfo`.
@@ -255,6 +277,23 @@ pub struct PropertyInfo {
 }
 
 impl PropertyInfo {
+    /// Creates a property of type `T` with default hint and usage, as used by `#[var]` fields.
+    pub fn new_var<T: GodotConvert>(property_name: &str) -> Self {
+        T::godot_property_info(property_name)
+    }
+
+    /// Returns the dictionary representation used by Godot, e.g. in [`Object::get_property_list()`][crate::engine::Object::get_property_list].
+    pub fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "name": self.property_name.clone(),
+            "class_name": self.class_name.to_string_name(),
+            "type": self.variant_type,
+            "hint": self.hint,
+            "hint_string": self.hint_string.clone(),
+            "usage": self.usage,
+        }
+    }
+
     /// Converts to the FFI type. Keep this object allocated while using that!
     pub fn property_sys(&self) -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
@@ -270,6 +309,66 @@ impl PropertyInfo {
         }
     }
 
+    /// Converts from the FFI type, copying all values.
+    ///
+    /// # Safety
+    /// The string pointers in `info` must point to valid `StringName` and `GString` instances.
+    pub(crate) unsafe fn new_from_sys(info: &sys::GDExtensionPropertyInfo) -> Self {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        let class_name = StringName::borrow_string_sys(info.class_name);
+        let class_name = if class_name.is_empty() {
+            ClassName::none()
+        } else {
+            ClassName::from_runtime_name(class_name.to_string())
+        };
+
+        Self {
+            variant_type: VariantType::from_sys(info.type_),
+            class_name,
+            property_name: StringName::new_from_string_sys(info.name),
+            hint: global::PropertyHint::from_ord(info.hint as i32),
+            hint_string: GString::new_from_string_sys(info.hint_string),
+            usage: global::PropertyUsageFlags::from_ord(info.usage as u64),
+        }
+    }
+
+    /// Writes all values into the FFI type, assigning to the strings it points to (rather than replacing the pointers).
+    ///
+    /// # Safety
+    /// The string pointers in `info` must point to valid, mutable `StringName` and `GString` instances.
+    pub(crate) unsafe fn move_into_sys(self, info: &mut sys::GDExtensionPropertyInfo) {
+        use crate::obj::EngineBitfield as _;
+        use crate::obj::EngineEnum as _;
+
+        info.type_ = self.variant_type.sys();
+        info.hint = u32::try_from(self.hint.ord()).expect("hint.ord()");
+        info.usage = u32::try_from(self.usage.ord()).expect("usage.ord()");
+
+        *info.name.cast::<StringName>() = self.property_name;
+        *info.class_name.cast::<StringName>() = self.class_name.to_string_name();
+        *info.hint_string.cast::<GString>() = self.hint_string;
+    }
+
+    /// Usage flags that belong to the type rather than to a specific property, i.e. `CLASS_IS_ENUM` and `CLASS_IS_BITFIELD`.
+    #[doc(hidden)]
+    pub fn type_usage_flags(&self) -> global::PropertyUsageFlags {
+        use crate::obj::EngineBitfield as _;
+
+        let mut flags = global::PropertyUsageFlags::NONE;
+        for flag in [
+            global::PropertyUsageFlags::CLASS_IS_ENUM,
+            global::PropertyUsageFlags::CLASS_IS_BITFIELD,
+        ] {
+            if self.usage.is_set(flag) {
+                flags = flags | flag;
+            }
+        }
+
+        flags
+    }
+
     pub fn empty_sys() -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
         use crate::obj::EngineEnum as _;
@@ -297,6 +396,25 @@ pub struct MethodInfo {
 }
 
 impl MethodInfo {
+    /// Returns the dictionary representation used by Godot, e.g. in [`Object::get_method_list()`][crate::engine::Object::get_method_list].
+    pub fn to_dictionary(&self) -> Dictionary {
+        let args: Array<Dictionary> = self
+            .arguments
+            .iter()
+            .map(PropertyInfo::to_dictionary)
+            .collect();
+        let default_args: VariantArray = self.default_arguments.iter().cloned().collect();
+
+        dict! {
+            "name": self.method_name.clone(),
+            "args": args,
+            "default_args": default_args,
+            "flags": self.flags,
+            "id": self.id,
+            "return": self.return_type.to_dictionary(),
+        }
+    }
+
     /// Converts to the FFI type. Keep this object allocated while using that!
     ///
     /// The struct returned by this function contains pointers into the fields of `self`. `self` should therefore not be dropped while the
@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::{CallContext, CallError, FromGodot, ToGodot};
+use crate::builtin::Variant;
+
+/// Tuple of statically typed parameters, which can be converted from/to a list of `Variant` arguments.
+///
+/// Unlike [`VarcallSignatureTuple`](super::VarcallSignatureTuple), this does not include a return type and does not deal with FFI pointers.
+/// It is used in places where arguments are only available as `Variant`s, such as typed signals.
+#[doc(hidden)]
+pub trait ParamTuple: Sized {
+    /// Number of parameters in the tuple.
+    const LEN: usize;
+
+    /// Converts dynamic arguments into a statically typed tuple.
+    ///
+    /// Fails if the number of arguments does not match, or if any argument cannot be converted to its parameter type.
+    fn from_variant_args(args: &[&Variant], call_ctx: &CallContext) -> Result<Self, CallError>;
+
+    /// Converts each element of the tuple to a `Variant`.
+    fn to_variant_args(&self) -> Vec<Variant>;
+}
+
+macro_rules! impl_param_tuple {
+    (
+        $PARAM_COUNT:literal
+        $(, ($pn:ident, $n:tt) : $Pn:ident)* // $n cannot be literal if substituted as tuple index .0
+    ) => {
+        #[allow(unused_variables)]
+        impl<$($Pn,)*> ParamTuple for ($($Pn,)*)
+        where
+            $( $Pn: FromGodot + ToGodot, )*
+        {
+            const LEN: usize = $PARAM_COUNT;
+
+            fn from_variant_args(args: &[&Variant], call_ctx: &CallContext) -> Result<Self, CallError> {
+                CallError::check_arg_count(call_ctx, args.len(), $PARAM_COUNT)?;
+
+                let tuple = ($(
+                    $Pn::try_from_variant(args[$n])
+                        .map_err(|err| CallError::failed_param_conversion::<$Pn>(call_ctx, $n, err))?,
+                )*);
+
+                Ok(tuple)
+            }
+
+            fn to_variant_args(&self) -> Vec<Variant> {
+                vec![
+                    $( self.$n.to_variant(), )*
+                ]
+            }
+        }
+    };
+}
+
+impl_param_tuple!(0);
+impl_param_tuple!(1, (p0, 0): P0);
+impl_param_tuple!(2, (p0, 0): P0, (p1, 1): P1);
+impl_param_tuple!(3, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_param_tuple!(4, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_param_tuple!(5, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_param_tuple!(6, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_param_tuple!(7, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_param_tuple!(8, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
+impl_param_tuple!(9, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8);
+impl_param_tuple!(10, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9);
@@ -9,3 +9,4 @@
 
 pub mod constant;
 pub mod method;
+pub mod rpc_config;
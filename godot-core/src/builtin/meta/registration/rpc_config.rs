@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{dict, Dictionary, StringName};
+use crate::engine::multiplayer_api::RpcMode;
+use crate::engine::multiplayer_peer::TransferMode;
+use crate::engine::Node;
+use crate::obj::Gd;
+
+/// Configuration of a remote procedure call, as accepted by [`Node::rpc_config()`].
+///
+/// Usually generated by the `#[rpc]` attribute. Can be passed to `#[rpc(config = ...)]` to share a configuration between methods.
+#[derive(Copy, Clone, Debug)]
+pub struct RpcConfig {
+    /// Which peers may call the method. Default: [`RpcMode::AUTHORITY`].
+    pub rpc_mode: RpcMode,
+
+    /// How packets are delivered. Default: [`TransferMode::RELIABLE`].
+    pub transfer_mode: TransferMode,
+
+    /// Whether the method is also called on the local peer. Default: `false`.
+    pub call_local: bool,
+
+    /// Channel on which the call is sent. Default: `0`.
+    pub channel: u32,
+}
+
+impl Default for RpcConfig {
+    fn default() -> Self {
+        Self {
+            rpc_mode: RpcMode::AUTHORITY,
+            transfer_mode: TransferMode::RELIABLE,
+            call_local: false,
+            channel: 0,
+        }
+    }
+}
+
+impl RpcConfig {
+    /// Returns the dictionary representation expected by [`Node::rpc_config()`].
+    pub fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "rpc_mode": self.rpc_mode,
+            "transfer_mode": self.transfer_mode,
+            "call_local": self.call_local,
+            "channel": self.channel,
+        }
+    }
+
+    /// Applies this configuration to the method `method_name` of `node`.
+    pub fn configure_node(&self, node: &mut Gd<Node>, method_name: &str) {
+        node.rpc_config(
+            StringName::from(method_name),
+            self.to_dictionary().to_variant(),
+        );
+    }
+}
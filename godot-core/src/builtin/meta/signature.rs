@@ -38,6 +38,17 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     ) -> Result<(), CallError>;
 
+    /// Like [`in_varcall()`](Self::in_varcall), but accepts additional arguments after the fixed parameters, which are passed as `varargs`.
+    unsafe fn in_varcall_varargs(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        call_ctx: &CallContext,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: i64,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[Variant]) -> Self::Ret,
+    ) -> Result<(), CallError>;
+
     unsafe fn out_class_varcall(
         method_bind: ClassMethodBind,
         // Separate parameters to reduce tokens in generated class API.
@@ -151,7 +162,10 @@ macro_rules! impl_varcall_signature_for_tuple {
             fn param_info(index: usize, param_name: &str) -> Option<MethodParamOrReturnInfo> {
                 match index {
                     $(
-                        $n => Some($Pn::Via::argument_info(param_name)),
+                        $n => Some(MethodParamOrReturnInfo::new(
+                            $Pn::godot_property_info(param_name),
+                            $Pn::Via::param_metadata(),
+                        )),
                     )*
                     _ => None,
                 }
@@ -159,14 +173,17 @@ macro_rules! impl_varcall_signature_for_tuple {
 
             #[inline]
             fn return_info() -> Option<MethodParamOrReturnInfo> {
-                $R::Via::return_info()
+                // Keep `None` if the `Via` type has no return info; otherwise let `$R` refine the type information.
+                $R::Via::return_info().map(|_| {
+                    MethodParamOrReturnInfo::new($R::godot_property_info(""), $R::Via::param_metadata())
+                })
             }
 
             #[inline]
             fn param_property_info(index: usize, param_name: &str) -> PropertyInfo {
                 match index {
                     $(
-                        $n => $Pn::Via::property_info(param_name),
+                        $n => $Pn::godot_property_info(param_name),
                     )*
                     _ => unreachable!("property_info: unavailable for index {}", index),
                 }
@@ -194,6 +211,39 @@ macro_rules! impl_varcall_signature_for_tuple {
                 Ok(())
             }
 
+            #[inline]
+            unsafe fn in_varcall_varargs(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                call_ctx: &CallContext,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: i64,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[Variant]) -> Self::Ret,
+            ) -> Result<(), CallError> {
+                //$crate::out!("in_varcall_varargs: {call_ctx}");
+                CallError::check_arg_count_varargs(call_ctx, arg_count as usize, $PARAM_COUNT)?;
+
+                let args = ($(
+                    unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
+                )*) ;
+
+                let varargs: Vec<Variant> = if arg_count as usize == $PARAM_COUNT {
+                    Vec::new()
+                } else {
+                    let vararg_ptrs = unsafe { args_ptr.add($PARAM_COUNT) };
+                    let vararg_count = arg_count as usize - $PARAM_COUNT;
+                    unsafe { Variant::borrow_ref_slice(vararg_ptrs, vararg_count) }
+                        .iter()
+                        .map(|&variant| variant.clone())
+                        .collect()
+                };
+
+                let rust_result = func(instance_ptr, args, &varargs);
+                varcall_return::<$R>(rust_result, ret, err);
+                Ok(())
+            }
+
             #[inline]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
@@ -634,6 +684,14 @@ impl<'a> CallContext<'a> {
         }
     }
 
+    /// Call from Godot into a Rust closure, via a custom `Callable`.
+    pub const fn custom_callable(function_name: &'a str) -> Self {
+        Self {
+            class_name: "Callable",
+            function_name,
+        }
+    }
+
     /// Outbound call from Rust into the engine, via Gd methods.
     pub fn gd<T: GodotClass>(function_name: &'a str) -> Self {
         let class_name = T::class_name().as_str();
@@ -46,7 +46,7 @@ pub mod __prelude_reexport {
     pub use callable::*;
     pub use color::*;
     pub use color_hsv::*;
-    pub use dictionary_inner::Dictionary;
+    pub use dictionary_inner::{Dictionary, VariantDictionary};
     pub use packed_array::*;
     pub use plane::*;
     pub use projection::*;
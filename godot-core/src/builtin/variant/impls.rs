@@ -6,7 +6,9 @@
  */
 
 use super::*;
-use crate::builtin::meta::{FromVariantError, GodotFfiVariant, GodotType, PropertyInfo};
+use crate::builtin::meta::{
+    ElementArg, FromVariantError, GodotFfiVariant, GodotType, PropertyInfo,
+};
 use crate::builtin::*;
 use crate::engine::global;
 use godot_ffi as sys;
@@ -80,6 +82,8 @@ macro_rules! impl_ffi_variant {
 
             impl_ffi_variant!(@godot_type_name $T $(, $godot_type_name)?);
         }
+
+        impl<A: ToGodot<Via = $T>> ElementArg<$T> for A {}
     };
 
     (@godot_type_name $T:ty) => {
@@ -135,7 +139,6 @@ mod impls {
     impl_ffi_variant!(Signal, signal_to_variant, signal_from_variant);
     impl_ffi_variant!(Transform2D, transform_2d_to_variant, transform_2d_from_variant);
     impl_ffi_variant!(Transform3D, transform_3d_to_variant, transform_3d_from_variant);
-    impl_ffi_variant!(Dictionary, dictionary_to_variant, dictionary_from_variant);
     impl_ffi_variant!(i64, int_to_variant, int_from_variant; int);
     impl_ffi_variant!(f64, float_to_variant, float_from_variant; float);
 
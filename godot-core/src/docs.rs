@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Class reference documentation, collected from `///` comments by the proc-macros and registered with the editor help.
+//!
+//! The macros pre-render the XML fragments for members, methods, signals and constants at compile time, except for type names: those
+//! are only known once the Rust types are mapped to Godot types, so fragments are rendered by functions. This module assembles the
+//! fragments belonging to one class into a full class reference document, following Godot's `doc/class.xsd` schema.
+
+use crate::builtin::meta::{ClassName, GodotConvert, GodotType};
+use crate::private::iterate_docs_plugins;
+use std::collections::HashMap;
+
+/// Documentation of a class, registered by `#[derive(GodotClass)]`.
+#[derive(Debug)]
+pub struct DocsPlugin {
+    pub class_name: ClassName,
+    pub item: DocsItem,
+}
+
+#[derive(Debug)]
+pub enum DocsItem {
+    /// Docs of the struct itself and its `#[var]`/`#[export]` fields.
+    Struct(StructDocs),
+
+    /// Docs of `#[func]`, `#[signal]` and `#[constant]` items in a `#[godot_api] impl` block.
+    InherentImpl(InherentImplDocs),
+}
+
+/// Pre-rendered documentation of a `#[derive(GodotClass)]` struct.
+///
+/// All strings are already XML-escaped.
+#[derive(Debug)]
+pub struct StructDocs {
+    pub base: &'static str,
+    pub brief_description: &'static str,
+    pub description: &'static str,
+
+    /// Renders the sequence of `<member>` elements.
+    pub members: fn() -> String,
+}
+
+/// Pre-rendered documentation of a `#[godot_api] impl` block.
+///
+/// All strings are already XML-escaped.
+#[derive(Debug)]
+pub struct InherentImplDocs {
+    /// Renders the sequence of `<method>` elements.
+    pub methods: fn() -> String,
+
+    /// Renders the sequence of `<signal>` elements.
+    pub signals: fn() -> String,
+
+    /// Sequence of `<constant>` elements.
+    pub constants: &'static str,
+}
+
+#[derive(Default)]
+struct ClassDocs {
+    definition: Option<&'static StructDocs>,
+    impls: Vec<&'static InherentImplDocs>,
+}
+
+/// Renders the class reference XML of every class that has been declared with `#[derive(GodotClass)]`.
+pub fn gather_xml_docs() -> impl Iterator<Item = String> {
+    let mut classes = HashMap::<ClassName, ClassDocs>::new();
+
+    iterate_docs_plugins(|plugin| {
+        let entry = classes.entry(plugin.class_name).or_default();
+
+        match &plugin.item {
+            DocsItem::Struct(docs) => entry.definition = Some(docs),
+            DocsItem::InherentImpl(docs) => entry.impls.push(docs),
+        }
+    });
+
+    classes.into_iter().filter_map(|(class_name, docs)| {
+        // An impl block without its struct cannot be documented, as the base class is unknown.
+        let definition = docs.definition?;
+
+        Some(make_class_xml(class_name, definition, &docs.impls))
+    })
+}
+
+fn make_class_xml(
+    class_name: ClassName,
+    definition: &StructDocs,
+    impls: &[&InherentImplDocs],
+) -> String {
+    let StructDocs {
+        base,
+        brief_description,
+        description,
+        members,
+    } = definition;
+
+    let members = members();
+    let methods: String = impls.iter().map(|docs| (docs.methods)()).collect();
+    let signals: String = impls.iter().map(|docs| (docs.signals)()).collect();
+    let constants: String = impls.iter().map(|docs| docs.constants).collect();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<class name="{class_name}" inherits="{base}" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+<brief_description>{brief_description}</brief_description>
+<description>{description}</description>
+<methods>{methods}</methods>
+<members>{members}</members>
+<signals>{signals}</signals>
+<constants>{constants}</constants>
+</class>"#
+    )
+}
+
+/// Name of the Godot type that `T` is converted to, as shown in the class reference, e.g. `int` for `i64` or `Node` for `Gd<Node>`.
+pub fn docs_type_name<T: GodotConvert>() -> String {
+    <T::Via as GodotType>::godot_type_name()
+}
+
+/// Loads the documentation of all classes into the editor help.
+///
+/// Must be called at the `Editor` init level, once all classes have been registered.
+#[cfg(since_api = "4.3")]
+pub(crate) fn register() {
+    for xml in gather_xml_docs() {
+        // SAFETY: the buffer is valid UTF-8 and outlives the call; Godot copies the data.
+        unsafe {
+            crate::sys::interface_fn!(editor_help_load_xml_from_utf8_chars_and_len)(
+                xml.as_ptr() as *const std::ffi::c_char,
+                xml.len() as crate::sys::GDExtensionInt,
+            );
+        }
+    }
+}
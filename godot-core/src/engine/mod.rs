@@ -15,7 +15,11 @@ pub use crate::gen::central::global;
 pub use crate::gen::classes::*;
 pub use crate::gen::utilities;
 pub use io::*;
-pub use script_instance::{create_script_instance, ScriptInstance};
+#[cfg(since_api = "4.2")]
+pub use script_instance::ScriptPlaceholders;
+pub use script_instance::{
+    create_script_instance, ScriptBaseMut, ScriptBaseRef, ScriptInstance, SiMut,
+};
 
 use crate::builtin::meta::CallContext;
 use crate::sys;
@@ -5,17 +5,22 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
 use std::sync::Mutex;
 
+use godot_cell::{GdCell, InaccessibleGuard, MutGuard};
+
 use crate::builtin::meta::{MethodInfo, PropertyInfo};
 use crate::builtin::{GString, StringName, Variant, VariantType};
-use crate::obj::Gd;
+use crate::obj::{Base, Gd};
 use crate::sys;
 
-use super::{Script, ScriptLanguage};
+use super::{Object, Script, ScriptLanguage};
 
 /// Implement custom scripts that can be attached to objects in Godot.
 ///
@@ -30,7 +35,7 @@ use super::{Script, ScriptLanguage};
 /// # use godot::prelude::*;
 /// # use godot::engine::{IScriptExtension, Script, ScriptExtension};
 /// # trait ScriptInstance {} // trick 17 to avoid listing all the methods. Needs also a method.
-/// # fn create_script_instance(_: MyScriptInstance) -> *mut std::ffi::c_void { std::ptr::null_mut() }
+/// # fn create_script_instance(_: MyScriptInstance, _: Gd<Object>) -> *mut std::ffi::c_void { std::ptr::null_mut() }
 /// // 1) Define the script.
 /// #[derive(GodotClass)]
 /// #[class(init, base=ScriptExtension)]
@@ -54,17 +59,23 @@ use super::{Script, ScriptLanguage};
 /// // 3) Implement the script's virtual interface to wire up 1) and 2).
 /// #[godot_api]
 /// impl IScriptExtension for MyScript {
-///     unsafe fn instance_create(&self, _for_object: Gd<Object>) -> *mut std::ffi::c_void {
+///     unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut std::ffi::c_void {
 ///         // Upcast Gd<ScriptExtension> to Gd<Script>.
 ///         let script = self.to_gd().upcast();
 ///         let script_instance = MyScriptInstance::from_gd(script);
 ///
 ///         // Note on safety: the returned pointer must be obtained
 ///         // through create_script_instance().
-///         create_script_instance(script_instance)
+///         create_script_instance(script_instance, for_object)
 ///     }
 /// }
 /// ```
+///
+/// # Re-entrancy
+///
+/// Methods that may modify the instance, such as [`call()`](Self::call), receive a [`SiMut<Self>`] guard instead of `&mut self`.
+/// Calling into Godot through [`SiMut::base_mut()`] releases the borrow for the duration of that call, so that the engine can call
+/// back into the same script instance -- for example, when a script method emits a signal connected to another method of the same script.
 pub trait ScriptInstance {
     /// Name of the new class the script implements.
     fn class_name(&self) -> GString;
@@ -72,7 +83,7 @@ pub trait ScriptInstance {
     /// Property setter for Godot's virtual dispatch system.
     ///
     /// The engine will call this function when it wants to change a property on the script.
-    fn set_property(&mut self, name: StringName, value: &Variant) -> bool;
+    fn set_property(this: SiMut<Self>, name: StringName, value: &Variant) -> bool;
 
     /// Property getter for Godot's virtual dispatch system.
     ///
@@ -90,10 +101,11 @@ pub trait ScriptInstance {
     /// All method calls are taking a mutable reference of the script instance, as the engine does not differentiate between immutable and
     /// mutable method calls like rust.
     ///
-    /// It's important that the script does not cause a second call to this function while executing a method call. This would result in a panic.
+    /// If the method calls back into the engine, which in turn may call this script instance again, use [`SiMut::base_mut()`]. Calling into
+    /// the engine through other means (e.g. a separate `Gd` pointer to the owner) while the instance is borrowed results in a panic on re-entry.
     // TODO: map the sys::GDExtensionCallErrorType to some public API type.
     fn call(
-        &mut self,
+        this: SiMut<Self>,
         method: StringName,
         args: &[&Variant],
     ) -> Result<Variant, sys::GDExtensionCallErrorType>;
@@ -136,16 +148,71 @@ pub trait ScriptInstance {
     fn property_get_fallback(&self, name: StringName) -> Option<Variant>;
 
     /// The engine may call this function if ScriptLanguage::is_placeholder_fallback_enabled is enabled.
-    fn property_set_fallback(&mut self, name: StringName, value: &Variant) -> bool;
+    fn property_set_fallback(this: SiMut<Self>, name: StringName, value: &Variant) -> bool;
+
+    /// Category under which the script's properties are listed in the inspector.
+    ///
+    /// Returning `None` lets Godot use the default category, derived from the script's path.
+    #[cfg(since_api = "4.2")]
+    fn get_class_category(&self) -> Option<PropertyInfo> {
+        None
+    }
+
+    /// Whether the property `name` can be reverted to a default value, e.g. with the revert arrow in the inspector.
+    ///
+    /// By default, this is the case if [`Self::property_get_revert`] returns a value.
+    fn property_can_revert(&self, name: StringName) -> bool {
+        self.property_get_revert(name).is_some()
+    }
+
+    /// Value that the property `name` reverts to, or `None` if the property cannot be reverted.
+    #[allow(unused_variables)]
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        None
+    }
+
+    /// Lets the script modify how a property is presented (hint, usage flags, ...), for example to hide it in the inspector.
+    #[cfg(since_api = "4.2")]
+    #[allow(unused_variables)]
+    fn validate_property(&self, property: &mut PropertyInfo) {}
+
+    /// Callback from the engine when the object the script is attached to receives a notification.
+    ///
+    /// `what` is one of the `NOTIFICATION_*` constants of the object's class. If `reversed` is true, the notification is propagated
+    /// from the most derived class to the base class; this is for example the case for `NOTIFICATION_PREDELETE`. Before Godot 4.2,
+    /// `reversed` is always false.
+    #[allow(unused_variables)]
+    fn on_notification(this: SiMut<Self>, what: i32, reversed: bool) {}
+
+    /// Number of arguments the script method `method` takes, or `None` if the script has no such method.
+    ///
+    /// By default, this is looked up in [`Self::get_method_list`].
+    #[cfg(since_api = "4.3")]
+    fn get_method_argument_count(&self, method: StringName) -> Option<u32> {
+        self.get_method_list()
+            .into_iter()
+            .find(|info| info.method_name == method)
+            .map(|info| info.arguments.len() as u32)
+    }
+
+    /// Object that the script instance is attached to.
+    ///
+    /// Returning `None` reports the object passed to [`create_script_instance()`], which is what the default implementation does.
+    fn get_owner(&self) -> Option<Gd<Object>> {
+        None
+    }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Box forwarding
+
 impl<T: ScriptInstance + ?Sized> ScriptInstance for Box<T> {
     fn class_name(&self) -> GString {
         self.as_ref().class_name()
     }
 
-    fn set_property(&mut self, name: StringName, value: &Variant) -> bool {
-        self.as_mut().set_property(name, value)
+    fn set_property(this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        this.map_box(|this| T::set_property(this, name, value))
     }
 
     fn get_property(&self, name: StringName) -> Option<Variant> {
@@ -161,11 +228,11 @@ impl<T: ScriptInstance + ?Sized> ScriptInstance for Box<T> {
     }
 
     fn call(
-        &mut self,
+        this: SiMut<Self>,
         method: StringName,
         args: &[&Variant],
     ) -> Result<Variant, sys::GDExtensionCallErrorType> {
-        self.as_mut().call(method, args)
+        this.map_box(|this| T::call(this, method, args))
     }
 
     fn is_placeholder(&self) -> bool {
@@ -208,21 +275,258 @@ impl<T: ScriptInstance + ?Sized> ScriptInstance for Box<T> {
         self.as_ref().property_get_fallback(name)
     }
 
-    fn property_set_fallback(&mut self, name: StringName, value: &Variant) -> bool {
-        self.as_mut().property_set_fallback(name, value)
+    fn property_set_fallback(this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        this.map_box(|this| T::property_set_fallback(this, name, value))
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn get_class_category(&self) -> Option<PropertyInfo> {
+        self.as_ref().get_class_category()
+    }
+
+    fn property_can_revert(&self, name: StringName) -> bool {
+        self.as_ref().property_can_revert(name)
+    }
+
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        self.as_ref().property_get_revert(name)
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn validate_property(&self, property: &mut PropertyInfo) {
+        self.as_ref().validate_property(property);
+    }
+
+    fn on_notification(this: SiMut<Self>, what: i32, reversed: bool) {
+        this.map_box(|this| T::on_notification(this, what, reversed));
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn get_method_argument_count(&self, method: StringName) -> Option<u32> {
+        self.as_ref().get_method_argument_count(method)
+    }
+
+    fn get_owner(&self) -> Option<Gd<Object>> {
+        self.as_ref().get_owner()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Guards
+
+/// Mutable/exclusive reference to a script instance, passed to the [`ScriptInstance`] methods that may modify it.
+///
+/// Dereferences to the instance. In addition, it gives access to the object the script is attached to; see [`base_mut()`](Self::base_mut)
+/// for calls that may re-enter the script instance.
+pub struct SiMut<'a, T: ScriptInstance + ?Sized> {
+    access: &'a mut dyn SiAccess<T>,
+    base: &'a Base<Object>,
+}
+
+impl<'a, T: ScriptInstance + ?Sized> SiMut<'a, T> {
+    fn new(access: &'a mut dyn SiAccess<T>, base: &'a Base<Object>) -> Self {
+        Self { access, base }
+    }
+
+    /// Returns a shared reference suitable for calling engine methods on the object the script is attached to.
+    ///
+    /// Engine methods that call back into this script instance will panic, since the instance is still borrowed. Use
+    /// [`base_mut()`](Self::base_mut) for those.
+    ///
+    /// The guard does not hold a strong reference to the object. Cloning the dereferenced `Gd` does, so avoid storing such clones of a
+    /// `RefCounted` owner while it is being destroyed, e.g. in response to `NOTIFICATION_PREDELETE`.
+    pub fn base(&self) -> ScriptBaseRef<'_, T> {
+        ScriptBaseRef::new(self.weak_base(), self.access.get())
+    }
+
+    /// Returns a mutable reference suitable for calling engine methods on the object the script is attached to.
+    ///
+    /// While the returned guard is alive, the script instance itself is inaccessible. This allows Godot to call back into the same
+    /// script instance, e.g. when calling another script method through [`Object::call()`](crate::engine::Object::call) or emitting a
+    /// signal that is connected to it.
+    ///
+    /// Like [`base()`](Self::base), the guard does not hold a strong reference to the object.
+    pub fn base_mut(&mut self) -> ScriptBaseMut<'_, T> {
+        let base = self.weak_base();
+        let guard = self.access.make_inaccessible();
+
+        ScriptBaseMut::new(base, guard)
+    }
+
+    /// Pointer to the owner that does not modify its reference count.
+    ///
+    /// A strong `Gd` would increment the reference count of a `RefCounted` owner and decrement it again on drop. During
+    /// `NOTIFICATION_PREDELETE`, the count is already zero, so this would resurrect the object and destroy it a second time.
+    fn weak_base(&self) -> ManuallyDrop<Gd<Object>> {
+        // SAFETY: the owner destroys the script instance before itself, so it is alive while any guard borrowing the instance exists.
+        ManuallyDrop::new(unsafe { Gd::from_obj_sys_weak(self.base.obj_sys()) })
+    }
+}
+
+impl<'a, T: ScriptInstance + ?Sized> SiMut<'a, Box<T>> {
+    /// Passes a guard for the boxed instance to `f`, which forwards re-entrancy to the box's own guard.
+    fn map_box<R>(self, f: impl FnOnce(SiMut<'_, T>) -> R) -> R {
+        let mut access = BoxAccess { inner: self.access };
+
+        f(SiMut::new(&mut access, self.base))
+    }
+}
+
+impl<T: ScriptInstance + ?Sized> Deref for SiMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.access.get()
+    }
+}
+
+impl<T: ScriptInstance + ?Sized> DerefMut for SiMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.access.get_mut()
+    }
+}
+
+/// Shared reference guard for the object a script instance is attached to.
+///
+/// See [`SiMut::base()`] for usage.
+pub struct ScriptBaseRef<'a, T: ScriptInstance + ?Sized> {
+    base: ManuallyDrop<Gd<Object>>,
+    _instance: &'a T,
+}
+
+impl<'a, T: ScriptInstance + ?Sized> ScriptBaseRef<'a, T> {
+    fn new(base: ManuallyDrop<Gd<Object>>, instance: &'a T) -> Self {
+        Self {
+            base,
+            _instance: instance,
+        }
+    }
+}
+
+impl<T: ScriptInstance + ?Sized> Deref for ScriptBaseRef<'_, T> {
+    type Target = Gd<Object>;
+
+    fn deref(&self) -> &Gd<Object> {
+        &self.base
+    }
+}
+
+/// Mutable/exclusive reference guard for the object a script instance is attached to.
+///
+/// See [`SiMut::base_mut()`] for usage.
+pub struct ScriptBaseMut<'a, T: ScriptInstance + ?Sized> {
+    base: ManuallyDrop<Gd<Object>>,
+    _inaccessible_guard: Box<dyn ErasedGuard + 'a>,
+    _instance: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: ScriptInstance + ?Sized> ScriptBaseMut<'a, T> {
+    fn new(base: ManuallyDrop<Gd<Object>>, inaccessible_guard: Box<dyn ErasedGuard + 'a>) -> Self {
+        Self {
+            base,
+            _inaccessible_guard: inaccessible_guard,
+            _instance: PhantomData,
+        }
+    }
+}
+
+impl<T: ScriptInstance + ?Sized> Deref for ScriptBaseMut<'_, T> {
+    type Target = Gd<Object>;
+
+    fn deref(&self) -> &Gd<Object> {
+        &self.base
+    }
+}
+
+impl<T: ScriptInstance + ?Sized> DerefMut for ScriptBaseMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Gd<Object> {
+        &mut self.base
+    }
+}
+
+/// Mutable access to a borrowed script instance, which can temporarily be given up for re-entrant calls.
+///
+/// Type-erased, so that [`SiMut`] can also refer to an instance that is nested inside the stored one, such as `T` inside `Box<T>`.
+trait SiAccess<T: ?Sized> {
+    fn get(&self) -> &T;
+    fn get_mut(&mut self) -> &mut T;
+
+    /// Makes the instance inaccessible until the returned guard is dropped.
+    fn make_inaccessible(&mut self) -> Box<dyn ErasedGuard + '_>;
+}
+
+/// Keeps an instance inaccessible while alive, see [`SiAccess::make_inaccessible()`].
+trait ErasedGuard {}
+
+impl<T> ErasedGuard for InaccessibleGuard<'_, T> {}
+
+/// Access to the instance stored in a [`ScriptInstanceData`].
+struct CellAccess<'a, T> {
+    cell: Pin<&'a GdCell<T>>,
+    guard: MutGuard<'a, T>,
+}
+
+impl<T> SiAccess<T> for CellAccess<'_, T> {
+    fn get(&self) -> &T {
+        &self.guard
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+
+    fn make_inaccessible(&mut self) -> Box<dyn ErasedGuard + '_> {
+        let guard = self
+            .cell
+            .make_inaccessible(&mut self.guard)
+            .expect("script instance is already borrowed immutably");
+
+        Box::new(guard)
     }
 }
 
+/// Access to the instance inside a boxed script instance.
+struct BoxAccess<'a, T: ?Sized> {
+    inner: &'a mut dyn SiAccess<Box<T>>,
+}
+
+impl<T: ?Sized> SiAccess<T> for BoxAccess<'_, T> {
+    fn get(&self) -> &T {
+        self.inner.get().as_ref()
+    }
+
+    fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut().as_mut()
+    }
+
+    fn make_inaccessible(&mut self) -> Box<dyn ErasedGuard + '_> {
+        self.inner.make_inaccessible()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Instance creation
+
 #[cfg(before_api = "4.2")]
 type ScriptInstanceInfo = sys::GDExtensionScriptInstanceInfo;
-#[cfg(since_api = "4.2")]
+#[cfg(all(since_api = "4.2", before_api = "4.3"))]
 type ScriptInstanceInfo = sys::GDExtensionScriptInstanceInfo2;
+#[cfg(since_api = "4.3")]
+type ScriptInstanceInfo = sys::GDExtensionScriptInstanceInfo3;
 
 struct ScriptInstanceData<T: ScriptInstance> {
-    inner: RefCell<T>,
+    inner: Pin<Box<GdCell<T>>>,
+
+    /// Object the script is attached to. Weak pointer, since the object owns the script instance.
+    base: Base<Object>,
+
     script_instance_ptr: *mut ScriptInstanceInfo,
     property_list: Mutex<HashMap<*const sys::GDExtensionPropertyInfo, Vec<PropertyInfo>>>,
     method_list: Mutex<HashMap<*const sys::GDExtensionMethodInfo, Vec<MethodInfo>>>,
+
+    /// Keeps the strings of the last returned class category alive, until Godot has copied them.
+    #[cfg(since_api = "4.2")]
+    class_category: Mutex<Option<PropertyInfo>>,
 }
 
 impl<T: ScriptInstance> Drop for ScriptInstanceData<T> {
@@ -237,13 +541,22 @@ impl<T: ScriptInstance> Drop for ScriptInstanceData<T> {
     }
 }
 
-/// Creates a new  from a type that implements [`ScriptInstance`].
+impl<T: ScriptInstance> ScriptInstanceData<T> {
+    fn cell(&self) -> Pin<&GdCell<T>> {
+        self.inner.as_ref()
+    }
+}
+
+/// Creates a new script instance from a type that implements [`ScriptInstance`], attached to `for_object`.
 ///
 /// See [`ScriptInstance`] for usage. Discarding the resulting value will result in a memory leak.
 ///
 /// The exact GDExtension type of the pointer is `sys::GDExtensionScriptInstancePtr`, but you can treat it like an opaque pointer.
 #[must_use]
-pub fn create_script_instance<T: ScriptInstance>(rs_instance: T) -> *mut c_void {
+pub fn create_script_instance<T: ScriptInstance>(
+    rs_instance: T,
+    for_object: Gd<Object>,
+) -> *mut c_void {
     // Field grouping matches C header.
     let gd_instance = ScriptInstanceInfo {
         set_func: Some(script_instance_info::set_property_func::<T>),
@@ -252,25 +565,29 @@ pub fn create_script_instance<T: ScriptInstance>(rs_instance: T) -> *mut c_void
         free_property_list_func: Some(script_instance_info::free_property_list_func::<T>),
 
         #[cfg(since_api = "4.2")]
-        get_class_category_func: None, // not yet implemented.
+        get_class_category_func: Some(script_instance_info::get_class_category_func::<T>),
 
-        property_can_revert_func: None, // unimplemented until needed.
-        property_get_revert_func: None, // unimplemented until needed.
+        property_can_revert_func: Some(script_instance_info::property_can_revert_func::<T>),
+        property_get_revert_func: Some(script_instance_info::property_get_revert_func::<T>),
 
-        // ScriptInstance::get_owner() is apparently not called by Godot 4.0 to 4.2 (to verify).
-        get_owner_func: None,
+        get_owner_func: Some(script_instance_info::get_owner_func::<T>),
         get_property_state_func: Some(script_instance_info::get_property_state_func::<T>),
 
         get_method_list_func: Some(script_instance_info::get_method_list_func::<T>),
         free_method_list_func: Some(script_instance_info::free_method_list_func::<T>),
         get_property_type_func: Some(script_instance_info::get_property_type_func::<T>),
         #[cfg(since_api = "4.2")]
-        validate_property_func: None, // not yet implemented.
+        validate_property_func: Some(script_instance_info::validate_property_func::<T>),
 
         has_method_func: Some(script_instance_info::has_method_func::<T>),
 
+        #[cfg(since_api = "4.3")]
+        get_method_argument_count_func: Some(
+            script_instance_info::get_method_argument_count_func::<T>,
+        ),
+
         call_func: Some(script_instance_info::call_func::<T>),
-        notification_func: None, // not yet implemented.
+        notification_func: Some(script_instance_info::notification_func::<T>),
 
         to_string_func: Some(script_instance_info::to_string_func::<T>),
 
@@ -292,10 +609,14 @@ pub fn create_script_instance<T: ScriptInstance>(rs_instance: T) -> *mut c_void
     let instance_ptr = Box::into_raw(Box::new(gd_instance));
 
     let data = ScriptInstanceData {
-        inner: RefCell::new(rs_instance),
+        inner: GdCell::new(rs_instance),
+        // SAFETY: the script instance is owned by `for_object` and destroyed together with it, so the weak pointer never dangles.
+        base: unsafe { Base::from_sys(for_object.obj_sys()) },
         script_instance_ptr: instance_ptr,
         property_list: Default::default(),
         method_list: Default::default(),
+        #[cfg(since_api = "4.2")]
+        class_category: Default::default(),
     };
 
     let data_ptr = Box::into_raw(Box::new(data));
@@ -308,9 +629,12 @@ pub fn create_script_instance<T: ScriptInstance>(rs_instance: T) -> *mut c_void
         #[cfg(before_api = "4.2")]
         let create_fn = sys::interface_fn!(script_instance_create);
 
-        #[cfg(since_api = "4.2")]
+        #[cfg(all(since_api = "4.2", before_api = "4.3"))]
         let create_fn = sys::interface_fn!(script_instance_create2);
 
+        #[cfg(since_api = "4.3")]
+        let create_fn = sys::interface_fn!(script_instance_create3);
+
         create_fn(
             instance_ptr,
             data_ptr as sys::GDExtensionScriptInstanceDataPtr,
@@ -318,32 +642,206 @@ pub fn create_script_instance<T: ScriptInstance>(rs_instance: T) -> *mut c_void
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Placeholder instances
+
+/// Placeholder script instances of one script, which Godot uses in the editor for scripts that are not in tool mode.
+///
+/// A placeholder does not run any script code; it only stores the values of the script's exported properties, so they can be edited
+/// in the inspector and saved with the scene. This type creates the placeholders and keeps track of the ones still alive, so they can be
+/// updated whenever the script's properties change (typically after recompiling).
+///
+/// Keep one instance of this type per script and forward the corresponding `IScriptExtension` methods:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::{IScriptExtension, Script, ScriptExtension, ScriptPlaceholders};
+/// # use std::ffi::c_void;
+/// #[derive(GodotClass)]
+/// #[class(init, base=ScriptExtension)]
+/// struct MyScript {
+///     placeholders: ScriptPlaceholders,
+///     base: Base<ScriptExtension>,
+/// }
+///
+/// # impl MyScript {
+/// #     fn language(&self) -> Gd<godot::engine::ScriptLanguage> { unimplemented!() }
+/// #     fn properties(&self) -> (Vec<godot::builtin::meta::PropertyInfo>, Vec<(StringName, Variant)>) { unimplemented!() }
+/// # }
+/// #[godot_api]
+/// impl IScriptExtension for MyScript {
+///     unsafe fn placeholder_instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+///         let (properties, values) = self.properties();
+///         let script = self.to_gd().upcast();
+///
+///         // Returns a pointer that must be handed over to Godot.
+///         self.placeholders.create(self.language(), script, for_object, &properties, &values)
+///     }
+///
+///     unsafe fn placeholder_erased(&mut self, placeholder: *mut c_void) {
+///         // Required for the safety of `ScriptPlaceholders::update()`.
+///         self.placeholders.erase(placeholder);
+///     }
+/// }
+/// ```
+///
+/// Only available from Godot 4.2 on.
+#[cfg(since_api = "4.2")]
+#[derive(Debug, Default)]
+pub struct ScriptPlaceholders {
+    // RefCell, so that placeholders can be created from `&self` methods such as `IScriptExtension::placeholder_instance_create()`.
+    instances: std::cell::RefCell<Vec<sys::GDExtensionScriptInstancePtr>>,
+}
+
+#[cfg(since_api = "4.2")]
+impl ScriptPlaceholders {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a placeholder instance of `script` for `for_object`, filled with the given properties and their values.
+    ///
+    /// The returned pointer must be returned from [`IScriptExtension::placeholder_instance_create()`][crate::engine::IScriptExtension::placeholder_instance_create];
+    /// discarding it results in a memory leak.
+    #[must_use]
+    pub fn create(
+        &self,
+        language: Gd<ScriptLanguage>,
+        script: Gd<Script>,
+        for_object: Gd<Object>,
+        properties: &[PropertyInfo],
+        values: &[(StringName, Variant)],
+    ) -> *mut c_void {
+        // SAFETY: all three objects are alive for the duration of the call. The placeholder keeps its own references to them.
+        let placeholder = unsafe {
+            sys::interface_fn!(placeholder_script_instance_create)(
+                language.obj_sys(),
+                script.obj_sys(),
+                for_object.obj_sys(),
+            )
+        };
+
+        // SAFETY: placeholder was just created.
+        unsafe { update_placeholder(placeholder, properties, values) };
+        self.instances.borrow_mut().push(placeholder);
+
+        placeholder as *mut c_void
+    }
+
+    /// Replaces the properties and values of all placeholders that are still alive.
+    ///
+    /// # Safety
+    /// Every placeholder created by this object and since destroyed by Godot must have been passed to [`erase()`](Self::erase). This is
+    /// the case if [`IScriptExtension::placeholder_erased()`][crate::engine::IScriptExtension::placeholder_erased] of each script that
+    /// received placeholders from [`create()`](Self::create) forwards to `erase()`.
+    pub unsafe fn update(&self, properties: &[PropertyInfo], values: &[(StringName, Variant)]) {
+        for &placeholder in self.instances.borrow().iter() {
+            // SAFETY: Godot calls `placeholder_erased()` before destroying a placeholder, which per the contract removes it from the
+            // list. All remaining placeholders are thus alive.
+            update_placeholder(placeholder, properties, values);
+        }
+    }
+
+    /// Stops tracking a placeholder. Call this from [`IScriptExtension::placeholder_erased()`][crate::engine::IScriptExtension::placeholder_erased].
+    ///
+    /// Pointers that were not created by this object are ignored.
+    pub fn erase(&self, placeholder: *mut c_void) {
+        self.instances
+            .borrow_mut()
+            .retain(|&p| p != placeholder as sys::GDExtensionScriptInstancePtr);
+    }
+
+    /// Number of placeholders that are currently alive.
+    pub fn len(&self) -> usize {
+        self.instances.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// # Safety
+/// `placeholder` must point to a live placeholder instance, created by `placeholder_script_instance_create()`.
+#[cfg(since_api = "4.2")]
+unsafe fn update_placeholder(
+    placeholder: sys::GDExtensionScriptInstancePtr,
+    properties: &[PropertyInfo],
+    values: &[(StringName, Variant)],
+) {
+    use crate::builtin::{Array, Dictionary};
+    use sys::GodotFfi as _;
+
+    let properties: Array<Dictionary> = properties.iter().map(|p| p.to_dictionary()).collect();
+    let values: Dictionary = values.iter().cloned().collect();
+
+    sys::interface_fn!(placeholder_script_instance_update)(
+        placeholder,
+        properties.sys(),
+        values.sys(),
+    );
+}
+
 mod script_instance_info {
     use std::any::type_name;
-    use std::cell::{BorrowError, Ref, RefMut};
+    use std::error::Error;
     use std::ffi::c_void;
     use std::mem::ManuallyDrop;
 
+    use godot_cell::{MutGuard, RefGuard};
+
+    use crate::builtin::meta::PropertyInfo;
     use crate::builtin::{GString, StringName, Variant};
     use crate::engine::ScriptLanguage;
     use crate::obj::Gd;
     use crate::private::handle_panic;
     use crate::sys;
 
-    use super::{ScriptInstance, ScriptInstanceData};
+    use super::{CellAccess, ScriptInstance, ScriptInstanceData, SiMut};
 
-    fn borrow_instance_mut<T: ScriptInstance>(instance: &ScriptInstanceData<T>) -> RefMut<'_, T> {
-        instance.inner.borrow_mut()
+    fn borrow_instance_mut<T: ScriptInstance>(instance: &ScriptInstanceData<T>) -> MutGuard<'_, T> {
+        instance.cell().borrow_mut().unwrap_or_else(|err| {
+            panic!(
+                "\
+                    script instance is already bound; T = {}.\n  \
+                    Make sure to use `SiMut::base_mut()` when calling into the engine from a script instance.\n  \
+                    Details: {err}.\
+                ",
+                type_name::<T>()
+            )
+        })
     }
 
-    fn borrow_instance<T: ScriptInstance>(instance: &ScriptInstanceData<T>) -> Ref<'_, T> {
-        instance.inner.borrow()
+    fn borrow_instance<T: ScriptInstance>(instance: &ScriptInstanceData<T>) -> RefGuard<'_, T> {
+        instance.cell().borrow().unwrap_or_else(|err| {
+            panic!(
+                "\
+                    script instance is already bound mutably; T = {}.\n  \
+                    Make sure to use `SiMut::base_mut()` when calling into the engine from a script instance.\n  \
+                    Details: {err}.\
+                ",
+                type_name::<T>()
+            )
+        })
     }
 
     fn try_borrow_instance<T: ScriptInstance>(
         instance: &ScriptInstanceData<T>,
-    ) -> Result<Ref<'_, T>, BorrowError> {
-        instance.inner.try_borrow()
+    ) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
+        instance.cell().borrow()
+    }
+
+    /// Borrows the instance mutably and passes it to `f` as [`SiMut`], which allows re-entrant calls through `base_mut()`.
+    fn with_instance_mut<T: ScriptInstance, R>(
+        instance: &ScriptInstanceData<T>,
+        f: impl FnOnce(SiMut<T>) -> R,
+    ) -> R {
+        let mut access = CellAccess {
+            cell: instance.cell(),
+            guard: borrow_instance_mut(instance),
+        };
+
+        f(SiMut::new(&mut access, &instance.base))
     }
 
     /// # Safety
@@ -447,7 +945,7 @@ mod script_instance_info {
         let result = handle_panic(ctx, || {
             let instance = instance_data_as_script_instance::<T>(p_instance);
 
-            borrow_instance_mut(instance).set_property(name, value)
+            with_instance_mut(instance, |this| T::set_property(this, name, value))
         })
         // Unwrapping to a default of false, to indicate that the assignment is not handled by the script.
         .unwrap_or_default();
@@ -567,6 +1065,7 @@ mod script_instance_info {
     pub(super) unsafe extern "C" fn free_property_list_func<T: ScriptInstance>(
         p_instance: sys::GDExtensionScriptInstanceDataPtr,
         p_prop_info: *const sys::GDExtensionPropertyInfo,
+        #[cfg(since_api = "4.3")] _p_len: u32,
     ) {
         let ctx = || {
             format!(
@@ -619,7 +1118,7 @@ mod script_instance_info {
 
         let result = handle_panic(ctx, || {
             let instance = instance_data_as_script_instance::<T>(p_self);
-            borrow_instance_mut(instance).call(method.clone(), args)
+            with_instance_mut(instance, |this| T::call(this, method.clone(), args))
         });
 
         match result {
@@ -705,6 +1204,7 @@ mod script_instance_info {
     pub(super) unsafe extern "C" fn free_method_list_func<T: ScriptInstance>(
         p_instance: sys::GDExtensionScriptInstanceDataPtr,
         p_method_info: *const sys::GDExtensionMethodInfo,
+        #[cfg(since_api = "4.3")] _p_len: u32,
     ) {
         let ctx = || format!("error while calling {}::get_method_list", type_name::<T>());
 
@@ -966,10 +1466,223 @@ mod script_instance_info {
         let result = handle_panic(ctx, || {
             let instance = instance_data_as_script_instance::<T>(p_instance);
 
-            borrow_instance_mut(instance).property_set_fallback(name, value)
+            with_instance_mut(instance, |this| T::property_set_fallback(this, name, value))
+        })
+        .unwrap_or_default();
+
+        transfer_bool_to_godot(result)
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    /// - `p_class_category` has to be a valid pointer to a `GDExtensionPropertyInfo`, which is filled if `true` is returned. The strings it
+    ///   points to stay valid until the next call to this function.
+    #[cfg(since_api = "4.2")]
+    pub(super) unsafe extern "C" fn get_class_category_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_class_category: *mut sys::GDExtensionPropertyInfo,
+    ) -> sys::GDExtensionBool {
+        let ctx = || {
+            format!(
+                "error when calling {}::get_class_category",
+                type_name::<T>()
+            )
+        };
+
+        let class_category = handle_panic(ctx, || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            borrow_instance(instance).get_class_category()
+        })
+        .ok()
+        .flatten();
+
+        let Some(class_category) = class_category else {
+            return transfer_bool_to_godot(false);
+        };
+
+        // Godot copies the property info after this function returns, so the backing strings must outlive this call. Store the info
+        // first and only then take pointers to it; pointers into a local would dangle once it is moved.
+        let instance = instance_data_as_script_instance::<T>(p_instance);
+        let mut stored = instance
+            .class_category
+            .lock()
+            .expect("mutex should not be poisoned");
+
+        let class_category = stored.insert(class_category);
+        *p_class_category = class_category.property_sys();
+
+        transfer_bool_to_godot(true)
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    /// - `p_name` has to be a valid pointer to a `StringName`.
+    pub(super) unsafe extern "C" fn property_can_revert_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionBool {
+        let name = StringName::new_from_string_sys(p_name);
+        let ctx = || {
+            format!(
+                "error when calling {}::property_can_revert",
+                type_name::<T>()
+            )
+        };
+
+        let result = handle_panic(ctx, || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            borrow_instance(instance).property_can_revert(name)
         })
         .unwrap_or_default();
 
         transfer_bool_to_godot(result)
     }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    /// - `p_name` has to be a valid pointer to a `StringName`.
+    /// - `r_ret` has to be a valid pointer to which the return value can be moved.
+    pub(super) unsafe extern "C" fn property_get_revert_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = StringName::new_from_string_sys(p_name);
+        let ctx = || {
+            format!(
+                "error when calling {}::property_get_revert",
+                type_name::<T>()
+            )
+        };
+
+        let return_value = handle_panic(ctx, || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            borrow_instance(instance).property_get_revert(name)
+        });
+
+        let result = match return_value {
+            Ok(return_value) => return_value
+                .map(|value| transfer_variant_to_godot(value, r_ret))
+                .is_some(),
+            Err(_) => false,
+        };
+
+        transfer_bool_to_godot(result)
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    /// - `p_property` has to be a valid pointer to a `GDExtensionPropertyInfo`, whose string pointers point to valid, mutable strings.
+    #[cfg(since_api = "4.2")]
+    pub(super) unsafe extern "C" fn validate_property_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_property: *mut sys::GDExtensionPropertyInfo,
+    ) -> sys::GDExtensionBool {
+        let ctx = || format!("error when calling {}::validate_property", type_name::<T>());
+
+        let property_sys = &mut *p_property;
+        let mut property = PropertyInfo::new_from_sys(property_sys);
+
+        let result = handle_panic(ctx, move || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            borrow_instance(instance).validate_property(&mut property);
+            property
+        });
+
+        // Leave the property untouched if the user code panicked.
+        let Ok(property) = result else {
+            return transfer_bool_to_godot(false);
+        };
+
+        property.move_into_sys(property_sys);
+        transfer_bool_to_godot(true)
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    /// - `p_name` has to be a valid pointer to a `StringName`.
+    /// - `r_is_valid` has to be a valid `GDExtensionBool` ptr which can be modified to reflect the validity of the return value.
+    #[cfg(since_api = "4.3")]
+    pub(super) unsafe extern "C" fn get_method_argument_count_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_is_valid: *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionInt {
+        let method = StringName::new_from_string_sys(p_name);
+        let ctx = || {
+            format!(
+                "error when calling {}::get_method_argument_count",
+                type_name::<T>()
+            )
+        };
+
+        let count = handle_panic(ctx, || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            borrow_instance(instance).get_method_argument_count(method)
+        })
+        .ok()
+        .flatten();
+
+        *r_is_valid = transfer_bool_to_godot(count.is_some());
+        count.unwrap_or(0) as sys::GDExtensionInt
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    pub(super) unsafe extern "C" fn notification_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_what: i32,
+        #[cfg(since_api = "4.2")] p_reversed: sys::GDExtensionBool,
+    ) {
+        // Before 4.2, notifications were always propagated from base to derived class.
+        #[cfg(before_api = "4.2")]
+        let reversed = false;
+        #[cfg(since_api = "4.2")]
+        let reversed = p_reversed != 0;
+
+        let ctx = || format!("error when calling {}::on_notification", type_name::<T>());
+
+        handle_panic(ctx, || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            with_instance_mut(instance, |this| T::on_notification(this, p_what, reversed));
+        })
+        .unwrap_or_default();
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` has to be a valid pointer that can be cast to `*mut ScriptInstanceData<T>`.
+    /// - The returned owner is not reference-counted; it is kept alive by the object the script instance is attached to.
+    pub(super) unsafe extern "C" fn get_owner_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionObjectPtr {
+        let ctx = || format!("error when calling {}::get_owner", type_name::<T>());
+
+        let owner = handle_panic(ctx, || {
+            let instance = instance_data_as_script_instance::<T>(p_instance);
+
+            borrow_instance(instance).get_owner()
+        })
+        .ok()
+        .flatten();
+
+        match owner {
+            Some(owner) => owner.obj_sys(),
+            None => instance_data_as_script_instance::<T>(p_instance)
+                .base
+                .obj_sys(),
+        }
+    }
 }
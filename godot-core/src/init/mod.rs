@@ -140,11 +140,26 @@ fn gdext_on_level_init(level: InitLevel) {
             }
         }
         crate::auto_register_classes(level);
+
+        // Docs can only be loaded into the editor help once all classes are known.
+        #[cfg(all(since_api = "4.3", feature = "register-docs"))]
+        if level == InitLevel::Editor {
+            crate::docs::register();
+        }
     }
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    // Pending async tasks may hold objects and variants, which must be released while the engine is still available.
+    #[cfg(since_api = "4.2")]
+    if level == InitLevel::Scene {
+        crate::task::cleanup();
+
+        // Script languages are unregistered before their classes.
+        crate::script::cleanup();
+    }
+
     crate::unregister_classes(level);
 
     if level == InitLevel::Core {
@@ -257,7 +272,7 @@ pub enum EditorRunBehavior {
 
     /// Runs the extension with full functionality in editor.
     ///
-    /// Ignores any `#[class(tool)]` annotations.
+    /// Ignores any `#[class(tool)]` annotations. Classes annotated with `#[class(runtime)]` are still not run in the editor.
     AllClasses,
 }
 
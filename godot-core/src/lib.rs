@@ -15,6 +15,27 @@ pub mod log;
 pub mod obj;
 pub mod property;
 
+#[cfg(since_api = "4.2")]
+pub mod script;
+#[cfg(before_api = "4.2")]
+pub mod script {
+    //! Script languages are only available from Godot 4.2 on.
+}
+
+#[cfg(since_api = "4.2")]
+pub mod task;
+#[cfg(before_api = "4.2")]
+pub mod task {
+    //! Async tasks are only available from Godot 4.2 on.
+}
+
+#[cfg(feature = "register-docs")]
+#[doc(hidden)]
+pub mod docs;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[doc(hidden)]
 #[path = "deprecated.rs"]
 pub mod __deprecated;
@@ -0,0 +1,325 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::{Any, TypeId};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::Deref;
+
+use crate::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
+use crate::obj::{bounds, Bounds, DynGdMut, DynGdRef, Gd, GodotClass, Inherits, InstanceId};
+use crate::registry;
+
+/// Implemented for user classes that can be used as trait object `Trait` inside a [`DynGd`].
+///
+/// Do not implement this manually; use the `#[godot_dyn]` attribute on an `impl Trait for MyClass` block instead. This also registers the
+/// implementation, so that objects obtained dynamically (e.g. from the scene tree or a `Variant`) can be converted to `DynGd<_, dyn Trait>`.
+pub trait AsDyn<Trait: ?Sized>: GodotClass {
+    fn dyn_upcast(&self) -> &Trait;
+    fn dyn_upcast_mut(&mut self) -> &mut Trait;
+}
+
+/// Smart pointer to a Godot object, which is also accessible through the Rust trait object `D`.
+///
+/// `T` is the static class of the object, which can be a base class such as `Node`, while `D` is typically `dyn Trait`. Any user class that
+/// implements `Trait` with `#[godot_dyn]` can be stored, regardless of its concrete type. This allows dynamic dispatch across multiple classes
+/// sharing a Rust trait, without knowing the concrete class up front.
+///
+/// `DynGd` dereferences immutably to `Gd<T>`, so methods of the static class taking `&self` remain available. There is no mutable dereference,
+/// as replacing the `Gd<T>` would leave the trait object pointing to the old instance; for `&mut self` methods, call them on a clone
+/// of the `Gd<T>` or on [`into_gd()`][Self::into_gd]. The trait object is accessed through
+/// [`dyn_bind()`][Self::dyn_bind] and [`dyn_bind_mut()`][Self::dyn_bind_mut], which follow the same borrow rules as `Gd::bind()` and
+/// `Gd::bind_mut()`. They are named differently to not shadow the latter when `T` is itself a user class.
+///
+/// # Construction
+/// - From a `Gd<T>` of a user class known to implement the trait: [`Gd::into_dyn()`].
+/// - From an arbitrary `Gd<T>`, checked at runtime: [`Gd::try_dynify()`].
+/// - From a `Variant`: [`FromGodot`], which performs the same runtime check.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// trait Health {
+///     fn hitpoints(&self) -> i32;
+///     fn deal_damage(&mut self, damage: i32);
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Monster {
+///     hp: i32,
+/// }
+///
+/// #[godot_dyn]
+/// impl Health for Monster {
+///     fn hitpoints(&self) -> i32 {
+///         self.hp
+///     }
+///
+///     fn deal_damage(&mut self, damage: i32) {
+///         self.hp -= damage;
+///     }
+/// }
+///
+/// fn hit(node: Gd<Node>) {
+///     // Works for any class implementing `Health` via #[godot_dyn].
+///     if let Ok(mut target) = node.try_dynify::<dyn Health>() {
+///         target.dyn_bind_mut().deal_damage(10);
+///         godot_print!("{} has {} HP left", target.get_name(), target.dyn_bind().hitpoints());
+///     }
+/// }
+/// ```
+pub struct DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    obj: Gd<T>,
+    erased_obj: Box<dyn ErasedDynGd<D>>,
+}
+
+impl<T, D> DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    pub(crate) fn from_gd(obj: Gd<T>) -> Self
+    where
+        T: AsDyn<D> + Bounds<Declarer = bounds::DeclUser>,
+    {
+        let erased_obj = Box::new(obj.clone());
+
+        Self { obj, erased_obj }
+    }
+
+    /// Converts `obj` if its dynamic class implements `D`; returns the original object otherwise.
+    pub(crate) fn try_from_gd(obj: Gd<T>) -> Result<Self, Gd<T>> {
+        let Some(instance_id) = obj.instance_id_or_none() else {
+            return Err(obj);
+        };
+
+        for erased_dynify_fn in registry::find_dyn_trait_impls(TypeId::of::<D>()) {
+            if let Some(erased_obj) = (erased_dynify_fn.raw)(instance_id) {
+                let erased_obj = erased_obj
+                    .downcast::<Box<dyn ErasedDynGd<D>>>()
+                    .expect("bad type erasure in DynGd; this is a bug, please report it");
+
+                return Ok(Self {
+                    obj,
+                    erased_obj: *erased_obj,
+                });
+            }
+        }
+
+        Err(obj)
+    }
+
+    /// Acquires a shared borrow of the object, accessed through the trait object `D`.
+    ///
+    /// # Panics
+    /// If the object is currently bound mutably, see [`Gd::bind()`].
+    pub fn dyn_bind(&self) -> DynGdRef<D> {
+        self.erased_obj.dyn_bind()
+    }
+
+    /// Acquires an exclusive borrow of the object, accessed through the trait object `D`.
+    ///
+    /// # Panics
+    /// If the object is currently bound, see [`Gd::bind_mut()`].
+    pub fn dyn_bind_mut(&mut self) -> DynGdMut<D> {
+        self.erased_obj.dyn_bind_mut()
+    }
+
+    /// **Upcast** to a base class, keeping the trait object accessible.
+    pub fn upcast<Base>(self) -> DynGd<Base, D>
+    where
+        Base: GodotClass,
+        T: Inherits<Base>,
+    {
+        DynGd {
+            obj: self.obj.upcast::<Base>(),
+            erased_obj: self.erased_obj,
+        }
+    }
+
+    /// **Downcast** to a derived class, keeping the trait object accessible.
+    ///
+    /// Returns the original object if the dynamic class is not `Derived` or one of its subclasses.
+    pub fn try_cast<Derived>(self) -> Result<DynGd<Derived, D>, Self>
+    where
+        Derived: GodotClass + Inherits<T>,
+    {
+        match self.obj.try_cast::<Derived>() {
+            Ok(obj) => Ok(DynGd {
+                obj,
+                erased_obj: self.erased_obj,
+            }),
+            Err(obj) => Err(Self {
+                obj,
+                erased_obj: self.erased_obj,
+            }),
+        }
+    }
+
+    /// Returns the underlying `Gd<T>`, discarding the trait object.
+    pub fn into_gd(self) -> Gd<T> {
+        self.obj
+    }
+}
+
+impl<T, D> Clone for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            obj: self.obj.clone(),
+            erased_obj: self.erased_obj.clone_box(),
+        }
+    }
+}
+
+impl<T, D> Deref for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Target = Gd<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.obj
+    }
+}
+
+impl<T, D> PartialEq for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.obj == other.obj
+    }
+}
+
+impl<T, D> Eq for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+}
+
+impl<T, D> Debug for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let trait_name = std::any::type_name::<D>();
+        write!(f, "DynGd<{trait_name}>({:?})", self.obj)
+    }
+}
+
+impl<T, D> Display for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.obj, f)
+    }
+}
+
+impl<T, D> From<DynGd<T, D>> for Gd<T>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn from(obj: DynGd<T, D>) -> Self {
+        obj.into_gd()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions
+
+impl<T, D> GodotConvert for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    type Via = Gd<T>;
+}
+
+impl<T, D> ToGodot for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn to_godot(&self) -> Self::Via {
+        self.obj.to_godot()
+    }
+
+    fn into_godot(self) -> Self::Via {
+        self.obj.into_godot()
+    }
+}
+
+impl<T, D> FromGodot for DynGd<T, D>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_gd(via).map_err(|obj| {
+            let trait_name = std::any::type_name::<D>();
+            ConvertError::with_error_value(
+                format!("object's class does not implement `{trait_name}` via #[godot_dyn]"),
+                obj,
+            )
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type erasure
+
+/// `Gd<Concrete>` with the concrete class erased, only exposing the trait object `D`.
+trait ErasedDynGd<D: ?Sized> {
+    fn dyn_bind(&self) -> DynGdRef<D>;
+    fn dyn_bind_mut(&mut self) -> DynGdMut<D>;
+    fn clone_box(&self) -> Box<dyn ErasedDynGd<D>>;
+}
+
+impl<T, D> ErasedDynGd<D> for Gd<T>
+where
+    T: AsDyn<D> + Bounds<Declarer = bounds::DeclUser>,
+    D: ?Sized + 'static,
+{
+    fn dyn_bind(&self) -> DynGdRef<D> {
+        DynGdRef::from_guard::<T>(Gd::bind(self))
+    }
+
+    fn dyn_bind_mut(&mut self) -> DynGdMut<D> {
+        DynGdMut::from_guard::<T>(Gd::bind_mut(self))
+    }
+
+    fn clone_box(&self) -> Box<dyn ErasedDynGd<D>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Registered by `#[godot_dyn]`; see [`ErasedDynifyFn`](crate::private::ErasedDynifyFn).
+pub fn erased_dynify<T, D>(instance_id: InstanceId) -> Option<Box<dyn Any>>
+where
+    T: AsDyn<D> + Bounds<Declarer = bounds::DeclUser>,
+    D: ?Sized + 'static,
+{
+    let obj = Gd::<T>::try_from_instance_id(instance_id).ok()?;
+    let erased_obj: Box<dyn ErasedDynGd<D>> = Box::new(obj);
+
+    Some(Box::new(erased_obj))
+}
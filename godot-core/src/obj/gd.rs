@@ -13,12 +13,14 @@ use godot_ffi as sys;
 use sys::{static_assert_eq_size_align, VariantType};
 
 use crate::builtin::meta::{
-    CallContext, ConvertError, FromFfiError, FromGodot, GodotConvert, GodotType, ToGodot,
+    CallContext, ConvertError, ElementArg, FromFfiError, FromGodot, GodotConvert, GodotType,
+    ToGodot,
 };
-use crate::builtin::{Callable, NodePath, StringName, Variant};
+use crate::builtin::{Callable, NodePath, Signal, StringName, Variant};
 use crate::obj::raw::RawGd;
 use crate::obj::{
-    bounds, cap, Bounds, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    bounds, cap, AsDyn, Bounds, DynGd, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass,
+    Inherits, InstanceId,
 };
 use crate::property::{Export, PropertyHintInfo, TypeStringHint, Var};
 use crate::{callbacks, engine, out};
@@ -173,6 +175,18 @@ where
     pub fn bind_mut(&mut self) -> GdMut<T> {
         self.raw.bind_mut()
     }
+
+    /// Converts into a [`DynGd`], which gives access to the object through the trait object `D`.
+    ///
+    /// `T` must implement the trait through `#[godot_dyn]`. If the concrete class isn't statically known, use
+    /// [`try_dynify()`][Self::try_dynify] instead.
+    pub fn into_dyn<D>(self) -> DynGd<T, D>
+    where
+        T: AsDyn<D>,
+        D: ?Sized + 'static,
+    {
+        DynGd::from_gd(self)
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -353,6 +367,17 @@ impl<T: GodotClass> Gd<T> {
         })
     }
 
+    /// **Dynify:** try to convert into a [`DynGd`], which gives access to the object through the trait object `D`.
+    ///
+    /// Succeeds if the dynamic class of the object implements the trait through `#[godot_dyn]`, even if `T` is a base class
+    /// such as `Node`. Otherwise, the original object is returned.
+    pub fn try_dynify<D>(self) -> Result<DynGd<T, D>, Self>
+    where
+        D: ?Sized + 'static,
+    {
+        DynGd::try_from_gd(self)
+    }
+
     /// Returns `Ok(cast_obj)` on success, `Err(self)` on error
     fn owned_cast<U>(self) -> Result<Gd<U>, Self>
     where
@@ -421,6 +446,14 @@ impl<T: GodotClass> Gd<T> {
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self, method_name)
     }
+
+    /// Returns the signal named `signal_name` of this object.
+    ///
+    /// This is shorter syntax for [`Signal::from_object_signal(self, signal_name)`][Signal::from_object_signal]. From Godot 4.2 on,
+    /// the signal can be awaited in async tasks, see [`godot::task`][crate::task].
+    pub fn signal<S: Into<StringName>>(&self, signal_name: S) -> Signal {
+        Signal::from_object_signal(self, signal_name)
+    }
 }
 
 impl<T: GodotClass> Deref for Gd<T> {
@@ -620,6 +653,8 @@ impl<T: GodotClass> GodotType for Gd<T> {
     }
 }
 
+impl<T: GodotClass, A: ToGodot<Via = Gd<T>>> ElementArg<Gd<T>> for A {}
+
 impl<T> Default for Gd<T>
 where
     T: cap::GodotDefault + Bounds<Memory = bounds::MemRefCounted>,
@@ -11,7 +11,7 @@ use godot_ffi::out;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
-use super::{Gd, GodotClass};
+use super::{AsDyn, Gd, GodotClass};
 
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
@@ -143,3 +143,84 @@ impl<T: GodotClass> DerefMut for BaseMut<'_, T> {
         &mut self.gd
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Shared reference guard for a [`DynGd`][crate::obj::DynGd] smart pointer, dereferencing to the trait object `D`.
+///
+/// See [`DynGd::dyn_bind`][crate::obj::DynGd::dyn_bind] for usage.
+pub struct DynGdRef<'a, D: ?Sized> {
+    /// Keeps the concrete class borrowed; its type is erased.
+    _guard: Box<dyn ErasedGuard<'a>>,
+
+    /// Points into the user instance borrowed by `_guard`, which is not moved while the guard lives.
+    cached_ptr: *const D,
+}
+
+impl<'a, D: ?Sized + 'static> DynGdRef<'a, D> {
+    pub(crate) fn from_guard<T: AsDyn<D>>(guard: GdRef<'a, T>) -> Self {
+        let obj = &*guard;
+        let dyn_obj = obj.dyn_upcast();
+        let cached_ptr = std::ptr::addr_of!(*dyn_obj);
+
+        Self {
+            _guard: Box::new(guard),
+            cached_ptr,
+        }
+    }
+}
+
+impl<D: ?Sized> Deref for DynGdRef<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        // SAFETY: pointer refers to the object borrowed by the guard, which lives as long as `self`.
+        unsafe { &*self.cached_ptr }
+    }
+}
+
+/// Mutable/exclusive reference guard for a [`DynGd`][crate::obj::DynGd] smart pointer, dereferencing to the trait object `D`.
+///
+/// See [`DynGd::dyn_bind_mut`][crate::obj::DynGd::dyn_bind_mut] for usage.
+pub struct DynGdMut<'a, D: ?Sized> {
+    /// Keeps the concrete class borrowed; its type is erased.
+    _guard: Box<dyn ErasedGuard<'a>>,
+
+    /// Points into the user instance borrowed by `_guard`, which is not moved while the guard lives.
+    cached_ptr: *mut D,
+}
+
+impl<'a, D: ?Sized + 'static> DynGdMut<'a, D> {
+    pub(crate) fn from_guard<T: AsDyn<D>>(mut guard: GdMut<'a, T>) -> Self {
+        let obj = &mut *guard;
+        let dyn_obj = obj.dyn_upcast_mut();
+        let cached_ptr = std::ptr::addr_of_mut!(*dyn_obj);
+
+        Self {
+            _guard: Box::new(guard),
+            cached_ptr,
+        }
+    }
+}
+
+impl<D: ?Sized> Deref for DynGdMut<'_, D> {
+    type Target = D;
+
+    fn deref(&self) -> &D {
+        // SAFETY: pointer refers to the object exclusively borrowed by the guard, which lives as long as `self`.
+        unsafe { &*self.cached_ptr }
+    }
+}
+
+impl<D: ?Sized> DerefMut for DynGdMut<'_, D> {
+    fn deref_mut(&mut self) -> &mut D {
+        // SAFETY: pointer refers to the object exclusively borrowed by the guard, which lives as long as `self`.
+        unsafe { &mut *self.cached_ptr }
+    }
+}
+
+/// Guard whose concrete class has been erased; only needed for its `Drop` impl.
+trait ErasedGuard<'a>: 'a {}
+
+impl<'a, T: GodotClass> ErasedGuard<'a> for GdRef<'a, T> {}
+impl<'a, T: GodotClass> ErasedGuard<'a> for GdMut<'a, T> {}
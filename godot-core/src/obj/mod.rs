@@ -18,16 +18,20 @@ mod instance_id;
 mod onready;
 mod raw;
 mod traits;
+mod typed_signal;
 
+pub(crate) mod dyn_gd;
 pub(crate) mod rtti;
 
 pub use base::*;
+pub use dyn_gd::{AsDyn, DynGd};
 pub use gd::*;
 pub use guards::*;
 pub use instance_id::*;
 pub use onready::*;
 pub use raw::*;
 pub use traits::*;
+pub use typed_signal::*;
 
 pub mod bounds;
 pub use bounds::private::Bounds;
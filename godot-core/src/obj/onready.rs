@@ -193,6 +193,10 @@ impl<T> std::ops::DerefMut for OnReady<T> {
 
 impl<T: GodotConvert> GodotConvert for OnReady<T> {
     type Via = T::Via;
+
+    fn godot_property_info(property_name: &str) -> crate::builtin::meta::PropertyInfo {
+        T::godot_property_info(property_name)
+    }
 }
 
 impl<T: Var> Var for OnReady<T> {
@@ -52,6 +52,14 @@ where
             Self::Base::inherits::<U>()
         }
     }
+
+    /// Function configuring the `#[rpc]` methods of a new instance, if the class declares any.
+    ///
+    /// Invoked on every instance creation; user classes resolve it only once per class.
+    #[doc(hidden)]
+    fn __rpc_register_fn() -> Option<crate::private::ErasedRegisterFn> {
+        None
+    }
 }
 
 /// Type representing the absence of a base class, at the root of the hierarchy.
@@ -406,6 +414,7 @@ where
 /// Capability traits, providing dedicated functionalities for Godot classes
 pub mod cap {
     use super::*;
+    use crate::builtin::meta::{GodotConvert, PropertyInfo};
     use crate::builtin::{StringName, Variant};
     use crate::obj::{Base, Bounds, Gd};
 
@@ -486,12 +495,41 @@ pub mod cap {
         fn __godot_set_property(&mut self, property: StringName, value: Variant) -> bool;
     }
 
+    #[doc(hidden)]
+    pub trait GodotGetPropertyList: GodotClass {
+        #[doc(hidden)]
+        fn __godot_get_property_list(&mut self) -> Vec<PropertyInfo>;
+    }
+
+    #[doc(hidden)]
+    pub trait GodotPropertyGetRevert: GodotClass {
+        #[doc(hidden)]
+        fn __godot_property_get_revert(&self, property: StringName) -> Option<Variant>;
+    }
+
+    #[doc(hidden)]
+    #[cfg(since_api = "4.2")]
+    pub trait GodotValidateProperty: GodotClass {
+        #[doc(hidden)]
+        fn __godot_validate_property(&self, property: &mut PropertyInfo);
+    }
+
     /// Auto-implemented for `#[godot_api] impl MyClass` blocks
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
         fn __register_methods();
         #[doc(hidden)]
         fn __register_constants();
+
+        /// Configures `#[rpc]` methods on a new instance. `base` holds a `Gd<Self::Base>`.
+        #[doc(hidden)]
+        fn __register_rpcs(_base: &mut dyn std::any::Any) {}
+    }
+
+    /// Auto-implemented for enums with `#[derive(GodotConvert)]` and `#[godot(class = MyClass)]`.
+    pub trait ImplementsClassEnum: GodotConvert {
+        #[doc(hidden)]
+        fn __register_enum();
     }
 
     pub trait ImplementsGodotExports: GodotClass {
@@ -0,0 +1,313 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::meta::{FromGodot, ParamTuple, ToGodot};
+use crate::builtin::{Callable, Signal, StringName};
+use crate::engine::Object;
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, WithBaseField};
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::meta::CallContext;
+#[cfg(since_api = "4.2")]
+use crate::builtin::Variant;
+#[cfg(since_api = "4.2")]
+use crate::obj::InstanceId;
+
+/// Statically typed signal of a user-defined class `C`, with parameters `Ps` (a tuple).
+///
+/// Generated by `#[signal]` declarations inside `#[godot_api]` blocks. For a class `MyClass`, every signal is available as a method on the
+/// `MyClassSignals` struct, which is returned by `self.signals()`:
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i32, source: Gd<Node>);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i32, source: Gd<Node>) {
+///         // Parameter types and count are checked at compile time.
+///         self.signals().hit().emit(damage, source);
+///     }
+///
+///     #[func]
+///     fn on_hit(&mut self, damage: i32, _source: Gd<Node>) {
+///         godot_print!("Ouch, lost {damage} HP");
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl INode for Player {
+///     fn ready(&mut self) {
+///         self.signals().hit().connect_self(Self::on_hit);
+///     }
+/// }
+/// ```
+///
+/// Emitting and connecting go through [`WithBaseField::base_mut()`], so handlers are allowed to call back into the same object.
+///
+/// Methods that are only available on certain Godot versions or class configurations are bounded accordingly. Notably, `emit()` and
+/// `connect*()` require the class to have a `Base<T>` field.
+pub struct TypedSignal<'c, C, Ps> {
+    owner: &'c mut C,
+    name: &'static str,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+impl<'c, C, Ps> TypedSignal<'c, C, Ps> {
+    #[doc(hidden)]
+    pub fn new(owner: &'c mut C, name: &'static str) -> Self {
+        Self {
+            owner,
+            name,
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the signal, as registered in Godot.
+    pub fn name(&self) -> StringName {
+        StringName::from(self.name)
+    }
+}
+
+impl<'c, C, Ps> TypedSignal<'c, C, Ps>
+where
+    C: WithBaseField,
+    C::Base: Inherits<Object>,
+    Ps: ParamTuple,
+{
+    /// Emits the signal with the given arguments, passed as a tuple.
+    ///
+    /// Usually you would call `emit()` instead, which takes the arguments individually.
+    pub fn emit_tuple(&mut self, args: Ps) {
+        let name = self.name();
+        let varargs = args.to_variant_args();
+
+        let mut base = self.owner.base_mut();
+        base.upcast_mut::<Object>().emit_signal(name, &varargs);
+    }
+
+    /// Returns the untyped [`Signal`] for the same object and name.
+    pub fn to_untyped(&self) -> Signal {
+        Signal::from_object_signal(&self.owner.to_gd(), self.name)
+    }
+
+    /// Returns a future that resolves the next time this signal is emitted, yielding the arguments as a tuple.
+    ///
+    /// See [`godot::task`][crate::task] for running futures.
+    #[cfg(since_api = "4.2")]
+    pub fn to_future(&self) -> crate::task::SignalFuture<Ps>
+    where
+        Ps: 'static,
+    {
+        self.to_untyped().to_future()
+    }
+
+    /// Connects the signal to a Rust closure or function, which receives the signal arguments.
+    ///
+    /// Parameter types are checked at compile time. The closure must be `Send + Sync`, since Godot may invoke callables from any thread.
+    #[cfg(since_api = "4.2")]
+    pub fn connect<F>(&mut self, function: F)
+    where
+        F: SignalReceiver<(), Ps> + Send + Sync,
+    {
+        let callable = make_callable_fn(C::class_name().as_str(), self.name, function);
+        self.connect_callable(callable);
+    }
+
+    /// Connects the signal to a method of `C`, invoked on the same object that emits the signal.
+    ///
+    /// The method is called with a mutable borrow of the object (as with `Gd::bind_mut()`), so it can be a plain `&mut self` method.
+    #[cfg(since_api = "4.2")]
+    pub fn connect_self<F>(&mut self, method: F)
+    where
+        F: for<'a> SignalReceiver<&'a mut C, Ps> + Send + Sync,
+    {
+        let object = self.owner.to_gd();
+        self.connect_obj(&object, method);
+    }
+
+    /// Connects the signal to a method of another object `object`, of user-defined class `R`.
+    ///
+    /// The connection only refers to `object` by instance ID. If `object` is freed before the signal is emitted, an error is printed.
+    #[cfg(since_api = "4.2")]
+    pub fn connect_obj<R, F>(&mut self, object: &Gd<R>, method: F)
+    where
+        R: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+        F: for<'a> SignalReceiver<&'a mut R, Ps> + Send + Sync,
+    {
+        let callable = make_callable_method(object.instance_id(), self.name, method);
+        self.connect_callable(callable);
+    }
+
+    /// Connects the signal to an arbitrary untyped [`Callable`].
+    ///
+    /// No type checks take place; prefer the other `connect*()` methods if possible.
+    pub fn connect_callable(&mut self, callable: Callable) {
+        let name = self.name();
+
+        let mut base = self.owner.base_mut();
+        base.upcast_mut::<Object>().connect(name, callable);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Emit overloads with individual parameters
+
+macro_rules! impl_typed_signal_emit {
+    (
+        $( ($pn:ident, $n:tt) : $Pn:ident ),*
+    ) => {
+        impl<'c, C, $($Pn,)*> TypedSignal<'c, C, ($($Pn,)*)>
+        where
+            C: WithBaseField,
+            C::Base: Inherits<Object>,
+            $( $Pn: FromGodot + ToGodot, )*
+        {
+            /// Emits the signal with the given arguments.
+            ///
+            /// All objects connected to this signal are notified.
+            pub fn emit(&mut self, $($pn: $Pn),*) {
+                self.emit_tuple(($($pn,)*));
+            }
+        }
+    };
+}
+
+impl_typed_signal_emit!();
+impl_typed_signal_emit!((p0, 0): P0);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8);
+impl_typed_signal_emit!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Receivers
+
+/// Function or closure that can be connected to a [`TypedSignal`] with parameters `Ps`.
+///
+/// `I` is `()` for free functions and closures, and `&mut C` for methods taking a receiver of class `C`.
+///
+/// This trait is automatically implemented for all matching `FnMut` types; you don't need to implement it yourself.
+pub trait SignalReceiver<I, Ps>: 'static {
+    /// Invokes the receiver with the given instance (or `()`) and parameters.
+    fn call(&mut self, maybe_instance: I, params: Ps);
+}
+
+macro_rules! impl_signal_receiver {
+    (
+        $( ($pn:ident, $n:tt) : $Pn:ident ),*
+    ) => {
+        impl<F, $($Pn,)*> SignalReceiver<(), ($($Pn,)*)> for F
+        where
+            F: FnMut($($Pn),*) + 'static,
+        {
+            fn call(&mut self, _maybe_instance: (), ($($pn,)*): ($($Pn,)*)) {
+                self($($pn),*)
+            }
+        }
+
+        impl<'c, C, F, $($Pn,)*> SignalReceiver<&'c mut C, ($($Pn,)*)> for F
+        where
+            C: 'c,
+            F: FnMut(&mut C, $($Pn),*) + 'static,
+        {
+            fn call(&mut self, instance: &'c mut C, ($($pn,)*): ($($Pn,)*)) {
+                self(instance, $($pn),*)
+            }
+        }
+    };
+}
+
+impl_signal_receiver!();
+impl_signal_receiver!((p0, 0): P0);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8);
+impl_signal_receiver!((p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Callable construction
+
+#[cfg(since_api = "4.2")]
+fn make_callable_fn<Ps, F>(
+    class_name: &'static str,
+    signal_name: &'static str,
+    mut function: F,
+) -> Callable
+where
+    Ps: ParamTuple,
+    F: SignalReceiver<(), Ps> + Send + Sync,
+{
+    let callable_name = format!("{class_name}::{signal_name}");
+
+    Callable::from_fn(callable_name, move |args: &[&Variant]| {
+        let call_ctx = CallContext::func(class_name, signal_name);
+        let params = convert_params::<Ps>(args, &call_ctx)?;
+
+        function.call((), params);
+        Ok(Variant::nil())
+    })
+}
+
+#[cfg(since_api = "4.2")]
+fn make_callable_method<R, Ps, F>(
+    instance_id: InstanceId,
+    signal_name: &'static str,
+    mut method: F,
+) -> Callable
+where
+    R: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+    Ps: ParamTuple,
+    F: for<'a> SignalReceiver<&'a mut R, Ps> + Send + Sync,
+{
+    let class_name = R::class_name().as_str();
+    let callable_name = format!("{class_name}::{signal_name}");
+
+    Callable::from_fn(callable_name, move |args: &[&Variant]| {
+        let call_ctx = CallContext::func(class_name, signal_name);
+        let params = convert_params::<Ps>(args, &call_ctx)?;
+
+        let Ok(mut object) = Gd::<R>::try_from_instance_id(instance_id) else {
+            crate::godot_error!(
+                "{call_ctx}: receiver object {instance_id} was freed before signal was emitted"
+            );
+            return Err(());
+        };
+
+        let mut guard = object.bind_mut();
+        method.call(&mut *guard, params);
+        Ok(Variant::nil())
+    })
+}
+
+#[cfg(since_api = "4.2")]
+fn convert_params<Ps: ParamTuple>(args: &[&Variant], call_ctx: &CallContext) -> Result<Ps, ()> {
+    Ps::from_variant_args(args, call_ctx).map_err(|err| {
+        crate::godot_error!("{err}");
+    })
+}
@@ -9,12 +9,20 @@ use godot_ffi::Global;
 use std::collections::HashMap;
 use std::sync::{atomic, Arc, Mutex};
 
+#[cfg(feature = "register-docs")]
+pub use crate::docs::{
+    docs_type_name, gather_xml_docs, DocsItem, DocsPlugin, InherentImplDocs, StructDocs,
+};
 pub use crate::gen::classes::class_macros;
-pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginItem};
+pub use crate::obj::dyn_gd::erased_dynify;
+pub use crate::registry::{
+    callbacks, find_rpc_register_fn, ClassPlugin, ErasedDynifyFn, ErasedRegisterFn, PluginItem,
+};
 pub use crate::storage::{as_storage, Storage};
 pub use sys::out;
 
-use crate::builtin::meta::{CallContext, CallError};
+use crate::builtin::meta::{CallContext, CallError, ConvertError, FromGodot};
+use crate::builtin::{Dictionary, GString, Variant};
 use crate::{log, sys};
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -29,6 +37,8 @@ static CALL_ERRORS: Global<CallErrors> = Global::default();
 static ERROR_PRINT_LEVEL: atomic::AtomicU8 = atomic::AtomicU8::new(2);
 
 sys::plugin_registry!(pub __GODOT_PLUGIN_REGISTRY: ClassPlugin);
+#[cfg(feature = "register-docs")]
+sys::plugin_registry!(pub __GODOT_DOCS_REGISTRY: DocsPlugin);
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Call error handling
@@ -82,6 +92,70 @@ pub(crate) fn call_error_remove(in_error: &sys::GDExtensionCallError) -> Option<
     call_error
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Thread confinement
+
+/// Value that can only be accessed on the thread it was created on.
+///
+/// Allows storing non-`Send` values (such as `Variant` or closures capturing `Gd<T>`) in objects that Godot may move to, or destroy on,
+/// other threads. On those threads, the value is inaccessible. If the holder is dropped on another thread, the value is leaked and an
+/// error is printed, since running its destructor there would be unsound.
+#[cfg(since_api = "4.2")]
+pub(crate) struct ThreadConfined<T> {
+    value: std::mem::ManuallyDrop<T>,
+    thread_id: std::thread::ThreadId,
+}
+
+#[cfg(since_api = "4.2")]
+impl<T> ThreadConfined<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value: std::mem::ManuallyDrop::new(value),
+            thread_id: std::thread::current().id(),
+        }
+    }
+
+    /// Returns the value if called from the owning thread, otherwise `None`.
+    pub fn get(&self) -> Option<&T> {
+        self.is_owning_thread().then_some(&*self.value)
+    }
+
+    /// Returns the value mutably if called from the owning thread, otherwise `None`.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.is_owning_thread() {
+            Some(&mut *self.value)
+        } else {
+            None
+        }
+    }
+
+    fn is_owning_thread(&self) -> bool {
+        std::thread::current().id() == self.thread_id
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl<T> Drop for ThreadConfined<T> {
+    fn drop(&mut self) {
+        if self.is_owning_thread() {
+            // SAFETY: value is not accessed after this point.
+            unsafe { std::mem::ManuallyDrop::drop(&mut self.value) };
+        } else {
+            log::godot_error!(
+                "thread-confined value of type `{}` dropped on a different thread than it was created on; leaking it",
+                std::any::type_name::<T>()
+            );
+        }
+    }
+}
+
+// SAFETY: the value is only accessed (and dropped) on the thread that created it.
+#[cfg(since_api = "4.2")]
+unsafe impl<T> Send for ThreadConfined<T> {}
+// SAFETY: the value is only accessed on the thread that created it.
+#[cfg(since_api = "4.2")]
+unsafe impl<T> Sync for ThreadConfined<T> {}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Plugin handling
 
@@ -89,6 +163,11 @@ pub(crate) fn iterate_plugins(mut visitor: impl FnMut(&ClassPlugin)) {
     sys::plugin_foreach!(__GODOT_PLUGIN_REGISTRY; visitor);
 }
 
+#[cfg(feature = "register-docs")]
+pub(crate) fn iterate_docs_plugins(mut visitor: impl FnMut(&DocsPlugin)) {
+    sys::plugin_foreach!(__GODOT_DOCS_REGISTRY; visitor);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Traits and types
 
@@ -99,7 +178,11 @@ pub trait You_forgot_the_attribute__godot_api {}
 pub use crate::obj::rtti::ObjectRtti;
 
 pub struct ClassConfig {
+    /// Whether `#[class(tool)]` was used.
     pub is_tool: bool,
+
+    /// Whether `#[class(runtime)]` was used.
+    pub is_runtime: bool,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -125,10 +208,27 @@ pub fn flush_stdout() {
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::engine::EditorPlugin>>() {}
 
+/// Returns the instance registered by `#[class(singleton)]`; used by the generated `singleton()` accessor.
+pub fn user_singleton<T: crate::obj::Inherits<crate::engine::Object>>() -> crate::obj::Gd<T> {
+    let class_name = T::class_name();
+    let name = class_name.to_string_name();
+    let engine = crate::engine::Engine::singleton();
+
+    assert!(
+        engine.has_singleton(name.clone()),
+        "singleton `{class_name}` is not registered; its init level may not be loaded yet"
+    );
+
+    engine
+        .get_singleton(name)
+        .expect("registered singleton is null")
+        .cast::<T>()
+}
+
 // Starting from 4.3, Godot has "runtime classes"; this emulation is no longer needed.
 #[cfg(before_api = "4.3")]
-pub fn is_class_inactive(is_tool: bool) -> bool {
-    if is_tool {
+pub fn is_class_inactive(config: &ClassConfig) -> bool {
+    if config.is_tool {
         return false;
     }
 
@@ -136,17 +236,22 @@ pub fn is_class_inactive(is_tool: bool) -> bool {
     let global_config = unsafe { sys::config() };
     let is_editor = || crate::engine::Engine::singleton().is_editor_hint();
 
-    global_config.tool_only_in_editor //.
+    (config.is_runtime || global_config.tool_only_in_editor)
         && global_config.is_editor_or_init(is_editor)
 }
 
 // Starting from 4.3, Godot has "runtime classes"; we only need to check whether editor is running.
 #[cfg(since_api = "4.3")]
-pub fn is_class_runtime(is_tool: bool) -> bool {
-    if is_tool {
+pub fn is_class_runtime(config: &ClassConfig) -> bool {
+    if config.is_tool {
         return false;
     }
 
+    // An explicit #[class(runtime)] is never run in the editor, regardless of the library-wide setting.
+    if config.is_runtime {
+        return true;
+    }
+
     // SAFETY: only invoked after global library initialization.
     let global_config = unsafe { sys::config() };
 
@@ -155,6 +260,102 @@ pub fn is_class_runtime(is_tool: bool) -> bool {
     global_config.tool_only_in_editor
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion of #[derive(GodotConvert)] types via Dictionary
+
+/// Reads and converts the value stored under `key`; returns `Ok(None)` if the key is absent.
+pub fn dict_get_field<T: FromGodot>(
+    dict: &Dictionary,
+    key: &str,
+    type_name: &str,
+) -> Result<Option<T>, ConvertError> {
+    let Some(value) = dict.get(key) else {
+        return Ok(None);
+    };
+
+    match value.try_to::<T>() {
+        Ok(converted) => Ok(Some(converted)),
+        Err(err) => Err(ConvertError::with_error_value(
+            format!("{type_name}: invalid value for key `{key}`: {err}"),
+            value,
+        )),
+    }
+}
+
+pub fn dict_missing_field(dict: &Dictionary, key: &str, type_name: &str) -> ConvertError {
+    ConvertError::with_error_value(format!("{type_name}: missing key `{key}`"), dict.clone())
+}
+
+/// Reads the variant name of a tagged dictionary.
+pub fn dict_get_tag(
+    dict: &Dictionary,
+    tag_key: &str,
+    type_name: &str,
+) -> Result<String, ConvertError> {
+    match dict_get_field::<GString>(dict, tag_key, type_name)? {
+        Some(tag) => Ok(tag.to_string()),
+        None => Err(dict_missing_field(dict, tag_key, type_name)),
+    }
+}
+
+pub fn dict_unknown_tag(
+    dict: &Dictionary,
+    tag_key: &str,
+    tag: &str,
+    type_name: &str,
+) -> ConvertError {
+    ConvertError::with_error_value(
+        format!("{type_name}: unknown variant `{tag}` for key `{tag_key}`"),
+        dict.clone(),
+    )
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Default arguments of #[func]
+
+/// Invokes `call` with the varcall arguments, appending default values for trailing parameters that the caller omitted.
+///
+/// `make_defaults` returns the default values of the last parameters, as declared with `#[opt(default = ...)]`.
+/// If the argument count is invalid even with defaults, the arguments are forwarded unchanged, so that the regular
+/// argument count check reports the error.
+///
+/// # Safety
+/// `args_ptr` must be null or point to `arg_count` valid variant pointers.
+pub unsafe fn varcall_with_defaults<R>(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    param_count: usize,
+    make_defaults: fn() -> Vec<Variant>,
+    call: impl FnOnce(*const sys::GDExtensionConstVariantPtr, sys::GDExtensionInt) -> R,
+) -> R {
+    let given_count = arg_count as usize;
+    if given_count >= param_count {
+        return call(args_ptr, arg_count);
+    }
+
+    let defaults = make_defaults();
+    let required_count = param_count - defaults.len();
+    if given_count < required_count {
+        return call(args_ptr, arg_count);
+    }
+
+    let mut args = if args_ptr.is_null() {
+        Vec::with_capacity(param_count)
+    } else {
+        // SAFETY: `args_ptr` points to `arg_count` valid pointers, as per the safety contract.
+        unsafe { std::slice::from_raw_parts(args_ptr, given_count) }.to_vec()
+    };
+
+    // The defaults outlive `call`, so the pointers remain valid during the invocation.
+    args.extend(
+        defaults[given_count - required_count..]
+            .iter()
+            .map(Variant::var_sys),
+    );
+
+    call(args.as_ptr(), param_count as sys::GDExtensionInt)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Panic handling
 
@@ -230,6 +431,13 @@ pub fn handle_varcall_panic<F, R>(
         Err(panic_msg) => CallError::failed_by_user_panic(&call_ctx, panic_msg),
     };
 
+    report_call_error(call_error, out_err);
+
+    //sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
+}
+
+/// Prints `call_error` (depending on error print level) and stores it in `out_err`, to be picked up by the caller.
+pub(crate) fn report_call_error(call_error: CallError, out_err: &mut sys::GDExtensionCallError) {
     // Print failed calls to Godot's console.
     // TODO Level 1 is not yet set, so this will always print if level != 0. Needs better logic to recognize try_* calls and avoid printing.
     // But a bit tricky with multiple threads and re-entrancy; maybe pass in info in error struct.
@@ -239,8 +447,6 @@ pub fn handle_varcall_panic<F, R>(
 
     out_err.error = sys::GODOT_RUST_CUSTOM_CALL_ERROR;
     call_error_insert(call_error, out_err);
-
-    //sys::interface_fn!(variant_new_nil)(sys::AsUninit::as_uninit(ret));
 }
 
 fn handle_panic_with_print<E, F, R, S>(error_context: E, code: F, print: bool) -> Result<R, String>
@@ -7,6 +7,9 @@
 
 //! Registration support for property types.
 
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
 use crate::builtin::meta::{FromGodot, GodotConvert, ToGodot};
 use crate::builtin::GString;
 use crate::engine::global::PropertyHint;
@@ -92,6 +95,90 @@ where
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Blanket impls for Rust collections
+
+// These use the hints of their `Via` types: `Array<T::Via>` for sequences, `Dictionary` for maps and `VariantArray` for tuples.
+
+macro_rules! impl_property_for_collection {
+    (
+        impl<$($Gen:ident),* $(; const $N:ident: usize)?> for $Ty:ty
+        where $($bounds:tt)*
+    ) => {
+        impl<$($Gen,)* $(const $N: usize)?> Var for $Ty
+        where
+            $($bounds)*
+        {
+            fn get_property(&self) -> Self::Via {
+                self.to_godot()
+            }
+
+            fn set_property(&mut self, value: Self::Via) {
+                *self = FromGodot::from_godot(value);
+            }
+
+            fn property_hint() -> PropertyHintInfo {
+                <<Self as GodotConvert>::Via as Var>::property_hint()
+            }
+        }
+
+        impl<$($Gen,)* $(const $N: usize)?> Export for $Ty
+        where
+            $($bounds)*
+            <Self as GodotConvert>::Via: Export,
+        {
+            fn default_export_info() -> PropertyHintInfo {
+                <<Self as GodotConvert>::Via as Export>::default_export_info()
+            }
+        }
+
+        impl<$($Gen,)* $(const $N: usize)?> TypeStringHint for $Ty
+        where
+            $($bounds)*
+            <Self as GodotConvert>::Via: TypeStringHint,
+        {
+            fn type_string() -> String {
+                <<Self as GodotConvert>::Via as TypeStringHint>::type_string()
+            }
+        }
+    };
+}
+
+impl_property_for_collection!(
+    impl<T> for Vec<T>
+    where T: ToGodot + FromGodot,
+);
+impl_property_for_collection!(
+    impl<T; const N: usize> for [T; N]
+    where T: ToGodot + FromGodot,
+);
+impl_property_for_collection!(
+    impl<T, S> for HashSet<T, S>
+    where T: ToGodot + FromGodot + Eq + Hash, S: BuildHasher + Default,
+);
+impl_property_for_collection!(
+    impl<K, V, S> for HashMap<K, V, S>
+    where K: ToGodot + FromGodot + Eq + Hash, V: ToGodot + FromGodot, S: BuildHasher + Default,
+);
+
+macro_rules! impl_property_for_tuple {
+    ($($Tn:ident),+) => {
+        impl_property_for_collection!(
+            impl<$($Tn),+> for ($($Tn,)+)
+            where $($Tn: ToGodot + FromGodot,)+
+        );
+    };
+}
+
+impl_property_for_tuple!(T0);
+impl_property_for_tuple!(T0, T1);
+impl_property_for_tuple!(T0, T1, T2);
+impl_property_for_tuple!(T0, T1, T2, T3);
+impl_property_for_tuple!(T0, T1, T2, T3, T4);
+impl_property_for_tuple!(T0, T1, T2, T3, T4, T5);
+impl_property_for_tuple!(T0, T1, T2, T3, T4, T5, T6);
+impl_property_for_tuple!(T0, T1, T2, T3, T4, T5, T6, T7);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Export machinery
 
@@ -117,8 +204,11 @@ impl PropertyHintInfo {
 
 /// Functions used to translate user-provided arguments into export hints.
 pub mod export_info_functions {
+    use crate::builtin::meta::ClassName;
     use crate::builtin::GString;
     use crate::engine::global::PropertyHint;
+    use crate::engine::Node;
+    use crate::obj::{GodotClass, Inherits};
 
     use super::PropertyHintInfo;
 
@@ -279,6 +369,29 @@ pub mod export_info_functions {
         }
     }
 
+    /// Restricts a `NodePath` property to nodes of the given classes, like `@export_node_path("Camera3D", "Camera2D")`.
+    ///
+    /// An empty list allows any node. Class names are typically obtained via [`node_path_class()`].
+    pub fn export_node_path(class_names: &[ClassName]) -> PropertyHintInfo {
+        let hint_string = class_names
+            .iter()
+            .map(|class_name| class_name.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        PropertyHintInfo {
+            hint: PropertyHint::NODE_PATH_VALID_TYPES,
+            hint_string: hint_string.into(),
+        }
+    }
+
+    /// Class name of `T`, for use in [`export_node_path()`].
+    ///
+    /// Fails to compile if `T` is not a node class (engine or user-defined).
+    pub fn node_path_class<T: Inherits<Node>>() -> ClassName {
+        T::class_name()
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -11,6 +11,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::builder::ClassBuilder;
+use crate::builtin::meta::PropertyInfo;
 use crate::builtin::{StringName, Variant};
 use crate::obj::{cap, Base, GodotClass, UserClass};
 use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
@@ -67,6 +68,8 @@ where
     let base = unsafe { Base::from_sys(base_ptr) };
     let user_instance = make_user_instance(unsafe { Base::from_base(&base) });
 
+    crate::registry::auto_register_rpcs::<T>(&base);
+
     let instance = InstanceStorage::<T>::construct(user_instance, base);
     let instance_ptr = instance.into_raw();
     let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
@@ -191,6 +194,109 @@ pub unsafe extern "C" fn set_property<T: cap::GodotSet>(
     T::__godot_set_property(&mut *instance, property, value) as sys::GDExtensionBool
 }
 
+/// Returns the properties from `get_property_list()`, to be released by [`free_property_list`].
+///
+/// The returned list is preceded by a hidden element, whose `name` field stores a pointer to the boxed Rust-side `Vec<PropertyInfo>`.
+/// This keeps the strings referenced by the FFI list alive, and allows freeing without knowing the list length (Godot < 4.3 doesn't pass it).
+pub unsafe extern "C" fn get_property_list<T: cap::GodotGetPropertyList>(
+    instance: sys::GDExtensionClassInstancePtr,
+    count: *mut u32,
+) -> *const sys::GDExtensionPropertyInfo {
+    let storage = as_storage::<T>(instance);
+    let mut instance = storage.get_mut();
+
+    let property_list = Box::new(T::__godot_get_property_list(&mut *instance));
+
+    let mut list_sys = Vec::with_capacity(property_list.len() + 1);
+    list_sys.push(PropertyInfo::empty_sys());
+    list_sys.extend(property_list.iter().map(PropertyInfo::property_sys));
+
+    *count = property_list.len() as u32;
+    list_sys[0].name = Box::into_raw(property_list).cast();
+
+    let list_sys =
+        Box::into_raw(list_sys.into_boxed_slice()).cast::<sys::GDExtensionPropertyInfo>();
+
+    // Skip the hidden element.
+    list_sys.add(1)
+}
+
+#[cfg(before_api = "4.3")]
+pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+) {
+    free_property_list_inner(list)
+}
+
+#[cfg(since_api = "4.3")]
+pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    list: *const sys::GDExtensionPropertyInfo,
+    _count: u32,
+) {
+    free_property_list_inner(list)
+}
+
+/// Releases a list allocated by [`get_property_list`].
+unsafe fn free_property_list_inner(list: *const sys::GDExtensionPropertyInfo) {
+    let list_sys = sys::force_mut_ptr(list).sub(1);
+
+    let property_list = Box::from_raw((*list_sys).name.cast::<Vec<PropertyInfo>>());
+    let len = property_list.len() + 1;
+
+    // Drop the FFI list before the Rust-side values it points to.
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        list_sys, len,
+    )));
+    drop(property_list);
+}
+
+pub unsafe extern "C" fn property_can_revert<T: cap::GodotPropertyGetRevert>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+    let property = StringName::new_from_string_sys(name);
+
+    T::__godot_property_get_revert(&*instance, property).is_some() as sys::GDExtensionBool
+}
+
+pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert>(
+    instance: sys::GDExtensionClassInstancePtr,
+    name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+    let property = StringName::new_from_string_sys(name);
+
+    match T::__godot_property_get_revert(&*instance, property) {
+        Some(value) => {
+            value.move_into_var_ptr(ret);
+            true as sys::GDExtensionBool
+        }
+        None => false as sys::GDExtensionBool,
+    }
+}
+
+#[cfg(since_api = "4.2")]
+pub unsafe extern "C" fn validate_property<T: cap::GodotValidateProperty>(
+    instance: sys::GDExtensionClassInstancePtr,
+    property_info: *mut sys::GDExtensionPropertyInfo,
+) -> sys::GDExtensionBool {
+    let storage = as_storage::<T>(instance);
+    let instance = storage.get();
+
+    let property_info = &mut *property_info;
+    let mut property = PropertyInfo::new_from_sys(property_info);
+    T::__godot_validate_property(&*instance, &mut property);
+    property.move_into_sys(property_info);
+
+    true as sys::GDExtensionBool
+}
+
 pub unsafe extern "C" fn reference<T: GodotClass>(instance: sys::GDExtensionClassInstancePtr) {
     let storage = as_storage::<T>(instance);
     storage.on_inc_ref();
@@ -229,6 +335,10 @@ pub fn register_user_properties<T: cap::ImplementsGodotExports>(_class_builder:
     T::__register_exports();
 }
 
+pub fn register_class_enum<E: cap::ImplementsClassEnum>(_class_builder: &mut dyn Any) {
+    E::__register_enum();
+}
+
 pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builder: &mut dyn Any) {
     // let class_builder = class_builder
     //     .downcast_mut::<ClassBuilder<T>>()
@@ -238,3 +348,7 @@ pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builde
     T::__register_methods();
     T::__register_constants();
 }
+
+pub fn register_user_rpcs<T: cap::ImplementsGodotApi>(base: &mut dyn Any) {
+    T::__register_rpcs(base);
+}
@@ -6,11 +6,12 @@
  */
 
 use crate::builtin::meta::ClassName;
+use crate::engine::{EditorInspectorPlugin, EditorPlugin, Engine, Object};
 use crate::init::InitLevel;
-use crate::obj::{cap, GodotClass};
+use crate::obj::{cap, Base, Gd, GodotClass, InstanceId, NewAlloc};
 use crate::{godot_error, out};
 use godot_ffi as sys;
-use std::any::Any;
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::{fmt, ptr};
 use sys::{interface_fn, Global, GlobalGuard, GlobalLockError};
@@ -23,6 +24,17 @@ pub mod callbacks;
 // side and analysis required to adopt these changes.
 static LOADED_CLASSES: Global<HashMap<InitLevel, Vec<LoadedClass>>> = Global::default();
 
+// RPC configuration functions of loaded classes which declare `#[rpc]` methods. Unlike other registration functions, these are invoked
+// for every new instance, because Godot stores RPC configuration per object, not per class.
+static CLASS_RPCS: Global<HashMap<ClassName, ErasedRegisterFn>> = Global::default();
+
+// Traits implemented by loaded classes via `#[godot_dyn]`, keyed by the `TypeId` of `dyn Trait`. Used to convert objects to `DynGd`.
+static DYN_TRAIT_IMPLS: Global<HashMap<TypeId, Vec<(ClassName, ErasedDynifyFn)>>> =
+    Global::default();
+
+// Instances of classes declared with `#[class(inspector_plugin)]`, which have been added to the editor's inspector.
+static INSPECTOR_PLUGINS: Global<HashMap<ClassName, InstanceId>> = Global::default();
+
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginItem, while others is directly
 // translated to code. Consider moving more code to the PluginItem, which allows for more dynamic registration and will
 // be easier for a future builder API.
@@ -53,6 +65,35 @@ impl fmt::Debug for ErasedRegisterFn {
     }
 }
 
+/// Type-erased function object, converting an object to `DynGd<_, D>` if its dynamic class is the one registered with `#[godot_dyn]`.
+///
+/// On success, returns a `Box<dyn ErasedDynGd<D>>`, itself boxed as `Box<dyn Any>`.
+#[derive(Copy, Clone)]
+pub struct ErasedDynifyFn {
+    pub raw: fn(InstanceId) -> Option<Box<dyn Any>>,
+}
+
+impl fmt::Debug for ErasedDynifyFn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:0>16x}", self.raw as usize)
+    }
+}
+
+/// Godot callback that frees a list returned by `get_property_list`. Since Godot 4.3, it also receives the list's length.
+#[cfg(before_api = "4.3")]
+pub type FreePropertyListFn = unsafe extern "C" fn(
+    p_instance: sys::GDExtensionClassInstancePtr,
+    p_list: *const sys::GDExtensionPropertyInfo,
+);
+
+/// Godot callback that frees a list returned by `get_property_list`. Since Godot 4.3, it also receives the list's length.
+#[cfg(since_api = "4.3")]
+pub type FreePropertyListFn = unsafe extern "C" fn(
+    p_instance: sys::GDExtensionClassInstancePtr,
+    p_list: *const sys::GDExtensionPropertyInfo,
+    p_count: u32,
+);
+
 /// Represents the data part of a [`ClassPlugin`] instance.
 ///
 /// Each enumerator represents a different item in Rust code, which is processed by an independent proc macro (for example,
@@ -97,9 +138,18 @@ pub enum PluginItem {
         /// Whether `#[class(tool)]` was used.
         is_tool: bool,
 
+        /// Whether `#[class(runtime)]` was used.
+        is_runtime: bool,
+
         /// Whether `#[class(editor_plugin)]` was used.
         is_editor_plugin: bool,
 
+        /// Whether `#[class(singleton)]` was used.
+        is_singleton: bool,
+
+        /// Whether `#[class(inspector_plugin)]` was used.
+        is_inspector_plugin: bool,
+
         /// Whether `#[class(hidden)]` was used.
         is_hidden: bool,
 
@@ -113,6 +163,30 @@ pub enum PluginItem {
         ///
         /// Always present since that's the entire point of this `impl` block.
         register_methods_constants_fn: ErasedRegisterFn,
+
+        /// Callback to library-generated function which configures the `#[rpc]` methods of a newly created instance.
+        ///
+        /// Only present if the `impl` block contains at least one `#[rpc]` method.
+        register_rpcs_fn: Option<ErasedRegisterFn>,
+    },
+
+    /// Collected from `#[derive(GodotConvert)]` on enums with `#[godot(class = MyClass)]`.
+    ///
+    /// Unlike other items, a class can have any number of these.
+    ClassEnum {
+        /// Callback to library-generated function which registers the enumerators as constants of the class.
+        register_enum_fn: ErasedRegisterFn,
+    },
+
+    /// Collected from `#[godot_dyn] impl Trait for MyClass`.
+    ///
+    /// Like enums, a class can implement any number of traits.
+    DynTraitImpl {
+        /// `TypeId` of the trait object type `dyn Trait`.
+        dyn_trait_typeid: TypeId,
+
+        /// Callback which converts an object of this class into a `DynGd<_, dyn Trait>`.
+        erased_dynify_fn: ErasedDynifyFn,
     },
 
     /// Collected from `#[godot_api] impl I... for MyClass`.
@@ -176,6 +250,36 @@ pub enum PluginItem {
             ) -> sys::GDExtensionBool,
         >,
 
+        /// User-defined `get_property_list` function, together with the function that frees the returned list.
+        user_get_property_list_fn: Option<(
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                r_count: *mut u32,
+            ) -> *const sys::GDExtensionPropertyInfo,
+            FreePropertyListFn,
+        )>,
+
+        /// User-defined `property_get_revert` function, as `property_can_revert` and `property_get_revert` callbacks.
+        user_property_revert_fn: Option<(
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        )>,
+
+        /// User-defined `validate_property` function (Godot 4.2+).
+        user_validate_property_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_property: *mut sys::GDExtensionPropertyInfo,
+            ) -> sys::GDExtensionBool,
+        >,
+
         /// Callback for other virtuals.
         get_virtual_fn: unsafe extern "C" fn(
             p_userdata: *mut std::os::raw::c_void,
@@ -190,6 +294,8 @@ pub enum PluginItem {
 pub struct LoadedClass {
     name: ClassName,
     is_editor_plugin: bool,
+    is_singleton: bool,
+    is_inspector_plugin: bool,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -200,8 +306,11 @@ struct ClassRegistrationInfo {
     parent_class_name: Option<ClassName>,
     // Following functions are stored separately, since their order matters.
     register_methods_constants_fn: Option<ErasedRegisterFn>,
+    register_enum_fns: Vec<ErasedRegisterFn>,
     register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
+    register_rpcs_fn: Option<ErasedRegisterFn>, // Not invoked on registration, but on each instance creation.
+    dyn_trait_impls: Vec<(TypeId, ErasedDynifyFn)>,
     default_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is at least one OnReady field)
     user_virtual_fn: sys::GDExtensionClassGetVirtual, // Option (set if there is a `#[godot_api] impl I*`)
 
@@ -216,6 +325,8 @@ struct ClassRegistrationInfo {
     #[allow(dead_code)] // Currently unused; may be useful for diagnostics in the future.
     init_level: InitLevel,
     is_editor_plugin: bool,
+    is_singleton: bool,
+    is_inspector_plugin: bool,
 
     /// Used to ensure that each component is only filled once.
     component_already_filled: [bool; 3],
@@ -230,6 +341,12 @@ impl ClassRegistrationInfo {
             PluginItem::Struct { .. } => 0,
             PluginItem::InherentImpl { .. } => 1,
             PluginItem::ITraitImpl { .. } => 2,
+
+            // Multiple enums per class are allowed; duplicate enum names are reported by Godot.
+            PluginItem::ClassEnum { .. } => return,
+
+            // Multiple traits per class are allowed; duplicate impls of the same trait are rejected by rustc.
+            PluginItem::DynTraitImpl { .. } => return,
         };
 
         if self.component_already_filled[index] {
@@ -287,15 +404,20 @@ pub fn register_class<
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
         register_methods_constants_fn: None,
+        register_enum_fns: Vec::new(),
         register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
         }),
+        register_rpcs_fn: None,
+        dyn_trait_impls: Vec::new(),
         user_virtual_fn: None,
         default_virtual_fn: None,
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        is_singleton: false,
+        is_inspector_plugin: false,
         component_already_filled: Default::default(), // [false; N]
     });
 }
@@ -326,6 +448,8 @@ pub fn auto_register_classes(init_level: InitLevel) {
         fill_class_info(elem.item.clone(), class_info);
     });
 
+    let mut singletons = Vec::new();
+    let mut inspector_plugins = Vec::new();
     let mut loaded_classes_by_level = global_loaded_classes();
     for info in map.into_values() {
         let class_name = info.class_name;
@@ -333,15 +457,37 @@ pub fn auto_register_classes(init_level: InitLevel) {
         let loaded_class = LoadedClass {
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
+            is_singleton: info.is_singleton,
+            is_inspector_plugin: info.is_inspector_plugin,
         };
         loaded_classes_by_level
             .entry(init_level)
             .or_default()
             .push(loaded_class);
 
+        if info.is_singleton {
+            singletons.push(class_name);
+        }
+        if info.is_inspector_plugin {
+            inspector_plugins.push(class_name);
+        }
+
         register_class_raw(info);
         out!("Class {class_name} loaded");
     }
+    drop(loaded_classes_by_level);
+
+    // Singletons are only instantiated once all classes of this level are registered, as their constructors may depend on other classes.
+    for class_name in singletons {
+        register_singleton(class_name);
+    }
+
+    // Only the editor has an inspector.
+    if !inspector_plugins.is_empty() && Engine::singleton().is_editor_hint() {
+        for class_name in inspector_plugins {
+            add_inspector_plugin(class_name);
+        }
+    }
 
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
@@ -351,7 +497,20 @@ pub fn unregister_classes(init_level: InitLevel) {
     let loaded_classes_current_level = loaded_classes_by_level
         .remove(&init_level)
         .unwrap_or_default();
+    drop(loaded_classes_by_level);
+
     out!("Unregistering classes of level {init_level:?}...");
+
+    // Free singletons before any class is unregistered, since their destructors may still use other classes.
+    for class in loaded_classes_current_level.iter().rev() {
+        if class.is_singleton {
+            unregister_singleton(class.name);
+        }
+        if class.is_inspector_plugin {
+            remove_inspector_plugin(class.name);
+        }
+    }
+
     for class_name in loaded_classes_current_level.into_iter().rev() {
         unregister_class_raw(class_name);
     }
@@ -370,6 +529,30 @@ fn global_loaded_classes() -> GlobalGuard<'static, HashMap<InitLevel, Vec<Loaded
     }
 }
 
+/// Returns the function configuring `#[rpc]` methods of a loaded class, if it declares any.
+///
+/// Looked up once per class by the `GodotClass` impl of user classes, so the lock is not taken on every instance creation.
+pub fn find_rpc_register_fn(class_name: ClassName) -> Option<ErasedRegisterFn> {
+    CLASS_RPCS.lock().get(&class_name).copied()
+}
+
+/// Configures the `#[rpc]` methods of a newly created instance, if its class declares any.
+pub(crate) fn auto_register_rpcs<T: GodotClass>(base: &Base<T::Base>) {
+    if let Some(register_fn) = T::__rpc_register_fn() {
+        let mut base_gd = base.to_gd();
+        (register_fn.raw)(&mut base_gd);
+    }
+}
+
+/// Returns the conversion functions of all loaded classes implementing the trait object type with `dyn_trait_typeid`.
+pub(crate) fn find_dyn_trait_impls(dyn_trait_typeid: TypeId) -> Vec<ErasedDynifyFn> {
+    DYN_TRAIT_IMPLS
+        .lock()
+        .get(&dyn_trait_typeid)
+        .map(|impls| impls.iter().map(|(_, erased_fn)| *erased_fn).collect())
+        .unwrap_or_default()
+}
+
 /// Populate `c` with all the relevant data from `component` (depending on component type).
 fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
     c.validate_unique(&item);
@@ -385,7 +568,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             free_fn,
             default_get_virtual_fn,
             is_tool,
+            is_runtime,
             is_editor_plugin,
+            is_singleton,
+            is_inspector_plugin,
             is_hidden,
             is_instantiable,
         } => {
@@ -393,8 +579,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.default_virtual_fn = default_get_virtual_fn;
             c.register_properties_fn = Some(register_properties_fn);
             c.is_editor_plugin = is_editor_plugin;
+            c.is_singleton = is_singleton;
+            c.is_inspector_plugin = is_inspector_plugin;
 
-            // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
+            // Classes marked #[class(no_init)] or #[class(abstract)] are translated to "abstract" in Godot. This disables their default constructor.
             // "Abstract" is a misnomer -- it's not an abstract base class, but rather a "utility/static class" (although it can have instance
             // methods). Examples are Input, IP, FileAccess, DisplayServer.
             //
@@ -425,19 +613,37 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             #[cfg(before_api = "4.2")]
             assert!(generated_recreate_fn.is_none()); // not used
 
+            // Before 4.3, non-tool classes are emulated by skipping virtual callbacks in the editor, see `private::is_class_inactive()`.
             #[cfg(before_api = "4.3")]
-            let _ = is_tool; // mark used
+            let _ = (is_tool, is_runtime); // mark used
             #[cfg(since_api = "4.3")]
             {
+                let config = crate::private::ClassConfig {
+                    is_tool,
+                    is_runtime,
+                };
                 c.godot_params.is_runtime =
-                    crate::private::is_class_runtime(is_tool) as sys::GDExtensionBool;
+                    crate::private::is_class_runtime(&config) as sys::GDExtensionBool;
             }
         }
 
         PluginItem::InherentImpl {
             register_methods_constants_fn,
+            register_rpcs_fn,
         } => {
             c.register_methods_constants_fn = Some(register_methods_constants_fn);
+            c.register_rpcs_fn = register_rpcs_fn;
+        }
+
+        PluginItem::ClassEnum { register_enum_fn } => {
+            c.register_enum_fns.push(register_enum_fn);
+        }
+
+        PluginItem::DynTraitImpl {
+            dyn_trait_typeid,
+            erased_dynify_fn,
+        } => {
+            c.dyn_trait_impls.push((dyn_trait_typeid, erased_dynify_fn));
         }
 
         PluginItem::ITraitImpl {
@@ -448,6 +654,9 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             user_on_notification_fn,
             user_set_fn,
             user_get_fn,
+            user_get_property_list_fn,
+            user_property_revert_fn,
+            user_validate_property_fn,
             get_virtual_fn,
         } => {
             c.user_register_fn = user_register_fn;
@@ -469,6 +678,23 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.godot_params.notification_func = user_on_notification_fn;
             c.godot_params.set_func = user_set_fn;
             c.godot_params.get_func = user_get_fn;
+
+            if let Some((get_property_list_fn, free_property_list_fn)) = user_get_property_list_fn {
+                c.godot_params.get_property_list_func = Some(get_property_list_fn);
+                c.godot_params.free_property_list_func = Some(free_property_list_fn);
+            }
+
+            if let Some((can_revert_fn, get_revert_fn)) = user_property_revert_fn {
+                c.godot_params.property_can_revert_func = Some(can_revert_fn);
+                c.godot_params.property_get_revert_func = Some(get_revert_fn);
+            }
+
+            #[cfg(since_api = "4.2")]
+            {
+                c.godot_params.validate_property_func = user_validate_property_fn;
+            }
+            #[cfg(before_api = "4.2")]
+            assert!(user_validate_property_fn.is_none()); // not used
             c.user_virtual_fn = Some(get_virtual_fn);
         }
     }
@@ -549,13 +775,17 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     let mut class_builder = 0; // TODO dummy argument; see callbacks
 
     // Order of the following registrations is crucial:
-    // 1. Methods and constants.
+    // 1. Methods and constants, including enums.
     // 2. Properties (they may depend on get/set methods).
     // 3. User-defined registration function (intuitively, user expects their own code to run after proc-macro generated code).
     if let Some(register_fn) = info.register_methods_constants_fn {
         (register_fn.raw)(&mut class_builder);
     }
 
+    for register_fn in info.register_enum_fns.iter() {
+        (register_fn.raw)(&mut class_builder);
+    }
+
     if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
@@ -564,12 +794,93 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         (register_fn.raw)(&mut class_builder);
     }
 
+    if let Some(register_fn) = info.register_rpcs_fn {
+        CLASS_RPCS.lock().insert(class_name, register_fn);
+    }
+
+    if !info.dyn_trait_impls.is_empty() {
+        let mut dyn_trait_impls = DYN_TRAIT_IMPLS.lock();
+        for (dyn_trait_typeid, erased_dynify_fn) in info.dyn_trait_impls.iter() {
+            dyn_trait_impls
+                .entry(*dyn_trait_typeid)
+                .or_default()
+                .push((class_name, *erased_dynify_fn));
+        }
+    }
+
     #[cfg(since_api = "4.1")]
     if info.is_editor_plugin {
         unsafe { interface_fn!(editor_add_plugin)(class_name.string_sys()) };
     }
 }
 
+/// Instantiates the class and registers the instance as an engine singleton under the class name.
+fn register_singleton(class_name: ClassName) {
+    // SAFETY: class has been registered with Godot, is instantiable and manually managed (checked by proc-macro).
+    let object: Gd<Object> = unsafe {
+        let object_ptr = interface_fn!(classdb_construct_object)(class_name.string_sys());
+        Gd::from_obj_sys(object_ptr)
+    };
+
+    Engine::singleton().register_singleton(class_name.to_string_name(), object);
+    out!("> Singleton {class_name} registered");
+}
+
+fn unregister_singleton(class_name: ClassName) {
+    let name = class_name.to_string_name();
+    let mut engine = Engine::singleton();
+
+    // Not present if registration failed, or if the user already removed it.
+    if !engine.has_singleton(name.clone()) {
+        return;
+    }
+
+    if let Some(object) = engine.get_singleton(name.clone()) {
+        engine.unregister_singleton(name);
+        object.free();
+
+        out!("> Singleton {class_name} freed");
+    }
+}
+
+/// Instantiates the class and adds it to the editor's inspector.
+///
+/// Only invoked when running in the editor. Integration tests don't, so this and [`remove_inspector_plugin()`] are not covered by them.
+fn add_inspector_plugin(class_name: ClassName) {
+    // SAFETY: class has been registered with Godot and is instantiable (checked by proc-macro).
+    let plugin: Gd<EditorInspectorPlugin> = unsafe {
+        let object_ptr = interface_fn!(classdb_construct_object)(class_name.string_sys());
+        Gd::<Object>::from_obj_sys(object_ptr).cast()
+    };
+
+    INSPECTOR_PLUGINS
+        .lock()
+        .insert(class_name, plugin.instance_id());
+
+    // EditorPlugin::add_inspector_plugin() only forwards to the inspector, so a temporary plugin instance is enough.
+    let mut editor_plugin = EditorPlugin::new_alloc();
+    editor_plugin.add_inspector_plugin(plugin);
+    editor_plugin.free();
+
+    out!("> Inspector plugin {class_name} added");
+}
+
+/// Removes the plugin added by [`add_inspector_plugin()`], if any.
+fn remove_inspector_plugin(class_name: ClassName) {
+    let Some(instance_id) = INSPECTOR_PLUGINS.lock().remove(&class_name) else {
+        return;
+    };
+
+    // The inspector holds the only reference, so the plugin is destroyed once removed.
+    if let Ok(plugin) = Gd::<EditorInspectorPlugin>::try_from_instance_id(instance_id) {
+        let mut editor_plugin = EditorPlugin::new_alloc();
+        editor_plugin.remove_inspector_plugin(plugin);
+        editor_plugin.free();
+
+        out!("> Inspector plugin {class_name} removed");
+    }
+}
+
 fn unregister_class_raw(class: LoadedClass) {
     let class_name = class.name;
     out!("Unregister class: {class_name}");
@@ -584,6 +895,11 @@ fn unregister_class_raw(class: LoadedClass) {
         out!("> Editor plugin removed");
     }
 
+    CLASS_RPCS.lock().remove(&class_name);
+    for impls in DYN_TRAIT_IMPLS.lock().values_mut() {
+        impls.retain(|(impl_class_name, _)| *impl_class_name != class_name);
+    }
+
     #[allow(clippy::let_unit_value)]
     let _: () = unsafe {
         interface_fn!(classdb_unregister_extension_class)(
@@ -605,13 +921,18 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
         register_methods_constants_fn: None,
+        register_enum_fns: Vec::new(),
         register_properties_fn: None,
         user_register_fn: None,
+        register_rpcs_fn: None,
+        dyn_trait_impls: Vec::new(),
         default_virtual_fn: None,
         user_virtual_fn: None,
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        is_singleton: false,
+        is_inspector_plugin: false,
         component_already_filled: Default::default(), // [false; N]
     }
 }
@@ -0,0 +1,82 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::meta::PropertyInfo;
+use crate::builtin::{dict, Array, Dictionary, StringName};
+
+/// Describes a signal declared by a script.
+#[derive(Debug, Clone)]
+pub struct SignalInfo {
+    pub signal_name: StringName,
+    pub arguments: Vec<PropertyInfo>,
+}
+
+impl SignalInfo {
+    /// Creates a signal with the given name and parameters.
+    pub fn new(signal_name: impl Into<StringName>, arguments: Vec<PropertyInfo>) -> Self {
+        Self {
+            signal_name: signal_name.into(),
+            arguments,
+        }
+    }
+
+    /// Returns the dictionary representation used by Godot, e.g. in [`Object::get_signal_list()`][crate::engine::Object::get_signal_list].
+    pub fn to_dictionary(&self) -> Dictionary {
+        let args: Array<Dictionary> = self
+            .arguments
+            .iter()
+            .map(PropertyInfo::to_dictionary)
+            .collect();
+
+        dict! {
+            "name": self.signal_name.clone(),
+            "args": args,
+        }
+    }
+}
+
+/// Error reported when compiling a script's source code fails.
+///
+/// Shown in the script editor and printed when a script fails to load.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    /// Line of the error, starting at 1.
+    pub line: i32,
+
+    /// Column of the error, starting at 1.
+    pub column: i32,
+
+    pub message: String,
+}
+
+impl ScriptError {
+    pub fn new(line: i32, column: i32, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub(super) fn to_dictionary(&self) -> Dictionary {
+        dict! {
+            "line": self.line,
+            "column": self.column,
+            "message": self.message.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ScriptError {}
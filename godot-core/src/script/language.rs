@@ -0,0 +1,127 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::rc::Rc;
+
+use crate::builtin::meta::{MethodInfo, PropertyInfo};
+use crate::builtin::{StringName, Variant};
+use crate::engine::{Object, Script, ScriptInstance};
+use crate::obj::Gd;
+
+use super::{ScriptError, SignalInfo};
+
+/// Describes a script language implemented in Rust.
+///
+/// This trait is stateless: the language is identified by its type, and all methods are associated functions. Use it together with
+/// [`script_language!`][crate::script::script_language] to generate the Godot classes that make the language available in the engine.
+///
+/// Only [`NAME`][Self::NAME], [`EXTENSION`][Self::EXTENSION] and [`compile()`][Self::compile] are required; the remaining methods
+/// provide editor integration and have sensible defaults.
+pub trait ScriptLanguageImpl: 'static {
+    /// Compiled representation of a script file.
+    type Script: ScriptImpl;
+
+    /// Human-readable name of the language, e.g. `"Lua"`.
+    const NAME: &'static str;
+
+    /// File extension of source files, without the leading dot.
+    const EXTENSION: &'static str;
+
+    /// Compiles the source code of a script.
+    ///
+    /// `path` is the resource path of the script, or empty if the script has not been saved yet. It is intended for error messages and
+    /// resolving relative imports.
+    fn compile(source: &str, path: &str) -> Result<Self::Script, ScriptError>;
+
+    /// All file extensions recognized by the resource loader and saver. Defaults to [`EXTENSION`][Self::EXTENSION].
+    fn recognized_extensions() -> Vec<&'static str> {
+        vec![Self::EXTENSION]
+    }
+
+    /// Keywords which cannot be used as identifiers. Used for syntax highlighting.
+    fn reserved_words() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Whether `keyword` affects control flow (`if`, `return`, ...). Such keywords are highlighted differently.
+    #[allow(unused_variables)]
+    fn is_control_flow_keyword(keyword: &str) -> bool {
+        false
+    }
+
+    /// Comment delimiters, as start and end separated by space (e.g. `"/* */"`), or start only for line comments (e.g. `"//"`).
+    fn comment_delimiters() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// String delimiters, in the same format as [`comment_delimiters()`][Self::comment_delimiters].
+    fn string_delimiters() -> Vec<&'static str> {
+        vec!["\" \""]
+    }
+
+    /// Source code of a newly created script that extends `base_class_name`.
+    #[allow(unused_variables)]
+    fn make_template(class_name: &str, base_class_name: &str) -> String {
+        String::new()
+    }
+}
+
+/// Compiled form of a single script, as returned by [`ScriptLanguageImpl::compile()`].
+///
+/// The compiled script is shared between the script resource and all of its instances.
+pub trait ScriptImpl: 'static {
+    /// Script instance attached to each object using this script.
+    type Instance: ScriptInstance;
+
+    /// Creates the instance attached to `for_object`.
+    ///
+    /// `script` is the script resource, which [`ScriptInstance::get_script()`] must return.
+    fn create_instance(
+        self: Rc<Self>,
+        script: Gd<Script>,
+        for_object: Gd<Object>,
+    ) -> Self::Instance;
+
+    /// Native class the script extends, e.g. `"Node"`.
+    fn base_type(&self) -> StringName;
+
+    /// Name under which the script is registered as a global class, if any.
+    fn global_name(&self) -> Option<StringName> {
+        None
+    }
+
+    /// Whether the script runs in the editor. Scripts that are not tools are represented by placeholders there.
+    fn is_tool(&self) -> bool {
+        false
+    }
+
+    /// Methods declared by the script.
+    fn methods(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    /// Properties declared by the script.
+    fn properties(&self) -> Vec<PropertyInfo> {
+        Vec::new()
+    }
+
+    /// Signals declared by the script.
+    fn signals(&self) -> Vec<SignalInfo> {
+        Vec::new()
+    }
+
+    /// Constants declared by the script.
+    fn constants(&self) -> Vec<(StringName, Variant)> {
+        Vec::new()
+    }
+
+    /// Initial value of a property, shown by the inspector and used to reset it.
+    #[allow(unused_variables)]
+    fn property_default(&self, property: &StringName) -> Option<Variant> {
+        None
+    }
+}
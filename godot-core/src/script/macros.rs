@@ -0,0 +1,612 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Generates the Godot classes for a script language implemented with [`ScriptLanguageImpl`][crate::script::ScriptLanguageImpl].
+///
+/// Four classes are declared, whose names you choose:
+/// - `language`: the `ScriptLanguageExtension` registered with the engine.
+/// - `script`: the `ScriptExtension` resource holding a script's source code and its compiled form.
+/// - `loader`, `saver`: the `ResourceFormatLoader` and `ResourceFormatSaver` for the language's file extensions.
+///
+/// All of them are tool classes, so that the language is also available in the editor. The language class receives an associated
+/// function `register()`, which makes the language known to the engine. Call it once the scene level has been loaded; the language is
+/// unregistered automatically when the library is unloaded.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::engine::{Object, Script, ScriptInstance, SiMut};
+/// use godot::init::InitLevel;
+/// use godot::script::{script_language, ScriptError, ScriptImpl, ScriptLanguageImpl};
+/// use std::rc::Rc;
+///
+/// struct MyDsl;
+/// struct MyDslScript { /* parsed source */ }
+/// # struct MyDslInstance;
+/// # impl ScriptInstance for MyDslInstance {
+/// #     fn class_name(&self) -> GString { unimplemented!() }
+/// #     fn set_property(_: SiMut<Self>, _: StringName, _: &Variant) -> bool { unimplemented!() }
+/// #     fn get_property(&self, _: StringName) -> Option<Variant> { unimplemented!() }
+/// #     fn get_property_list(&self) -> Vec<godot::builtin::meta::PropertyInfo> { unimplemented!() }
+/// #     fn get_method_list(&self) -> Vec<godot::builtin::meta::MethodInfo> { unimplemented!() }
+/// #     fn call(_: SiMut<Self>, _: StringName, _: &[&Variant]) -> Result<Variant, godot::sys::GDExtensionCallErrorType> { unimplemented!() }
+/// #     fn is_placeholder(&self) -> bool { unimplemented!() }
+/// #     fn has_method(&self, _: StringName) -> bool { unimplemented!() }
+/// #     fn get_script(&self) -> &Gd<Script> { unimplemented!() }
+/// #     fn get_property_type(&self, _: StringName) -> VariantType { unimplemented!() }
+/// #     fn to_string(&self) -> GString { unimplemented!() }
+/// #     fn get_property_state(&self) -> Vec<(StringName, Variant)> { unimplemented!() }
+/// #     fn get_language(&self) -> Gd<godot::engine::ScriptLanguage> { unimplemented!() }
+/// #     fn on_refcount_decremented(&self) -> bool { unimplemented!() }
+/// #     fn on_refcount_incremented(&self) { unimplemented!() }
+/// #     fn property_get_fallback(&self, _: StringName) -> Option<Variant> { unimplemented!() }
+/// #     fn property_set_fallback(_: SiMut<Self>, _: StringName, _: &Variant) -> bool { unimplemented!() }
+/// # }
+///
+/// impl ScriptLanguageImpl for MyDsl {
+///     type Script = MyDslScript;
+///     const NAME: &'static str = "MyDsl";
+///     const EXTENSION: &'static str = "dsl";
+///
+///     fn compile(source: &str, path: &str) -> Result<MyDslScript, ScriptError> {
+///         // Parse `source`...
+///         Ok(MyDslScript {})
+///     }
+/// }
+///
+/// impl ScriptImpl for MyDslScript {
+///     type Instance = MyDslInstance;
+///
+///     fn create_instance(self: Rc<Self>, script: Gd<Script>, for_object: Gd<Object>) -> MyDslInstance {
+///         // Keep `self` and `script` in the instance...
+///         # unimplemented!()
+///     }
+///
+///     fn base_type(&self) -> StringName {
+///         "Node".into()
+///     }
+/// }
+///
+/// script_language!(MyDsl {
+///     language: MyDslLanguage,
+///     script: MyDslScriptResource,
+///     loader: MyDslLoader,
+///     saver: MyDslSaver,
+/// });
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             MyDslLanguage::register();
+///         }
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! script_language {
+    (
+        $vis:vis $Lang:ty {
+            language: $Language:ident,
+            script: $Script:ident,
+            loader: $Loader:ident,
+            saver: $Saver:ident $(,)?
+        }
+    ) => {
+        // Language.
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, base = ScriptLanguageExtension)]
+        $vis struct $Language {
+            base: ::godot::obj::Base<::godot::engine::ScriptLanguageExtension>,
+        }
+
+        impl $Language {
+            /// Registers the language with the engine, together with its resource loader and saver.
+            ///
+            /// Has no effect if the language is already registered.
+            $vis fn register() {
+                use ::godot::obj::{NewAlloc as _, NewGd as _};
+
+                ::godot::script::register_language::<$Lang>(
+                    $Language::new_alloc().upcast(),
+                    $Loader::new_gd().upcast(),
+                    $Saver::new_gd().upcast(),
+                );
+            }
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::engine::IScriptLanguageExtension for $Language {
+            fn get_name(&self) -> ::godot::builtin::GString {
+                <$Lang as ::godot::script::ScriptLanguageImpl>::NAME.into()
+            }
+
+            fn init_ext(&mut self) {}
+
+            fn get_type(&self) -> ::godot::builtin::GString {
+                <$Script as ::godot::obj::GodotClass>::class_name().to_gstring()
+            }
+
+            fn get_extension(&self) -> ::godot::builtin::GString {
+                <$Lang as ::godot::script::ScriptLanguageImpl>::EXTENSION.into()
+            }
+
+            fn finish(&mut self) {}
+
+            fn get_reserved_words(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::script::string_array(
+                    <$Lang as ::godot::script::ScriptLanguageImpl>::reserved_words(),
+                )
+            }
+
+            fn is_control_flow_keyword(&self, keyword: ::godot::builtin::GString) -> bool {
+                <$Lang as ::godot::script::ScriptLanguageImpl>::is_control_flow_keyword(
+                    &keyword.to_string(),
+                )
+            }
+
+            fn get_comment_delimiters(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::script::string_array(
+                    <$Lang as ::godot::script::ScriptLanguageImpl>::comment_delimiters(),
+                )
+            }
+
+            fn get_string_delimiters(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::script::string_array(
+                    <$Lang as ::godot::script::ScriptLanguageImpl>::string_delimiters(),
+                )
+            }
+
+            fn make_template(
+                &self,
+                _template: ::godot::builtin::GString,
+                class_name: ::godot::builtin::GString,
+                base_class_name: ::godot::builtin::GString,
+            ) -> Option<::godot::obj::Gd<::godot::engine::Script>> {
+                use ::godot::obj::NewGd as _;
+
+                let source = <$Lang as ::godot::script::ScriptLanguageImpl>::make_template(
+                    &class_name.to_string(),
+                    &base_class_name.to_string(),
+                );
+
+                let mut script = $Script::new_gd();
+                script.bind_mut().state.set_source_code(source.into());
+                Some(script.upcast())
+            }
+
+            fn get_built_in_templates(
+                &self,
+                _object: ::godot::builtin::StringName,
+            ) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn is_using_templates(&mut self) -> bool {
+                false
+            }
+
+            fn validate(
+                &self,
+                script: ::godot::builtin::GString,
+                path: ::godot::builtin::GString,
+                _validate_functions: bool,
+                _validate_errors: bool,
+                _validate_warnings: bool,
+                _validate_safe_lines: bool,
+            ) -> ::godot::builtin::Dictionary {
+                ::godot::script::validate::<$Lang>(&script, &path)
+            }
+
+            fn validate_path(&self, _path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn create_script(&self) -> Option<::godot::obj::Gd<::godot::engine::Object>> {
+                use ::godot::obj::NewGd as _;
+
+                Some($Script::new_gd().upcast())
+            }
+
+            fn has_named_classes(&self) -> bool {
+                false
+            }
+
+            fn supports_builtin_mode(&self) -> bool {
+                false
+            }
+
+            fn supports_documentation(&self) -> bool {
+                false
+            }
+
+            fn can_inherit_from_file(&self) -> bool {
+                false
+            }
+
+            fn find_function(
+                &self,
+                _class_name: ::godot::builtin::GString,
+                _function_name: ::godot::builtin::GString,
+            ) -> i32 {
+                -1
+            }
+
+            fn make_function(
+                &self,
+                _class_name: ::godot::builtin::GString,
+                _function_name: ::godot::builtin::GString,
+                _function_args: ::godot::builtin::PackedStringArray,
+            ) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn open_in_external_editor(
+                &mut self,
+                _script: ::godot::obj::Gd<::godot::engine::Script>,
+                _line: i32,
+                _column: i32,
+            ) -> ::godot::engine::global::Error {
+                ::godot::engine::global::Error::ERR_UNAVAILABLE
+            }
+
+            fn overrides_external_editor(&mut self) -> bool {
+                false
+            }
+
+            fn auto_indent_code(
+                &self,
+                code: ::godot::builtin::GString,
+                _from_line: i32,
+                _to_line: i32,
+            ) -> ::godot::builtin::GString {
+                code
+            }
+
+            fn add_global_constant(
+                &mut self,
+                _name: ::godot::builtin::StringName,
+                _value: ::godot::builtin::Variant,
+            ) {
+            }
+
+            fn add_named_global_constant(
+                &mut self,
+                _name: ::godot::builtin::StringName,
+                _value: ::godot::builtin::Variant,
+            ) {
+            }
+
+            fn remove_named_global_constant(&mut self, _name: ::godot::builtin::StringName) {}
+
+            fn thread_enter(&mut self) {}
+
+            fn thread_exit(&mut self) {}
+
+            fn reload_all_scripts(&mut self) {}
+
+            fn reload_tool_script(
+                &mut self,
+                _script: ::godot::obj::Gd<::godot::engine::Script>,
+                _soft_reload: bool,
+            ) {
+            }
+
+            fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::script::string_array(
+                    <$Lang as ::godot::script::ScriptLanguageImpl>::recognized_extensions(),
+                )
+            }
+
+            fn get_public_functions(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn get_public_constants(&self) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            fn get_public_annotations(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn profiling_start(&mut self) {}
+
+            fn profiling_stop(&mut self) {}
+
+            unsafe fn profiling_get_accumulated_data(
+                &mut self,
+                _info_array: *mut ::godot::engine::native::ScriptLanguageExtensionProfilingInfo,
+                _info_max: i32,
+            ) -> i32 {
+                0
+            }
+
+            unsafe fn profiling_get_frame_data(
+                &mut self,
+                _info_array: *mut ::godot::engine::native::ScriptLanguageExtensionProfilingInfo,
+                _info_max: i32,
+            ) -> i32 {
+                0
+            }
+
+            fn frame(&mut self) {}
+
+            fn handles_global_class_type(&self, type_: ::godot::builtin::GString) -> bool {
+                type_ == <Self as ::godot::engine::IScriptLanguageExtension>::get_type(self)
+            }
+
+            fn get_global_class_name(&self, path: ::godot::builtin::GString) -> ::godot::builtin::Dictionary {
+                ::godot::script::global_class_name::<$Lang>(&path)
+            }
+        }
+
+        // Script resource.
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, base = ScriptExtension)]
+        $vis struct $Script {
+            base: ::godot::obj::Base<::godot::engine::ScriptExtension>,
+            state: ::godot::script::ScriptState<$Lang>,
+        }
+
+        impl $Script {
+            fn to_script(&self) -> ::godot::obj::Gd<::godot::engine::Script> {
+                use ::godot::obj::WithBaseField as _;
+
+                self.to_gd().upcast()
+            }
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::engine::IScriptExtension for $Script {
+            fn editor_can_reload_from_file(&mut self) -> bool {
+                true
+            }
+
+            unsafe fn placeholder_erased(&mut self, placeholder: *mut ::std::ffi::c_void) {
+                self.state.placeholder_erased(placeholder);
+            }
+
+            fn can_instantiate(&self) -> bool {
+                self.state.can_instantiate()
+            }
+
+            fn get_base_script(&self) -> Option<::godot::obj::Gd<::godot::engine::Script>> {
+                None
+            }
+
+            fn get_global_name(&self) -> ::godot::builtin::StringName {
+                self.state.global_name()
+            }
+
+            fn inherits_script(&self, script: ::godot::obj::Gd<::godot::engine::Script>) -> bool {
+                script == self.to_script()
+            }
+
+            fn get_instance_base_type(&self) -> ::godot::builtin::StringName {
+                self.state.instance_base_type()
+            }
+
+            unsafe fn instance_create(
+                &self,
+                for_object: ::godot::obj::Gd<::godot::engine::Object>,
+            ) -> *mut ::std::ffi::c_void {
+                self.state.instance_create(self.to_script(), for_object)
+            }
+
+            unsafe fn placeholder_instance_create(
+                &self,
+                for_object: ::godot::obj::Gd<::godot::engine::Object>,
+            ) -> *mut ::std::ffi::c_void {
+                self.state.placeholder_instance_create(self.to_script(), for_object)
+            }
+
+            fn instance_has(&self, object: ::godot::obj::Gd<::godot::engine::Object>) -> bool {
+                self.state.instance_has(&self.to_script(), object)
+            }
+
+            fn has_source_code(&self) -> bool {
+                !self.state.source_code().is_empty()
+            }
+
+            fn get_source_code(&self) -> ::godot::builtin::GString {
+                self.state.source_code()
+            }
+
+            fn set_source_code(&mut self, code: ::godot::builtin::GString) {
+                self.state.set_source_code(code);
+            }
+
+            fn reload(&mut self, _keep_state: bool) -> ::godot::engine::global::Error {
+                use ::godot::obj::WithBaseField as _;
+
+                let path = self.base().get_path();
+                self.state.reload(&path)
+            }
+
+            fn get_documentation(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn has_method(&self, method: ::godot::builtin::StringName) -> bool {
+                self.state.has_method(&method)
+            }
+
+            fn get_method_info(&self, method: ::godot::builtin::StringName) -> ::godot::builtin::Dictionary {
+                self.state.method_info_dictionary(&method)
+            }
+
+            fn is_tool(&self) -> bool {
+                self.state.is_tool()
+            }
+
+            fn is_valid(&self) -> bool {
+                self.state.is_valid()
+            }
+
+            fn get_language(&self) -> Option<::godot::obj::Gd<::godot::engine::ScriptLanguage>> {
+                ::godot::script::language::<$Lang>()
+            }
+
+            fn has_script_signal(&self, signal: ::godot::builtin::StringName) -> bool {
+                self.state.has_signal(&signal)
+            }
+
+            fn get_script_signal_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                self.state.signal_list()
+            }
+
+            fn has_property_default_value(&self, property: ::godot::builtin::StringName) -> bool {
+                self.state.property_default(&property).is_some()
+            }
+
+            fn get_property_default_value(
+                &self,
+                property: ::godot::builtin::StringName,
+            ) -> ::godot::builtin::Variant {
+                self.state.property_default(&property).unwrap_or_default()
+            }
+
+            fn update_exports(&mut self) {
+                self.state.update_placeholders();
+            }
+
+            fn get_script_method_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                self.state.method_list()
+            }
+
+            fn get_script_property_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                self.state.property_list()
+            }
+
+            fn get_member_line(&self, _member: ::godot::builtin::StringName) -> i32 {
+                -1
+            }
+
+            fn get_constants(&self) -> ::godot::builtin::Dictionary {
+                self.state.constants()
+            }
+
+            fn get_members(&self) -> ::godot::builtin::Array<::godot::builtin::StringName> {
+                self.state.members()
+            }
+
+            fn is_placeholder_fallback_enabled(&self) -> bool {
+                false
+            }
+
+            fn get_rpc_config(&self) -> ::godot::builtin::Variant {
+                ::godot::builtin::Variant::nil()
+            }
+        }
+
+        // Resource loader.
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, base = ResourceFormatLoader)]
+        $vis struct $Loader {
+            base: ::godot::obj::Base<::godot::engine::ResourceFormatLoader>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::engine::IResourceFormatLoader for $Loader {
+            fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                ::godot::script::string_array(
+                    <$Lang as ::godot::script::ScriptLanguageImpl>::recognized_extensions(),
+                )
+            }
+
+            fn handles_type(&self, type_: ::godot::builtin::StringName) -> bool {
+                type_ == "Script".into()
+                    || type_ == <$Script as ::godot::obj::GodotClass>::class_name().to_string_name()
+            }
+
+            fn get_resource_type(&self, path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                if ::godot::script::handles_path::<$Lang>(&path) {
+                    <$Script as ::godot::obj::GodotClass>::class_name().to_gstring()
+                } else {
+                    ::godot::builtin::GString::new()
+                }
+            }
+
+            fn load(
+                &self,
+                path: ::godot::builtin::GString,
+                _original_path: ::godot::builtin::GString,
+                _use_sub_threads: bool,
+                _cache_mode: i32,
+            ) -> ::godot::builtin::Variant {
+                use ::godot::builtin::meta::ToGodot as _;
+                use ::godot::obj::NewGd as _;
+
+                let source = match ::godot::script::read_source(&path) {
+                    Ok(source) => source,
+                    Err(err) => return err.to_variant(),
+                };
+
+                // Scripts that fail to compile are still loaded, so they can be fixed in the editor.
+                let mut script = $Script::new_gd();
+                {
+                    let mut guard = script.bind_mut();
+                    guard.state.set_source_code(source);
+                    guard.state.reload(&path);
+                }
+
+                script.to_variant()
+            }
+        }
+
+        // Resource saver.
+
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, base = ResourceFormatSaver)]
+        $vis struct $Saver {
+            base: ::godot::obj::Base<::godot::engine::ResourceFormatSaver>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::engine::IResourceFormatSaver for $Saver {
+            fn save(
+                &mut self,
+                resource: ::godot::obj::Gd<::godot::engine::Resource>,
+                path: ::godot::builtin::GString,
+                _flags: u32,
+            ) -> ::godot::engine::global::Error {
+                let Ok(script) = resource.try_cast::<$Script>() else {
+                    return ::godot::engine::global::Error::ERR_INVALID_PARAMETER;
+                };
+
+                let source = script.bind().state.source_code();
+                match ::godot::script::write_source(&path, source) {
+                    Ok(()) => ::godot::engine::global::Error::OK,
+                    Err(err) => err,
+                }
+            }
+
+            fn recognize(&self, resource: ::godot::obj::Gd<::godot::engine::Resource>) -> bool {
+                resource.try_cast::<$Script>().is_ok()
+            }
+
+            fn get_recognized_extensions(
+                &self,
+                resource: ::godot::obj::Gd<::godot::engine::Resource>,
+            ) -> ::godot::builtin::PackedStringArray {
+                if <Self as ::godot::engine::IResourceFormatSaver>::recognize(self, resource) {
+                    ::godot::script::string_array(
+                        <$Lang as ::godot::script::ScriptLanguageImpl>::recognized_extensions(),
+                    )
+                } else {
+                    ::godot::builtin::PackedStringArray::new()
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Framework for implementing script languages in Rust.
+//!
+//! [`ScriptInstance`][crate::engine::ScriptInstance] is enough to attach custom behavior to a single object, but a complete language
+//! additionally needs a `ScriptLanguageExtension`, a `ScriptExtension` and resource loaders/savers for its file extension. This module
+//! provides all of those on top of two traits:
+//! - [`ScriptLanguageImpl`] describes the language itself: name, file extension, keywords, and how source code is compiled.
+//! - [`ScriptImpl`] is the compiled form of one script file: its base class, methods, properties, signals, and how instances are created.
+//!
+//! The [`script_language!`] macro then generates the Godot classes that wire these traits to the engine. Method, property and signal
+//! metadata is expressed with typed structs ([`MethodInfo`], [`PropertyInfo`], [`SignalInfo`]) instead of raw dictionaries.
+//!
+//! In the editor, non-tool scripts are represented by placeholder instances, which expose the script's properties and their
+//! default values to the inspector. These are created and kept up-to-date automatically.
+//!
+//! Only available from Godot 4.2 on.
+
+mod info;
+mod language;
+mod macros;
+mod registration;
+mod state;
+
+pub use crate::builtin::meta::{MethodInfo, PropertyInfo};
+pub use crate::script_language;
+pub use info::{ScriptError, SignalInfo};
+pub use language::{ScriptImpl, ScriptLanguageImpl};
+pub use registration::{is_registered, language};
+
+#[doc(hidden)]
+pub use registration::{read_source, register_language, write_source};
+#[doc(hidden)]
+pub use state::{global_class_name, handles_path, string_array, validate, ScriptState};
+
+pub(crate) use registration::cleanup;
@@ -0,0 +1,126 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::builtin::GString;
+use crate::engine::file_access::ModeFlags;
+use crate::engine::global::Error;
+use crate::engine::{
+    Engine, GFile, ResourceFormatLoader, ResourceFormatSaver, ResourceLoader, ResourceSaver,
+    ScriptLanguage,
+};
+use crate::godot_error;
+use crate::obj::{Gd, InstanceId};
+use crate::sys::Global;
+
+use super::ScriptLanguageImpl;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Returns the engine object of the language `L`, or `None` if it has not been registered.
+///
+/// Useful to implement [`ScriptInstance::get_language()`][crate::engine::ScriptInstance::get_language].
+pub fn language<L: ScriptLanguageImpl>() -> Option<Gd<ScriptLanguage>> {
+    let instance_id = REGISTRY
+        .lock()
+        .get(&TypeId::of::<L>())
+        .map(|entry| entry.language)?;
+
+    Gd::try_from_instance_id(instance_id).ok()
+}
+
+/// Returns whether the language `L` is currently registered with the engine.
+pub fn is_registered<L: ScriptLanguageImpl>() -> bool {
+    REGISTRY.lock().contains_key(&TypeId::of::<L>())
+}
+
+/// Registers the language `L` with the engine, together with the resource loader and saver for its source files.
+///
+/// Called by the `register()` function generated by [`script_language!`][crate::script::script_language]. Languages are unregistered
+/// automatically when the library is unloaded.
+pub fn register_language<L: ScriptLanguageImpl>(
+    language: Gd<ScriptLanguage>,
+    loader: Gd<ResourceFormatLoader>,
+    saver: Gd<ResourceFormatSaver>,
+) {
+    // The new language object is not owned by the engine, unless registration succeeds.
+    if is_registered::<L>() {
+        language.free();
+        return;
+    }
+
+    let err = Engine::singleton().register_script_language(language.clone());
+    if err != Error::OK {
+        godot_error!("failed to register script language `{}`: {err:?}", L::NAME);
+        language.free();
+        return;
+    }
+
+    ResourceLoader::singleton().add_resource_format_loader(loader.clone());
+    ResourceSaver::singleton().add_resource_format_saver(saver.clone());
+
+    // Not holding the lock while calling into the engine above, which may call back into the language.
+    REGISTRY.lock().insert(
+        TypeId::of::<L>(),
+        RegisteredLanguage {
+            language: language.instance_id(),
+            loader: loader.instance_id(),
+            saver: saver.instance_id(),
+        },
+    );
+}
+
+/// Reads the source code of a script file.
+pub fn read_source(path: &GString) -> Result<GString, Error> {
+    GFile::open(path.clone(), ModeFlags::READ)
+        .and_then(|mut file| file.read_as_gstring_entire(false))
+        .map_err(|_| Error::ERR_FILE_CANT_READ)
+}
+
+/// Writes the source code of a script file.
+pub fn write_source(path: &GString, source: GString) -> Result<(), Error> {
+    GFile::open(path.clone(), ModeFlags::WRITE)
+        .and_then(|mut file| file.write_gstring(source))
+        .map_err(|_| Error::ERR_FILE_CANT_WRITE)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-local interface
+
+/// Unregisters all languages. Called on library unload, before the classes are unregistered.
+pub(crate) fn cleanup() {
+    let registered = std::mem::take(&mut *REGISTRY.lock());
+
+    for (_, entry) in registered {
+        if let Ok(loader) = Gd::<ResourceFormatLoader>::try_from_instance_id(entry.loader) {
+            ResourceLoader::singleton().remove_resource_format_loader(loader);
+        }
+        if let Ok(saver) = Gd::<ResourceFormatSaver>::try_from_instance_id(entry.saver) {
+            ResourceSaver::singleton().remove_resource_format_saver(saver);
+        }
+
+        if let Ok(language) = Gd::<ScriptLanguage>::try_from_instance_id(entry.language) {
+            Engine::singleton().unregister_script_language(language.clone());
+            language.free();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+// Objects are stored by ID, since `Gd` is not `Send`. While registered, the engine keeps all three alive.
+struct RegisteredLanguage {
+    language: InstanceId,
+    loader: InstanceId,
+    saver: InstanceId,
+}
+
+static REGISTRY: Global<HashMap<TypeId, RegisteredLanguage>> = Global::default();
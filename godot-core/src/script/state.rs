@@ -0,0 +1,278 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::c_void;
+use std::rc::Rc;
+
+use crate::builtin::meta::{FromGodot, MethodInfo, PropertyInfo};
+use crate::builtin::{dict, Array, Dictionary, GString, PackedStringArray, StringName, Variant};
+use crate::engine::global::Error;
+use crate::engine::{create_script_instance, Engine, Object, Script, ScriptPlaceholders};
+use crate::godot_error;
+use crate::obj::Gd;
+
+use super::{ScriptImpl, ScriptLanguageImpl};
+
+/// State of a script resource generated by [`script_language!`][crate::script::script_language].
+///
+/// Holds the source code and its compiled form, and implements the `IScriptExtension` methods on top of them.
+pub struct ScriptState<L: ScriptLanguageImpl> {
+    source: GString,
+    compiled: Option<Rc<L::Script>>,
+
+    /// Placeholder instances created in the editor, which are updated whenever the script is recompiled.
+    placeholders: ScriptPlaceholders,
+}
+
+impl<L: ScriptLanguageImpl> Default for ScriptState<L> {
+    fn default() -> Self {
+        Self {
+            source: GString::new(),
+            compiled: None,
+            placeholders: ScriptPlaceholders::new(),
+        }
+    }
+}
+
+impl<L: ScriptLanguageImpl> ScriptState<L> {
+    /// The compiled script, or `None` if the source has not been compiled successfully.
+    pub fn compiled(&self) -> Option<&Rc<L::Script>> {
+        self.compiled.as_ref()
+    }
+
+    pub fn source_code(&self) -> GString {
+        self.source.clone()
+    }
+
+    pub fn set_source_code(&mut self, source: GString) {
+        self.source = source;
+    }
+
+    /// Compiles the current source code, printing any error.
+    pub fn reload(&mut self, path: &GString) -> Error {
+        match L::compile(&self.source.to_string(), &path.to_string()) {
+            Ok(compiled) => {
+                self.compiled = Some(Rc::new(compiled));
+                self.update_placeholders();
+                Error::OK
+            }
+            Err(err) => {
+                godot_error!("{} script `{path}`: {err}", L::NAME);
+                self.compiled = None;
+                Error::ERR_PARSE_ERROR
+            }
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.compiled.is_some()
+    }
+
+    pub fn is_tool(&self) -> bool {
+        self.compiled.as_ref().map_or(false, |s| s.is_tool())
+    }
+
+    /// Scripts are instantiated at runtime, and only tool scripts in the editor. Otherwise Godot creates a placeholder.
+    pub fn can_instantiate(&self) -> bool {
+        self.is_valid() && (self.is_tool() || !Engine::singleton().is_editor_hint())
+    }
+
+    pub fn instance_base_type(&self) -> StringName {
+        self.compiled
+            .as_ref()
+            .map_or_else(StringName::default, |s| s.base_type())
+    }
+
+    pub fn global_name(&self) -> StringName {
+        self.compiled
+            .as_ref()
+            .and_then(|s| s.global_name())
+            .unwrap_or_default()
+    }
+
+    pub fn instance_has(&self, script: &Gd<Script>, object: Gd<Object>) -> bool {
+        Gd::<Script>::try_from_variant(&object.get_script()).map_or(false, |s| s == *script)
+    }
+
+    /// Creates the script instance for `for_object`, or returns null if the script is not valid.
+    ///
+    /// # Safety
+    /// The returned pointer must be handed over to Godot, as in `IScriptExtension::instance_create()`.
+    pub unsafe fn instance_create(
+        &self,
+        script: Gd<Script>,
+        for_object: Gd<Object>,
+    ) -> *mut c_void {
+        let Some(compiled) = self.compiled.clone() else {
+            return std::ptr::null_mut();
+        };
+
+        let instance = compiled.create_instance(script, for_object.clone());
+        create_script_instance(instance, for_object)
+    }
+
+    /// Creates a placeholder instance for `for_object`, exposing the script's properties and default values in the editor.
+    ///
+    /// # Safety
+    /// - The returned pointer must be handed over to Godot, as in `IScriptExtension::placeholder_instance_create()`.
+    /// - When Godot destroys the placeholder, it must be passed to [`placeholder_erased()`](Self::placeholder_erased), as in
+    ///   `IScriptExtension::placeholder_erased()`.
+    pub unsafe fn placeholder_instance_create(
+        &self,
+        script: Gd<Script>,
+        for_object: Gd<Object>,
+    ) -> *mut c_void {
+        let Some(language) = super::language::<L>() else {
+            return std::ptr::null_mut();
+        };
+
+        let (properties, values) = self.placeholder_properties();
+        self.placeholders
+            .create(language, script, for_object, &properties, &values)
+    }
+
+    /// Called by Godot when a placeholder instance is destroyed.
+    pub fn placeholder_erased(&mut self, placeholder: *mut c_void) {
+        self.placeholders.erase(placeholder);
+    }
+
+    pub fn update_placeholders(&self) {
+        let (properties, values) = self.placeholder_properties();
+
+        // SAFETY: placeholders are only created by `placeholder_instance_create()`, whose contract requires erased placeholders to be
+        // passed to `placeholder_erased()`.
+        unsafe { self.placeholders.update(&properties, &values) };
+    }
+
+    pub fn has_method(&self, method: &StringName) -> bool {
+        self.method_info(method).is_some()
+    }
+
+    pub fn method_info_dictionary(&self, method: &StringName) -> Dictionary {
+        self.method_info(method)
+            .map(|m| m.to_dictionary())
+            .unwrap_or_default()
+    }
+
+    pub fn method_list(&self) -> Array<Dictionary> {
+        self.with_compiled(|s| s.methods().iter().map(|m| m.to_dictionary()).collect())
+    }
+
+    pub fn property_list(&self) -> Array<Dictionary> {
+        self.with_compiled(|s| s.properties().iter().map(|p| p.to_dictionary()).collect())
+    }
+
+    pub fn has_signal(&self, signal: &StringName) -> bool {
+        self.with_compiled(|s| s.signals().iter().any(|sig| sig.signal_name == *signal))
+    }
+
+    pub fn signal_list(&self) -> Array<Dictionary> {
+        self.with_compiled(|s| s.signals().iter().map(|sig| sig.to_dictionary()).collect())
+    }
+
+    pub fn property_default(&self, property: &StringName) -> Option<Variant> {
+        self.compiled
+            .as_ref()
+            .and_then(|s| s.property_default(property))
+    }
+
+    pub fn constants(&self) -> Dictionary {
+        self.with_compiled(|s| s.constants().into_iter().collect())
+    }
+
+    pub fn members(&self) -> Array<StringName> {
+        self.with_compiled(|s| {
+            let properties = s.properties().into_iter().map(|p| p.property_name);
+            let signals = s.signals().into_iter().map(|sig| sig.signal_name);
+            properties.chain(signals).collect()
+        })
+    }
+
+    fn method_info(&self, method: &StringName) -> Option<MethodInfo> {
+        self.compiled
+            .as_ref()?
+            .methods()
+            .into_iter()
+            .find(|m| m.method_name == *method)
+    }
+
+    fn with_compiled<R: Default>(&self, f: impl FnOnce(&L::Script) -> R) -> R {
+        self.compiled.as_deref().map(f).unwrap_or_default()
+    }
+
+    /// Properties shown by placeholders, together with their default values.
+    fn placeholder_properties(&self) -> (Vec<PropertyInfo>, Vec<(StringName, Variant)>) {
+        self.with_compiled(|s| {
+            let properties = s.properties();
+            let values = properties
+                .iter()
+                .filter_map(|p| {
+                    let value = s.property_default(&p.property_name)?;
+                    Some((p.property_name.clone(), value))
+                })
+                .collect();
+
+            (properties, values)
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Language-level helpers, used by the generated ScriptLanguageExtension class.
+
+#[doc(hidden)]
+pub fn string_array(strings: Vec<&'static str>) -> PackedStringArray {
+    strings.into_iter().map(GString::from).collect()
+}
+
+/// Compiles `source` without keeping the result, and reports errors in the format expected by `IScriptLanguageExtension::validate()`.
+#[doc(hidden)]
+pub fn validate<L: ScriptLanguageImpl>(source: &GString, path: &GString) -> Dictionary {
+    match L::compile(&source.to_string(), &path.to_string()) {
+        Ok(_) => dict! { "valid": true },
+        Err(err) => {
+            let errors: Array<Dictionary> = [err.to_dictionary()].into_iter().collect();
+            dict! {
+                "valid": false,
+                "errors": errors,
+            }
+        }
+    }
+}
+
+/// Returns global class information of the script at `path`, as expected by `IScriptLanguageExtension::get_global_class_name()`.
+#[doc(hidden)]
+pub fn global_class_name<L: ScriptLanguageImpl>(path: &GString) -> Dictionary {
+    let compiled = super::read_source(path)
+        .ok()
+        .and_then(|source| L::compile(&source.to_string(), &path.to_string()).ok());
+
+    let Some(compiled) = compiled else {
+        return Dictionary::new();
+    };
+
+    match compiled.global_name() {
+        Some(name) => dict! {
+            "name": name,
+            "base_type": compiled.base_type(),
+        },
+        None => Dictionary::new(),
+    }
+}
+
+/// Whether `path` has one of the file extensions recognized by the language `L`.
+#[doc(hidden)]
+pub fn handles_path<L: ScriptLanguageImpl>(path: &GString) -> bool {
+    let path = path.to_string();
+    let Some(extension) = std::path::Path::new(&path).extension() else {
+        return false;
+    };
+
+    L::recognized_extensions()
+        .iter()
+        .any(|recognized| extension.eq_ignore_ascii_case(recognized))
+}
@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, ThreadId};
+
+use crate::builtin::{Callable, Variant};
+use crate::engine::{Engine, Os, SceneTree};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Starts running `future` as a task on the main thread.
+///
+/// The future is polled immediately, until it awaits something that is not ready yet, like a signal. Afterwards, it is resumed whenever
+/// it is woken up -- for signal futures, this happens directly when the signal is emitted, matching the behavior of `await` in GDScript.
+/// Wake-ups from other threads are processed at the beginning of the next frame.
+///
+/// Tasks are not required to be `Send`, so they can hold `Gd` pointers and other thread-bound values. If an object is freed while a task
+/// still refers to it, using the `Gd` panics as usual; a panic inside a task aborts only that task and prints an error. All remaining tasks
+/// are dropped when the library is unloaded.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// fn play_intro(player: Gd<Node>, dialog: Gd<Node>) {
+///     task::spawn(async move {
+///         player.signal("animation_finished").await;
+///         task::timer(0.5).await;
+///
+///         let (choice,): (i64,) = dialog.signal("choice_made").to_future().await;
+///         godot_print!("Player chose {choice}");
+///     });
+/// }
+/// ```
+///
+/// # Panics
+/// - If called from a thread other than the main thread.
+/// - If the main loop is not a [`SceneTree`], e.g. when called during library initialization before the scene tree exists. The scene
+///   tree is needed to resume tasks that are woken from other threads.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    assert!(
+        is_main_thread(),
+        "godot::task::spawn() can only be called on the main thread"
+    );
+
+    ensure_frame_driver();
+    let id = with_runtime(|rt| rt.add_task(Box::pin(future)));
+    poll_task(id);
+
+    TaskHandle::new(id)
+}
+
+/// Handle to a task started with [`spawn()`].
+///
+/// Dropping the handle does not cancel the task; use [`cancel()`][Self::cancel] for that.
+pub struct TaskHandle {
+    id: TaskId,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl TaskHandle {
+    fn new(id: TaskId) -> Self {
+        Self {
+            id,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Cancels the task, dropping its future.
+    ///
+    /// Has no effect if the task has already completed. If called from within the task itself, the future is dropped once it
+    /// yields control.
+    pub fn cancel(self) {
+        let future = with_runtime(|rt| rt.cancel_task(self.id));
+
+        // Drop outside of runtime borrow, as destructors may interact with other tasks.
+        drop(future);
+    }
+
+    /// Returns `true` if the task has neither completed nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        with_runtime(|rt| rt.tasks.contains_key(&self.id))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-local interface
+
+/// Wakes `waker` at the beginning of the next frame, rather than immediately.
+pub(super) fn wake_deferred(waker: Waker) {
+    DEFERRED_WAKERS
+        .lock()
+        .expect("deferred wakers poisoned")
+        .push(waker);
+}
+
+/// Drops all tasks. Called on library unload, while the engine is still available for destructors.
+pub(crate) fn cleanup() {
+    let tasks = RUNTIME.with(|rt| std::mem::take(&mut *rt.borrow_mut()));
+    drop(tasks);
+
+    DEFERRED_WAKERS
+        .lock()
+        .expect("deferred wakers poisoned")
+        .clear();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+type TaskId = u64;
+type BoxFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+enum TaskSlot {
+    /// Waiting to be woken up.
+    Idle(BoxFuture),
+
+    /// Future is currently taken out for polling; `cancelled` is set if the task was cancelled meanwhile.
+    Polling { cancelled: bool },
+}
+
+#[derive(Default)]
+struct AsyncRuntime {
+    tasks: HashMap<TaskId, TaskSlot>,
+    next_id: TaskId,
+    frame_driver_connected: bool,
+}
+
+impl AsyncRuntime {
+    fn add_task(&mut self, future: BoxFuture) -> TaskId {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.tasks.insert(id, TaskSlot::Idle(future));
+        id
+    }
+
+    /// Takes the future out for polling. Returns `None` if the task doesn't exist anymore or is already being polled.
+    fn begin_poll(&mut self, id: TaskId) -> Option<BoxFuture> {
+        let slot = self.tasks.get_mut(&id)?;
+
+        match std::mem::replace(slot, TaskSlot::Polling { cancelled: false }) {
+            TaskSlot::Idle(future) => Some(future),
+            polling @ TaskSlot::Polling { .. } => {
+                *slot = polling;
+                None
+            }
+        }
+    }
+
+    /// Puts back a pending future. Returns it if the task was cancelled during polling, so the caller can drop it.
+    fn end_poll(&mut self, id: TaskId, future: BoxFuture) -> Option<BoxFuture> {
+        match self.tasks.get_mut(&id) {
+            Some(slot @ TaskSlot::Polling { cancelled: false }) => {
+                *slot = TaskSlot::Idle(future);
+                None
+            }
+            _ => {
+                self.tasks.remove(&id);
+                Some(future)
+            }
+        }
+    }
+
+    fn cancel_task(&mut self, id: TaskId) -> Option<BoxFuture> {
+        match self.tasks.get_mut(&id)? {
+            TaskSlot::Polling { cancelled } => {
+                *cancelled = true;
+                None
+            }
+            TaskSlot::Idle(_) => match self.tasks.remove(&id) {
+                Some(TaskSlot::Idle(future)) => Some(future),
+                _ => unreachable!(),
+            },
+        }
+    }
+}
+
+thread_local! {
+    static RUNTIME: RefCell<AsyncRuntime> = RefCell::default();
+}
+
+/// Wakers to be invoked at the beginning of the next frame: wake-ups from other threads, or deferred ones.
+static DEFERRED_WAKERS: Mutex<Vec<Waker>> = Mutex::new(Vec::new());
+
+fn with_runtime<R>(f: impl FnOnce(&mut AsyncRuntime) -> R) -> R {
+    RUNTIME.with(|rt| f(&mut rt.borrow_mut()))
+}
+
+/// Polls the task `id` once, unless it is already being polled or doesn't exist.
+fn poll_task(id: TaskId) {
+    let Some(mut future) = with_runtime(|rt| rt.begin_poll(id)) else {
+        return;
+    };
+
+    let waker = Waker::from(Arc::new(TaskWaker {
+        id,
+        thread_id: thread::current().id(),
+    }));
+
+    let result = crate::private::handle_panic(
+        || format!("godot::task: task {id} panicked"),
+        AssertUnwindSafe(|| future.as_mut().poll(&mut Context::from_waker(&waker))),
+    );
+
+    // Completed, panicked or cancelled futures are dropped outside of the runtime borrow.
+    let finished = match result {
+        Ok(Poll::Pending) => with_runtime(|rt| rt.end_poll(id, future)),
+        Ok(Poll::Ready(())) | Err(_) => {
+            with_runtime(|rt| rt.tasks.remove(&id));
+            Some(future)
+        }
+    };
+    drop(finished);
+}
+
+/// Connects to the scene tree's `process_frame` signal, which processes deferred wake-ups every frame.
+///
+/// Without this connection, tasks woken from other threads would never be resumed. Thus, fails loudly if there is no scene tree yet.
+fn ensure_frame_driver() {
+    if with_runtime(|rt| rt.frame_driver_connected) {
+        return;
+    }
+
+    let mut tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("godot::task::spawn() requires the main loop to be a SceneTree; spawn tasks once the scene tree exists");
+
+    let driver = Callable::from_fn("godot::task::process_frame", |_args| {
+        process_deferred_wakers();
+        Ok(Variant::nil())
+    });
+
+    tree.connect("process_frame".into(), driver);
+    with_runtime(|rt| rt.frame_driver_connected = true);
+}
+
+fn process_deferred_wakers() {
+    let wakers = std::mem::take(&mut *DEFERRED_WAKERS.lock().expect("deferred wakers poisoned"));
+
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+fn is_main_thread() -> bool {
+    let os = Os::singleton();
+    os.get_thread_caller_id() == os.get_main_thread_id()
+}
+
+struct TaskWaker {
+    id: TaskId,
+    thread_id: ThreadId,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref()
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let is_polling =
+            || with_runtime(|rt| matches!(rt.tasks.get(&self.id), Some(TaskSlot::Polling { .. })));
+
+        // Poll right away when on the runtime's thread, so signal awaits resume during emission (like GDScript).
+        // A task waking itself while being polled is resumed next frame, to avoid busy loops within a single frame.
+        if thread::current().id() == self.thread_id && !is_polling() {
+            poll_task(self.id);
+        } else {
+            wake_deferred(Waker::from(self.clone()));
+        }
+    }
+}
@@ -0,0 +1,387 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::{Future, IntoFuture};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::meta::{CallContext, CallError, ParamTuple};
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::engine::object::ConnectFlags;
+use crate::engine::{Engine, SceneTree};
+use crate::obj::EngineEnum as _;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Future that resolves when a Godot signal is emitted, and yields the signal arguments.
+///
+/// Created by [`Signal::to_future()`], or by awaiting a [`Signal`] directly. The output type `R` is usually a tuple of the signal's
+/// parameter types, see [`FromSignalArgs`].
+///
+/// # Panics
+/// When polled after the object owning the signal has been freed before the signal was emitted, or when the signal arguments cannot be
+/// converted to `R`. In a task started with [`spawn()`](super::spawn), this aborts the task. Use [`FallibleSignalFuture`] to handle
+/// these cases gracefully.
+pub struct SignalFuture<R: FromSignalArgs>(FallibleSignalFuture<R>);
+
+impl<R: FromSignalArgs> SignalFuture<R> {
+    fn new(signal: Signal) -> Self {
+        Self(FallibleSignalFuture::new(signal))
+    }
+}
+
+impl<R: FromSignalArgs> Future for SignalFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        match this.0.poll_result(cx) {
+            Poll::Ready(Ok(args)) => Poll::Ready(args),
+            Poll::Ready(Err(err)) => panic!("{err}"),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Future that resolves when a Godot signal is emitted, or fails when the signal can no longer be emitted.
+///
+/// This is the fallible counterpart of [`SignalFuture`]: instead of panicking, it yields [`FallibleSignalFutureError`] if the object
+/// owning the signal is freed before the signal is emitted, or if the signal arguments cannot be converted to `R`.
+///
+/// The signal may be emitted on any thread. If it is emitted on another thread than the one awaiting it, the awaiting task is resumed
+/// at the beginning of the next frame.
+pub struct FallibleSignalFuture<R: FromSignalArgs> {
+    state: Arc<Mutex<SignalFutureState>>,
+    signal: Signal,
+    resolver_id: u64,
+    _output: PhantomData<fn() -> R>,
+}
+
+impl<R: FromSignalArgs> FallibleSignalFuture<R> {
+    fn new(signal: Signal) -> Self {
+        let state = Arc::new(Mutex::new(SignalFutureState::Pending(None)));
+        let resolver = SignalFutureResolver::new(state.clone());
+        let resolver_id = resolver.id;
+
+        // One-shot connection: Godot removes it after the first emission, which drops the resolver.
+        // If the object is freed beforehand, the connection is removed as well, which marks the future as dead.
+        let flags = ConnectFlags::ONE_SHOT.ord() as i64;
+        let error = signal.connect(Callable::from_custom(resolver), flags);
+
+        if error != crate::engine::global::Error::OK {
+            *lock_state(&state) = SignalFutureState::Dead;
+        }
+
+        Self {
+            state,
+            signal,
+            resolver_id,
+            _output: PhantomData,
+        }
+    }
+
+    fn poll_result(&mut self, cx: &mut Context<'_>) -> Poll<Result<R, FallibleSignalFutureError>> {
+        let mut state = lock_state(&self.state);
+
+        match std::mem::replace(&mut *state, SignalFutureState::Consumed) {
+            SignalFutureState::Pending(_) => {
+                *state = SignalFutureState::Pending(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            SignalFutureState::Ready(SignalArgs(args)) => {
+                drop(state);
+
+                let signal_name = self.signal.name().to_string();
+                let call_ctx = CallContext::func("Signal", &signal_name);
+                let result = R::from_signal_args(&args, &call_ctx)
+                    .map_err(FallibleSignalFutureError::BadArguments);
+
+                Poll::Ready(result)
+            }
+            SignalFutureState::Dead => Poll::Ready(Err(FallibleSignalFutureError::ObjectFreed)),
+            SignalFutureState::Consumed => {
+                panic!("FallibleSignalFuture polled after completion")
+            }
+        }
+    }
+}
+
+impl<R: FromSignalArgs> Future for FallibleSignalFuture<R> {
+    type Output = Result<R, FallibleSignalFutureError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().poll_result(cx)
+    }
+}
+
+impl<R: FromSignalArgs> Drop for FallibleSignalFuture<R> {
+    fn drop(&mut self) {
+        // If the future is dropped before the signal fired (e.g. task cancelled), remove the connection, so it doesn't linger until
+        // the next emission. The lookup works through the resolver's ID-based equality.
+        let is_pending = matches!(*lock_state(&self.state), SignalFutureState::Pending(_));
+        if !is_pending || self.signal.is_null() {
+            return;
+        }
+
+        let lookup = Callable::from_custom(SignalFutureResolver::lookup(self.resolver_id));
+        if self.signal.is_connected(lookup.clone()) {
+            self.signal.disconnect(lookup);
+        }
+    }
+}
+
+/// Error yielded by [`FallibleSignalFuture`].
+#[derive(Debug)]
+pub enum FallibleSignalFutureError {
+    /// The object owning the signal was freed before the signal was emitted.
+    ObjectFreed,
+
+    /// The signal was emitted, but its arguments could not be converted to the output type of the future.
+    BadArguments(CallError),
+}
+
+impl fmt::Display for FallibleSignalFutureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ObjectFreed => write!(
+                f,
+                "the object of the awaited signal was freed before the signal was emitted"
+            ),
+            Self::BadArguments(err) => write!(f, "signal arguments do not match: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for FallibleSignalFutureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::ObjectFreed => None,
+            Self::BadArguments(err) => Some(err),
+        }
+    }
+}
+
+/// Conversion from the arguments of an emitted signal to the output of a [`SignalFuture`].
+///
+/// Implemented for:
+/// - Tuples `(P0, P1, ...)` of up to 10 elements implementing `FromGodot`. The number of arguments must match exactly.
+///   For signals without parameters, use `()`.
+/// - `Vec<Variant>`, which accepts any arguments without conversion.
+pub trait FromSignalArgs: Sized + 'static {
+    #[doc(hidden)]
+    fn from_signal_args(
+        args: &[Variant],
+        call_ctx: &CallContext,
+    ) -> Result<Self, crate::builtin::meta::CallError>;
+}
+
+impl<Ps: ParamTuple + 'static> FromSignalArgs for Ps {
+    fn from_signal_args(
+        args: &[Variant],
+        call_ctx: &CallContext,
+    ) -> Result<Self, crate::builtin::meta::CallError> {
+        let arg_refs: Vec<&Variant> = args.iter().collect();
+        Ps::from_variant_args(&arg_refs, call_ctx)
+    }
+}
+
+impl FromSignalArgs for Vec<Variant> {
+    fn from_signal_args(
+        args: &[Variant],
+        _call_ctx: &CallContext,
+    ) -> Result<Self, crate::builtin::meta::CallError> {
+        Ok(args.to_vec())
+    }
+}
+
+impl Signal {
+    /// Returns a future that resolves the next time this signal is emitted.
+    ///
+    /// The output `R` is typically a tuple of the signal parameters, see [`FromSignalArgs`]. The connection is established immediately,
+    /// so emissions between this call and the first `.await` are not missed.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # async fn example(player: Gd<Node>) {
+    /// let (damage, _source): (i64, Gd<Node>) = player.signal("hit").to_future().await;
+    /// # }
+    /// ```
+    pub fn to_future<R: FromSignalArgs>(&self) -> SignalFuture<R> {
+        SignalFuture::new(self.clone())
+    }
+
+    /// Like [`to_future()`][Self::to_future], but yields an error instead of panicking if the object is freed first.
+    pub fn to_fallible_future<R: FromSignalArgs>(&self) -> FallibleSignalFuture<R> {
+        FallibleSignalFuture::new(self.clone())
+    }
+}
+
+/// Awaits the signal without converting its arguments.
+///
+/// `signal.await` is equivalent to `signal.to_future::<Vec<Variant>>().await`.
+impl IntoFuture for Signal {
+    type Output = Vec<Variant>;
+    type IntoFuture = SignalFuture<Vec<Variant>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        SignalFuture::new(self)
+    }
+}
+
+/// Returns a future that resolves at the beginning of the next frame.
+///
+/// Resolves on the `process_frame` signal of the [`SceneTree`], similar to `await get_tree().process_frame` in GDScript.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn next_frame() -> SignalFuture<()> {
+    scene_tree().signal("process_frame").to_future()
+}
+
+/// Returns a future that resolves after `seconds` have passed.
+///
+/// Uses a one-shot timer of the [`SceneTree`], similar to `await get_tree().create_timer(seconds).timeout` in GDScript.
+/// The timer respects pausing and time scale with the default settings of `SceneTree::create_timer()`.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn timer(seconds: f64) -> SignalFuture<()> {
+    let timer = scene_tree()
+        .create_timer(seconds)
+        .expect("SceneTree::create_timer() returned null");
+
+    timer.signal("timeout").to_future()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum SignalFutureState {
+    /// Signal not yet emitted; holds the waker of the last poll.
+    Pending(Option<Waker>),
+
+    /// Signal emitted, holding its arguments.
+    Ready(SignalArgs),
+
+    /// Signal can no longer be emitted, because the connection was removed before emission.
+    Dead,
+
+    /// Output has been taken by the future.
+    Consumed,
+}
+
+/// Arguments of an emitted signal, on their way from the emitting to the awaiting thread.
+struct SignalArgs(Vec<Variant>);
+
+// SAFETY: The arguments are moved to the awaiting thread as a whole and never accessed concurrently; Godot passes variants between
+// threads the same way for deferred calls. Contained objects are reference-counted atomically, or not owned by the variant at all.
+unsafe impl Send for SignalArgs {}
+
+/// Custom callable connected to the signal, which stores the arguments and wakes the future.
+///
+/// The signal may be emitted on any thread. The waker of a spawned task takes care of resuming the task on its own thread.
+///
+/// Each resolver has a unique ID, used for equality. This allows to look up the connection for disconnecting.
+struct SignalFutureResolver {
+    id: u64,
+
+    /// `None` for instances that are only used for lookup.
+    state: Option<Arc<Mutex<SignalFutureState>>>,
+}
+
+impl SignalFutureResolver {
+    fn new(state: Arc<Mutex<SignalFutureState>>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            state: Some(state),
+        }
+    }
+
+    fn lookup(id: u64) -> Self {
+        Self { id, state: None }
+    }
+
+    /// Transitions the state and wakes the future, if still pending.
+    ///
+    /// With `defer_wake`, the future is only woken on the next frame, e.g. when the resolver is dropped during destruction of an object.
+    fn resolve(&self, new_state: SignalFutureState, defer_wake: bool) {
+        let Some(state) = self.state.as_ref() else {
+            return;
+        };
+
+        let mut state = lock_state(state);
+        let SignalFutureState::Pending(waker) = &mut *state else {
+            return;
+        };
+
+        let waker = waker.take();
+        *state = new_state;
+
+        // Release lock before waking, since the task may be polled immediately.
+        drop(state);
+        match waker {
+            Some(waker) if defer_wake => super::async_runtime::wake_deferred(waker),
+            Some(waker) => waker.wake(),
+            None => {}
+        }
+    }
+}
+
+impl RustCallable for SignalFutureResolver {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        let args = args.iter().map(|&arg| arg.clone()).collect();
+        self.resolve(SignalFutureState::Ready(SignalArgs(args)), false);
+
+        Ok(Variant::nil())
+    }
+}
+
+impl Drop for SignalFutureResolver {
+    fn drop(&mut self) {
+        // Godot drops the callable after the one-shot emission, or when the connection is removed because the object is freed.
+        // In the latter case, the future is still pending and would otherwise never complete.
+        self.resolve(SignalFutureState::Dead, true);
+    }
+}
+
+impl PartialEq for SignalFutureResolver {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Hash for SignalFutureResolver {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Display for SignalFutureResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SignalFutureResolver::{}", self.id)
+    }
+}
+
+fn lock_state(state: &Mutex<SignalFutureState>) -> MutexGuard<'_, SignalFutureState> {
+    state.lock().expect("signal future state poisoned")
+}
+
+fn scene_tree() -> crate::obj::Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("godot::task: main loop is not a SceneTree")
+}
@@ -0,0 +1,27 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Async tasks, driven by the Godot main loop.
+//!
+//! This module allows writing sequential game logic with Rust `async`/`await`, similar to `await` in GDScript:
+//! - [`spawn()`] starts a task on the main thread, which is resumed whenever something it awaits becomes ready.
+//! - Signals can be awaited via [`Signal::to_future()`][crate::builtin::Signal::to_future] or directly with `.await`.
+//!   [`Gd::signal()`][crate::obj::Gd::signal] is a shorthand to obtain a signal of an object.
+//! - [`next_frame()`] and [`timer()`] wait for the next frame or a given time, respectively.
+//!
+//! Only available from Godot 4.2 on.
+
+mod async_runtime;
+mod futures;
+
+pub use async_runtime::{spawn, TaskHandle};
+pub use futures::{
+    next_frame, timer, FallibleSignalFuture, FallibleSignalFutureError, FromSignalArgs,
+    SignalFuture,
+};
+
+pub(crate) use async_runtime::cleanup;
@@ -23,15 +23,26 @@ const WARMUP_RUNS: usize = 200;
 const TEST_RUNS: usize = 501; // uneven, so median need not be interpolated.
 const METRIC_COUNT: usize = 2;
 
-pub struct BenchResult {
+/// Signal to the compiler that a value is used (to avoid optimization).
+#[doc(hidden)]
+pub fn bench_used<T: Sized>(value: T) {
+    // The following check would be used to prevent `()` arguments, ensuring that a value from the bench is actually going into the blackbox.
+    // However, we run into this issue, despite no array being used: https://github.com/rust-lang/rust/issues/43408.
+    //   error[E0401]: can't use generic parameters from outer function
+    // sys::static_assert!(std::mem::size_of::<T>() != 0, "returned unit value in benchmark; make sure to use a real value");
+
+    std::hint::black_box(value);
+}
+
+pub(crate) struct BenchResult {
     pub stats: [Duration; METRIC_COUNT],
 }
 
-pub fn metrics() -> [&'static str; METRIC_COUNT] {
+pub(crate) fn metrics() -> [&'static str; METRIC_COUNT] {
     ["min", "median"]
 }
 
-pub fn run_benchmark(code: fn(), inner_repetitions: usize) -> BenchResult {
+pub(crate) fn run_benchmark(code: fn(), inner_repetitions: usize) -> BenchResult {
     for _ in 0..WARMUP_RUNS {
         code();
     }
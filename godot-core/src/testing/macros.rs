@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Declares a `Node` class which runs all `#[itest]` tests and `#[bench]` benchmarks of the crate.
+///
+/// When the class is the root of the running scene, it runs the tests as soon as the scene is ready, and then quits Godot with the
+/// resulting exit code. See [`TestRunner::run_from_command_line()`][crate::testing::TestRunner::run_from_command_line].
+///
+/// The class also exposes `run_all_tests()` and `run_all_benchmarks()` to GDScript, which allows custom runner scripts to include
+/// GDScript test cases or set up the scene before tests start. Such scripts instantiate the class themselves; it then doesn't run any
+/// tests on its own, even if added to the scene tree.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::test::itest;
+///
+/// godot::test::integration_tests!(pub IntegrationTests);
+///
+/// #[itest]
+/// fn vector_length() {
+///     assert_eq!(Vector2::new(3.0, 4.0).length(), 5.0);
+/// }
+/// ```
+#[macro_export]
+macro_rules! integration_tests {
+    ($vis:vis $Runner:ident) => {
+        #[derive(::godot::register::GodotClass)]
+        #[class(init, base = Node)]
+        $vis struct $Runner {
+            base: $crate::obj::Base<$crate::engine::Node>,
+            runner: $crate::testing::TestRunner,
+        }
+
+        #[::godot::register::godot_api]
+        impl $Runner {
+            #[func]
+            fn run_all_tests(
+                &mut self,
+                gdscript_tests: $crate::builtin::VariantArray,
+                gdscript_file_count: i64,
+                allow_focus: bool,
+                scene_tree: $crate::obj::Gd<$crate::engine::Node>,
+                filters: $crate::builtin::VariantArray,
+            ) -> bool {
+                self.runner.run_all_tests(
+                    gdscript_tests,
+                    gdscript_file_count,
+                    allow_focus,
+                    scene_tree,
+                    filters,
+                )
+            }
+
+            #[func]
+            fn run_all_benchmarks(&mut self, scene_tree: $crate::obj::Gd<$crate::engine::Node>) {
+                self.runner.run_all_benchmarks(scene_tree);
+            }
+
+            #[func]
+            fn run_from_command_line(&mut self) {
+                use $crate::obj::WithBaseField as _;
+
+                let scene_tree = self.to_gd().upcast();
+                let exit_code = self.runner.run_from_command_line(scene_tree);
+
+                if let Some(mut tree) = self.base().get_tree() {
+                    tree.quit_ex().exit_code(exit_code).done();
+                }
+            }
+        }
+
+        #[::godot::register::godot_api]
+        impl $crate::engine::INode for $Runner {
+            fn ready(&mut self) {
+                use $crate::obj::WithBaseField as _;
+
+                // Only run automatically as the main scene, not when instantiated by a custom runner.
+                let current_scene = self.base().get_tree().and_then(|tree| tree.get_current_scene());
+                if current_scene != Some(self.to_gd().upcast::<$crate::engine::Node>()) {
+                    return;
+                }
+
+                // Deferred, so that tests can add nodes to the tree, which is still busy while `ready()` runs.
+                self.base_mut()
+                    .call_deferred("run_from_command_line".into(), &[]);
+            }
+        }
+    };
+}
@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Integration tests that run inside a Godot engine instance.
+//!
+//! Unit tests with `#[test]` run outside the engine, so they cannot use any Godot APIs. This module provides a small framework to write
+//! tests against a running engine, as used by godot-rust itself:
+//! - Functions annotated with `#[itest]` are registered as tests. They can optionally take a [`&TestContext`][TestContext]
+//!   parameter, which gives access to a node in the scene tree. `#[itest(skip)]` and `#[itest(focus)]` exclude or single out tests.
+//! - Functions annotated with `#[bench]` are registered as micro-benchmarks, which are run after all tests passed.
+//! - The [`integration_tests!`] macro declares a Godot class that runs all registered tests.
+//!
+//! # Running tests
+//! The simplest way to run the tests is a scene that contains only the runner class as its root node. The runner starts once the scene
+//! is ready, prints the results and quits Godot with exit code 0 if all tests passed, 1 if some failed, or 2 if the invocation was invalid.
+//! ```text
+//! [gd_scene format=3]
+//!
+//! [node name="TestRunner" type="IntegrationTests"]
+//! ```
+//!
+//! This scene is then launched in headless mode, optionally followed by user arguments:
+//! ```text
+//! godot --headless --path path/to/project res://TestRunner.tscn -- [filter1,filter2] --disallow-focus
+//! ```
+//! - `[filter1,filter2]` only runs tests whose name contains one of the comma-separated strings.
+//! - `--disallow-focus` fails the run if any test is annotated with `#[itest(focus)]`, which is useful in CI.
+//!
+//! For more control, e.g. to include GDScript test suites, a custom scene can instantiate the runner class and invoke its
+//! `run_all_tests()` and `run_all_benchmarks()` methods directly.
+//!
+//! Only available with the `testing` Cargo feature.
+
+use crate::engine::{Engine, Node};
+use crate::obj::Gd;
+use crate::sys;
+use std::collections::HashSet;
+
+mod bencher;
+mod macros;
+mod runner;
+
+pub use crate::integration_tests;
+pub use runner::TestRunner;
+
+#[doc(hidden)]
+pub use bencher::bench_used;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Plugin registration
+
+// Registers all the `#[itest]` tests and `#[bench]` benchmarks.
+sys::plugin_registry!(pub __GODOT_ITEST: RustTestCase);
+sys::plugin_registry!(pub __GODOT_BENCH: RustBenchmark);
+
+/// Finds all `#[itest]` tests.
+fn collect_rust_tests(filters: &[String]) -> (Vec<RustTestCase>, usize, bool) {
+    let mut all_files = HashSet::new();
+    let mut tests: Vec<RustTestCase> = vec![];
+    let mut is_focus_run = false;
+
+    sys::plugin_foreach!(__GODOT_ITEST; |test: &RustTestCase| {
+        // First time a focused test is encountered, switch to "focused" mode and throw everything away.
+        if !is_focus_run && test.focused {
+            tests.clear();
+            all_files.clear();
+            is_focus_run = true;
+        }
+
+        // Only collect tests if normal mode, or focus mode and test is focused.
+        if (!is_focus_run || test.focused) && passes_filter(filters, test.name) {
+            all_files.insert(test.file);
+            tests.push(*test);
+        }
+    });
+
+    // Sort alphabetically for deterministic run order
+    tests.sort_by_key(|test| test.file);
+
+    (tests, all_files.len(), is_focus_run)
+}
+
+/// Finds all `#[bench]` benchmarks.
+fn collect_rust_benchmarks() -> (Vec<RustBenchmark>, usize) {
+    let mut all_files = HashSet::new();
+    let mut benchmarks: Vec<RustBenchmark> = vec![];
+
+    sys::plugin_foreach!(__GODOT_BENCH; |bench: &RustBenchmark| {
+        benchmarks.push(*bench);
+        all_files.insert(bench.file);
+    });
+
+    // Sort alphabetically for deterministic run order
+    benchmarks.sort_by_key(|bench| bench.file);
+
+    (benchmarks, all_files.len())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shared types
+
+/// Passed to `#[itest]` functions that declare a `ctx: &TestContext` parameter.
+pub struct TestContext {
+    /// Node inside the scene tree, which tests can use as a parent for their own nodes.
+    pub scene_tree: Gd<Node>,
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct RustTestCase {
+    pub name: &'static str,
+    pub file: &'static str,
+    pub skipped: bool,
+    /// If one or more tests are focused, only they will be executed. Helpful for debugging and working on specific features.
+    pub focused: bool,
+    #[allow(dead_code)]
+    pub line: u32,
+    pub function: fn(&TestContext),
+}
+
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct RustBenchmark {
+    pub name: &'static str,
+    pub file: &'static str,
+    #[allow(dead_code)]
+    pub line: u32,
+    pub function: fn(),
+    pub repetitions: usize,
+}
+
+/// Whether a test named `test_name` is selected by `filters`, i.e. contains one of them. An empty filter list selects all tests.
+pub fn passes_filter(filters: &[String], test_name: &str) -> bool {
+    filters.is_empty() || filters.iter().any(|x| test_name.contains(x))
+}
+
+/// Asserts that `code` panics, while suppressing the panic message.
+///
+/// `context` is included in the failure message, if the code does not panic.
+pub fn expect_panic(context: &str, code: impl FnOnce()) {
+    use std::panic;
+
+    // Exchange panic hook, to disable printing during expected panics. Also disable gdext's panic printing.
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_panic_info| {}));
+    let prev_print_level = crate::private::set_error_print_level(0);
+
+    // Generally, types should be unwind safe, and this helps ergonomics in testing (especially around &mut in expect_panic closures).
+    let code = panic::AssertUnwindSafe(code);
+
+    // Run code that should panic, restore hook + gdext panic printing.
+    let panic = panic::catch_unwind(code);
+    panic::set_hook(prev_hook);
+    crate::private::set_error_print_level(prev_print_level);
+
+    assert!(
+        panic.is_err(),
+        "code should have panicked but did not: {context}",
+    );
+}
+
+/// Disables printing of Godot errors while `f` runs.
+///
+/// Ideally, errors would be caught and checked to happen when expected, but that isn't possible. This at least avoids spamming the
+/// terminal when a test triggers errors on purpose.
+pub fn suppress_godot_print(mut f: impl FnMut()) {
+    Engine::singleton().set_print_error_messages(false);
+    f();
+    Engine::singleton().set_print_error_messages(true);
+}
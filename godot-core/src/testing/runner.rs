@@ -7,20 +7,20 @@
 
 use std::time::{Duration, Instant};
 
-use godot::builtin::meta::ToGodot;
-use godot::builtin::{Array, GString, Variant, VariantArray};
-use godot::engine::{Engine, Node, Os};
-use godot::log::godot_error;
-use godot::obj::Gd;
-use godot::register::{godot_api, GodotClass};
-
-use crate::framework::{
-    bencher, passes_filter, BenchResult, RustBenchmark, RustTestCase, TestContext,
-};
-
-#[derive(GodotClass, Debug)]
-#[class(init)]
-pub struct IntegrationTests {
+use crate::builtin::meta::ToGodot;
+use crate::builtin::{Array, GString, Variant, VariantArray};
+use crate::engine::{DisplayServer, Engine, Node, Os};
+use crate::godot_error;
+use crate::obj::Gd;
+
+use super::bencher::{self, BenchResult};
+use super::{passes_filter, RustBenchmark, RustTestCase, TestContext};
+
+/// Runs `#[itest]` tests and `#[bench]` benchmarks, and keeps track of their results.
+///
+/// This is the state behind the class declared by [`integration_tests!`][crate::testing::integration_tests]; it is usually not used directly.
+#[derive(Default, Debug)]
+pub struct TestRunner {
     total: i64,
     passed: i64,
     skipped: i64,
@@ -28,18 +28,19 @@ pub struct IntegrationTests {
     focus_run: bool,
 }
 
-#[godot_api]
-impl IntegrationTests {
+impl TestRunner {
+    /// Runs the Rust tests and the given GDScript test cases, returning whether all of them passed.
+    ///
+    /// Each GDScript test case is an object with properties `suite_name` and `method_name`, and a method `run()` returning `bool`.
+    /// Only tests with names passing `filters` are run. If `allow_focus` is false, a run with `#[itest(focus)]` tests fails.
     #[allow(clippy::uninlined_format_args)]
-    #[func]
-    fn run_all_tests(
+    pub fn run_all_tests(
         &mut self,
         gdscript_tests: VariantArray,
         gdscript_file_count: i64,
         allow_focus: bool,
         scene_tree: Gd<Node>,
         filters: VariantArray,
-        property_tests: Gd<Node>,
     ) -> bool {
         println!("{}Run{} Godot integration tests...", FMT_CYAN_BOLD, FMT_END);
         let filters: Vec<String> = filters.iter_shared().map(|v| v.to::<String>()).collect();
@@ -63,7 +64,7 @@ impl IntegrationTests {
             rust_tests.len(),
             rust_file_count
         );
-        if !focus_run {
+        if !focus_run && gdscript_file_count > 0 {
             println!(
                 "  GDScript: found {} tests in {} files.",
                 gdscript_tests.len(),
@@ -72,9 +73,8 @@ impl IntegrationTests {
         }
 
         let clock = Instant::now();
-        self.run_rust_tests(rust_tests, scene_tree, property_tests.clone());
+        self.run_rust_tests(rust_tests, scene_tree);
         let rust_time = clock.elapsed();
-        property_tests.free();
 
         let gdscript_time = if !focus_run {
             let extra_duration = self.run_gdscript_tests(gdscript_tests);
@@ -86,18 +86,22 @@ impl IntegrationTests {
         self.conclude_tests(rust_time, gdscript_time, allow_focus)
     }
 
-    #[func]
-    fn run_all_benchmarks(&mut self, scene_tree: Gd<Node>) {
+    /// Runs all `#[bench]` benchmarks, unless the preceding test run was focused. Does nothing if there are no benchmarks.
+    pub fn run_all_benchmarks(&mut self, scene_tree: Gd<Node>) {
         if self.focus_run {
             println!("  Benchmarks skipped (focused run).");
             return;
         }
 
+        let (benchmarks, rust_file_count) = super::collect_rust_benchmarks();
+        if benchmarks.is_empty() {
+            return;
+        }
+
         println!("\n\n{}Run{} Godot benchmarks...", FMT_CYAN_BOLD, FMT_END);
 
         self.warn_if_debug();
 
-        let (benchmarks, rust_file_count) = super::collect_rust_benchmarks();
         println!(
             "  Rust: found {} benchmarks in {} files.",
             benchmarks.len(),
@@ -124,16 +128,58 @@ impl IntegrationTests {
         }
     }
 
-    fn run_rust_tests(
-        &mut self,
-        tests: Vec<RustTestCase>,
-        scene_tree: Gd<Node>,
-        property_tests: Gd<Node>,
-    ) {
-        let ctx = TestContext {
-            scene_tree,
-            property_tests,
-        };
+    /// Runs tests and benchmarks as configured by the command-line user arguments, and returns the process exit code.
+    ///
+    /// Recognized arguments are `[filter1,filter2,...]` and `--disallow-focus`. The exit code is 0 on success, 1 if tests failed,
+    /// and 2 if the tests could not be run.
+    pub fn run_from_command_line(&mut self, scene_tree: Gd<Node>) -> i32 {
+        // Loading the editor may break some parts, so tests must run in headless mode.
+        // Both checks are needed (it's possible to invoke `godot -e --headless`).
+        let is_headless = DisplayServer::singleton().get_name() == "headless".into();
+        if Engine::singleton().is_editor_hint() || !is_headless {
+            godot_error!("Integration tests must be run in headless mode (without editor).");
+            return 2;
+        }
+
+        let mut allow_focus = true;
+        let mut filters = VariantArray::new();
+        let mut unrecognized_args = Vec::new();
+        for arg in Os::singleton().get_cmdline_user_args().as_slice() {
+            let arg = arg.to_string();
+            if arg == "--disallow-focus" {
+                allow_focus = false;
+            } else if let Some(list) = arg.strip_prefix('[').and_then(|a| a.strip_suffix(']')) {
+                for filter in list.split(',') {
+                    filters.push(filter.to_variant());
+                }
+            } else {
+                unrecognized_args.push(arg);
+            }
+        }
+
+        if !unrecognized_args.is_empty() {
+            godot_error!("Unrecognized arguments: {unrecognized_args:?}");
+            return 2;
+        }
+
+        let success = self.run_all_tests(
+            VariantArray::new(),
+            0,
+            allow_focus,
+            scene_tree.clone(),
+            filters,
+        );
+
+        if success {
+            self.run_all_benchmarks(scene_tree);
+            0
+        } else {
+            1
+        }
+    }
+
+    fn run_rust_tests(&mut self, tests: Vec<RustTestCase>, scene_tree: Gd<Node>) {
+        let ctx = TestContext { scene_tree };
 
         let mut last_file = None;
         for test in tests {
@@ -184,7 +230,7 @@ impl IntegrationTests {
                     // could not be caught, causing UB at the Godot FFI boundary (in practice, this will be a defined Godot crash with
                     // stack trace though).
                     godot_error!("GDScript test panicked");
-                    godot::private::extract_panic_message(e);
+                    crate::private::extract_panic_message(e);
                     TestOutcome::Failed
                 }
             };
@@ -310,7 +356,7 @@ fn run_rust_test(test: &RustTestCase, ctx: &TestContext) -> TestOutcome {
 
     // Explicit type to prevent tests from returning a value
     let err_context = || format!("itest `{}` failed", test.name);
-    let success: Result<(), _> = godot::private::handle_panic(err_context, || (test.function)(ctx));
+    let success: Result<(), _> = crate::private::handle_panic(err_context, || (test.function)(ctx));
 
     TestOutcome::from_bool(success.is_ok())
 }
@@ -322,7 +368,7 @@ fn print_test_pre(test_case: &str, test_file: String, last_file: &mut Option<Str
     if flush {
         // Flush in GDScript, because its own print may come sooner than Rust prints otherwise.
         // (Strictly speaking, this can also happen from Rust, when Godot prints something. So far, it didn't though...)
-        godot::private::flush_stdout();
+        crate::private::flush_stdout();
     }
 }
 
@@ -46,11 +46,11 @@ pub fn attribute_bench(input_decl: venial::Item) -> ParseResult<TokenStream> {
         pub fn #bench_name() {
             for _ in 0..#repetitions {
                 let __ret: #ret = #body;
-                crate::common::bench_used(__ret);
+                ::godot::test::bench_used(__ret);
             }
         }
 
-        ::godot::sys::plugin_add!(__GODOT_BENCH in crate::framework; crate::framework::RustBenchmark {
+        ::godot::sys::plugin_add!(__GODOT_BENCH in ::godot::test; ::godot::test::RustBenchmark {
             name: #bench_name_str,
             file: std::file!(),
             line: std::line!(),
@@ -6,6 +6,7 @@
  */
 
 use crate::class::{FieldExport, FieldVar};
+use crate::docs;
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -14,7 +15,15 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    /// `EditorProperty` class from `#[export(editor = T)]`, which edits this property in the inspector.
+    pub editor: Option<TokenStream>,
     pub is_onready: bool,
+
+    /// Content of the field's `///` doc comments.
+    pub docs: String,
+
+    /// Categories, groups and subgroups that start at this field, in registration order.
+    pub groups: Vec<FieldGroup>,
 }
 
 impl Field {
@@ -25,11 +34,45 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            editor: None,
             is_onready: false,
+            docs: docs::extract_docs(&field.attributes),
+            groups: Vec::new(),
+        }
+    }
+
+    /// Name under which the field is registered as a property with `#[var]` or `#[export]`.
+    pub fn property_name(&self) -> String {
+        self.name.to_string()
+    }
+}
+
+/// Kind of inspector section started by `#[export_category]`, `#[export_group]` or `#[export_subgroup]`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FieldGroupKind {
+    Category,
+    Group,
+    Subgroup,
+}
+
+impl FieldGroupKind {
+    /// Name of the field attribute, e.g. `export_group`.
+    pub fn attribute_name(self) -> &'static str {
+        match self {
+            Self::Category => "export_category",
+            Self::Group => "export_group",
+            Self::Subgroup => "export_subgroup",
         }
     }
 }
 
+/// Inspector section, registered as a pseudo-property right before the field it's declared on.
+pub struct FieldGroup {
+    pub kind: FieldGroupKind,
+    pub name: TokenStream,
+    pub prefix: Option<TokenStream>,
+}
+
 pub struct Fields {
     /// All fields except `base_field`.
     pub all_fields: Vec<Field>,
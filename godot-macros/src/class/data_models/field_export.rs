@@ -118,6 +118,13 @@ pub enum FieldExport {
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePath { classes: Vec<TokenStream> },
 }
 
 impl FieldExport {
@@ -252,6 +259,10 @@ impl FieldExport {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some(list_parser) = parser.handle_list("node_path")? {
+            return Self::new_node_path(list_parser);
+        }
+
         Ok(FieldExport::Default)
     }
 
@@ -349,6 +360,18 @@ impl FieldExport {
 
         Ok(Self::Flags { bits })
     }
+
+    fn new_node_path(mut parser: ListParser) -> ParseResult<Self> {
+        let mut classes = Vec::new();
+
+        while parser.peek().is_some() {
+            classes.push(parser.next_expr()?);
+        }
+
+        parser.finish()?;
+
+        Ok(Self::NodePath { classes })
+    }
 }
 
 macro_rules! quote_export_func {
@@ -456,6 +479,16 @@ impl FieldExport {
                 export_placeholder(#placeholder)
             },
             FieldExport::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            FieldExport::NodePath { classes } => {
+                let class_names = classes.iter().map(|class| {
+                    quote! { ::godot::register::property::export_info_functions::node_path_class::<#class>() }
+                });
+
+                quote_export_func! {
+                    export_node_path(&[#(#class_names),*])
+                }
+            }
         }
     }
 }
@@ -204,6 +204,9 @@ impl GetterSetterImpl {
                 rename: None,
                 is_virtual: false,
                 has_gd_self: false,
+                rpc_info: None,
+                default_parameters: Vec::new(),
+                is_varargs: false,
             },
         );
 
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::class::RpcAttr;
 use crate::util::{bail_fn, ident};
 use crate::{util, ParseResult};
 use proc_macro2::{Group, Ident, TokenStream, TokenTree};
@@ -20,6 +21,21 @@ pub struct FuncDefinition {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    /// Present if the function is also annotated with `#[rpc]`.
+    pub rpc_info: Option<RpcAttr>,
+    /// Default values of the trailing parameters, from `#[opt(default = ...)]`.
+    pub default_parameters: Vec<TokenStream>,
+    /// Whether the function ends in a `&[Variant]` parameter, which is not part of `signature`.
+    pub is_varargs: bool,
+}
+
+impl FuncDefinition {
+    /// The name under which the function is registered in Godot.
+    pub fn godot_name(&self) -> String {
+        self.rename
+            .clone()
+            .unwrap_or_else(|| self.signature.name.to_string())
+    }
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -63,15 +79,20 @@ pub fn make_method_registration(
     class_name: &Ident,
     func_definition: FuncDefinition,
 ) -> ParseResult<TokenStream> {
-    let signature_info = into_signature_info(
+    let mut signature_info = into_signature_info(
         func_definition.signature,
         class_name,
         func_definition.has_gd_self,
     );
+    signature_info.has_varargs = func_definition.is_varargs;
     let sig_tuple = signature_info.tuple_type();
 
     let is_virtual = func_definition.is_virtual;
-    let method_flags = match make_method_flags(signature_info.receiver_type, is_virtual) {
+    let method_flags = match make_method_flags(
+        signature_info.receiver_type,
+        is_virtual,
+        signature_info.has_varargs,
+    ) {
         Ok(mf) => mf,
         Err(msg) => return bail_fn(msg, signature_info.method_name),
     };
@@ -88,9 +109,34 @@ pub fn make_method_registration(
         method_name.to_string()
     };
 
+    // Default values are evaluated by an associated function, declared in the class' impl block by make_default_args_method().
+    let default_args_fn = (!func_definition.default_parameters.is_empty()).then(|| {
+        let default_args_method = default_args_method_name(method_name);
+        quote! { <#class_name>::#default_args_method }
+    });
+    let default_args = match &default_args_fn {
+        Some(default_args_fn) => quote! { #default_args_fn() },
+        None => quote! { Vec::new() },
+    };
+
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_func = make_varcall_func(&call_ctx, &sig_tuple, &forwarding_closure);
-    let ptrcall_func = make_ptrcall_func(&call_ctx, &sig_tuple, &forwarding_closure);
+    let varcall_func = make_varcall_func(
+        &call_ctx,
+        &sig_tuple,
+        &forwarding_closure,
+        default_args_fn
+            .as_ref()
+            .map(|default_args_fn| (signature_info.param_types.len(), default_args_fn)),
+        signature_info.has_varargs,
+    );
+
+    // Variadic methods can only be called through varcall.
+    let ptrcall_func = if signature_info.has_varargs {
+        quote! { None }
+    } else {
+        let ptrcall_func = make_ptrcall_func(&call_ctx, &sig_tuple, &forwarding_closure);
+        quote! { Some(#ptrcall_func) }
+    };
 
     // String literals II
     let param_ident_strs = signature_info
@@ -127,12 +173,12 @@ pub fn make_method_registration(
                     #class_name::class_name(),
                     method_name,
                     Some(varcall_func),
-                    Some(ptrcall_func),
+                    ptrcall_func,
                     #method_flags,
                     &[
                         #( #param_ident_strs ),*
                     ],
-                    Vec::new()
+                    #default_args
                 )
             };
 
@@ -168,6 +214,8 @@ pub struct SignatureInfo {
     pub param_idents: Vec<Ident>,
     pub param_types: Vec<venial::TypeExpr>,
     pub ret_type: TokenStream,
+    /// Whether a trailing `&[Variant]` parameter receives the remaining arguments (not part of `param_types`).
+    pub has_varargs: bool,
 }
 
 impl SignatureInfo {
@@ -178,6 +226,7 @@ impl SignatureInfo {
             param_idents: vec![],
             param_types: vec![],
             ret_type: quote! { () },
+            has_varargs: false,
         }
     }
 
@@ -207,6 +256,15 @@ fn make_forwarding_closure(
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
+    // Variadic methods receive the remaining arguments as an additional closure parameter, which is forwarded last.
+    let mut args: Vec<TokenStream> = params.iter().map(|param| quote! { #param }).collect();
+    let varargs_param = if signature_info.has_varargs {
+        args.push(quote! { __varargs });
+        quote! { , __varargs }
+    } else {
+        TokenStream::new()
+    };
+
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
             let instance = ::godot::private::Storage::get(storage);
@@ -232,11 +290,11 @@ fn make_forwarding_closure(
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
                 TokenStream::new()
             } else {
-                quote! { instance.#method_name(#(#params),*) }
+                quote! { instance.#method_name(#(#args),*) }
             };
 
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
@@ -252,23 +310,23 @@ fn make_forwarding_closure(
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    <#class_name>::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    <#class_name>::#method_name(::godot::private::Storage::get_gd(storage), #(#args),*)
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
             quote! {
-                |_, params| {
+                |_, params #varargs_param| {
                     let ( #(#params,)* ) = params;
-                    <#class_name>::#method_name(#(#params),*)
+                    <#class_name>::#method_name(#(#args),*)
                 }
             }
         }
@@ -277,7 +335,7 @@ fn make_forwarding_closure(
 
 /// Maps each usage of `Self` to the struct it's referencing,
 /// since `Self` can't be used inside nested functions.
-fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
+pub(crate) fn map_self_to_class_name<In, Out>(tokens: In, class_name: &Ident) -> Out
 where
     In: IntoIterator<Item = TokenTree>,
     Out: FromIterator<TokenTree>,
@@ -360,12 +418,14 @@ pub(crate) fn into_signature_info(
         param_idents,
         param_types,
         ret_type,
+        has_varargs: false,
     }
 }
 
 fn make_method_flags(
     method_type: ReceiverType,
     is_rust_virtual: bool,
+    has_varargs: bool,
 ) -> Result<TokenStream, String> {
     let scope = quote! { ::godot::engine::global::MethodFlags };
 
@@ -391,6 +451,12 @@ fn make_method_flags(
         base_flags
     };
 
+    let flags = if has_varargs {
+        quote! { #flags | #scope::VARARG }
+    } else {
+        flags
+    };
+
     Ok(flags)
 }
 
@@ -399,8 +465,23 @@ fn make_varcall_func(
     call_ctx: &TokenStream,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    param_count_and_default_args_fn: Option<(usize, &TokenStream)>,
+    has_varargs: bool,
 ) -> TokenStream {
-    let invocation = make_varcall_invocation(call_ctx, sig_tuple, wrapped_method);
+    let mut invocation = make_varcall_invocation(call_ctx, sig_tuple, wrapped_method, has_varargs);
+
+    // Omitted trailing arguments are filled in from the values returned by `default_args_fn`.
+    if let Some((param_count, default_args_fn)) = param_count_and_default_args_fn {
+        invocation = quote! {
+            ::godot::private::varcall_with_defaults(
+                args_ptr,
+                arg_count,
+                #param_count,
+                #default_args_fn,
+                |args_ptr, arg_count| #invocation
+            )
+        };
+    }
 
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
@@ -425,6 +506,49 @@ fn make_varcall_func(
     }
 }
 
+/// Generates the associated function `__default_args_{method}() -> Vec<Variant>`, which evaluates the `#[opt(default = ...)]` values
+/// of the trailing parameters.
+///
+/// This is declared in an impl block of the class rather than next to the registration, so that default values can refer to `Self`.
+pub fn make_default_args_method(func_definition: &FuncDefinition) -> Option<TokenStream> {
+    let default_parameters = &func_definition.default_parameters;
+    if default_parameters.is_empty() {
+        return None;
+    }
+
+    let param_types = func_definition
+        .signature
+        .params
+        .inner
+        .iter()
+        .filter_map(|(param, _punct)| match param {
+            venial::FnParam::Typed(param) => Some(&param.ty),
+            venial::FnParam::Receiver(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let param_types = &param_types[param_types.len() - default_parameters.len()..];
+
+    let cfg_attrs = util::extract_cfg_attrs(&func_definition.external_attributes).into_iter();
+    let default_args_method = default_args_method_name(&func_definition.signature.name);
+
+    Some(quote! {
+        #(#cfg_attrs)*
+        #[doc(hidden)]
+        fn #default_args_method() -> Vec<::godot::builtin::Variant> {
+            vec![
+                #({
+                    let value: #param_types = #default_parameters;
+                    ::godot::builtin::meta::ToGodot::to_variant(&value)
+                }),*
+            ]
+        }
+    })
+}
+
+fn default_args_method_name(method_name: &Ident) -> Ident {
+    format_ident!("__default_args_{}", method_name)
+}
+
 /// Generate code for a C FFI function that performs a ptrcall.
 fn make_ptrcall_func(
     call_ctx: &TokenStream,
@@ -486,9 +610,16 @@ fn make_varcall_invocation(
     call_ctx: &TokenStream,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    has_varargs: bool,
 ) -> TokenStream {
+    let in_varcall = if has_varargs {
+        quote! { in_varcall_varargs }
+    } else {
+        quote! { in_varcall }
+    };
+
     quote! {
-        <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall(
+        <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::#in_varcall(
             instance_ptr,
             & #call_ctx,
             args_ptr,
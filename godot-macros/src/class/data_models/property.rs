@@ -7,7 +7,9 @@
 
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{
+    Field, FieldGroup, FieldGroupKind, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags,
+};
 use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -48,10 +50,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
     for field in &fields.all_fields {
         let Field {
-            name: field_ident,
             ty: field_type,
             var,
             export,
+            groups,
             ..
         } = field;
 
@@ -69,9 +71,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             continue;
         };
 
-        let field_variant_type = util::property_variant_type(field_type);
-        let field_class_name = util::property_variant_class_name(field_type);
-        let field_name = field_ident.to_string();
+        let field_name = field.property_name();
 
         let FieldVar {
             getter,
@@ -140,6 +140,11 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             },
         };
 
+        // Groups must be registered right before the first property they contain.
+        for group in groups {
+            export_tokens.push(make_group_registration(&class_name_obj, group));
+        }
+
         let getter_name = make_getter_setter(
             getter.to_impl(class_name, GetSet::Get, field),
             &mut getter_setter_impls,
@@ -155,11 +160,14 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             use ::godot::sys::GodotFfi;
 
             let (hint, hint_string) = #hint;
-            let usage = #usage_flags;
+
+            // Type-level information, e.g. the qualified name of class enums.
+            let type_info = <#field_type as ::godot::builtin::meta::GodotConvert>::godot_property_info(#field_name);
+            let usage = #usage_flags | type_info.type_usage_flags();
 
             let property_info = ::godot::builtin::meta::PropertyInfo {
-                variant_type: #field_variant_type,
-                class_name: #field_class_name,
+                variant_type: type_info.variant_type,
+                class_name: type_info.class_name,
                 property_name: #field_name.into(),
                 hint,
                 hint_string,
@@ -200,6 +208,62 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+/// Registers an inspector category, group or subgroup, which applies to all properties registered after it.
+fn make_group_registration(class_name_obj: &TokenStream, group: &FieldGroup) -> TokenStream {
+    let FieldGroup { kind, name, prefix } = group;
+
+    let prefix = prefix.clone().unwrap_or_else(|| quote! { "" });
+
+    match kind {
+        FieldGroupKind::Category => quote! {
+            use ::godot::sys::GodotFfi;
+
+            // Godot has no dedicated registration function for categories; they are plain pseudo-properties.
+            let property_info = ::godot::builtin::meta::PropertyInfo {
+                variant_type: ::godot::builtin::VariantType::Nil,
+                class_name: ::godot::builtin::meta::ClassName::none(),
+                property_name: ::godot::builtin::StringName::from(#name),
+                hint: ::godot::engine::global::PropertyHint::NONE,
+                hint_string: ::godot::builtin::GString::new(),
+                usage: ::godot::engine::global::PropertyUsageFlags::CATEGORY,
+            };
+
+            let empty_name = ::godot::builtin::StringName::default();
+            let property_info_sys = property_info.property_sys();
+
+            unsafe {
+                ::godot::sys::interface_fn!(classdb_register_extension_class_property)(
+                    ::godot::sys::get_library(),
+                    #class_name_obj.string_sys(),
+                    std::ptr::addr_of!(property_info_sys),
+                    empty_name.string_sys(),
+                    empty_name.string_sys(),
+                );
+            }
+        },
+        FieldGroupKind::Group | FieldGroupKind::Subgroup => {
+            let register_fn = match kind {
+                FieldGroupKind::Group => quote! { classdb_register_extension_class_property_group },
+                _ => quote! { classdb_register_extension_class_property_subgroup },
+            };
+
+            quote! {
+                let group_name = ::godot::builtin::GString::from(#name);
+                let prefix = ::godot::builtin::GString::from(#prefix);
+
+                unsafe {
+                    ::godot::sys::interface_fn!(#register_fn)(
+                        ::godot::sys::get_library(),
+                        #class_name_obj.string_sys(),
+                        group_name.string_sys(),
+                        prefix.string_sys(),
+                    );
+                }
+            }
+        }
+    }
+}
+
 fn make_getter_setter(
     getter_setter_impl: Option<GetterSetterImpl>,
     getter_setter_impls: &mut Vec<TokenStream>,
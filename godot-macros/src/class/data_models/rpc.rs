@@ -0,0 +1,254 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::class::FuncDefinition;
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Store info from `#[rpc]` attribute.
+pub enum RpcAttr {
+    /// Individual keys, e.g. `#[rpc(any_peer, unreliable, channel = 2)]`.
+    ///
+    /// Omitted keys use the defaults of `RpcConfig`.
+    SeparatedArgs {
+        rpc_mode: Option<Ident>,
+        transfer_mode: Option<Ident>,
+        call_local: Option<bool>,
+        channel: Option<TokenStream>,
+    },
+
+    /// User-provided config expression: `#[rpc(config = expr)]`.
+    Expression(TokenStream),
+}
+
+impl RpcAttr {
+    /// Parse a `#[rpc]` attribute.
+    ///
+    /// Possible keys:
+    /// - `any_peer` | `authority`
+    /// - `reliable` | `unreliable` | `unreliable_ordered`
+    /// - `call_local` | `call_remote`
+    /// - `channel = expr`
+    /// - `config = expr` (cannot be combined with the other keys)
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let rpc_mode = handle_exclusive_keys(
+            parser,
+            &[("any_peer", "ANY_PEER"), ("authority", "AUTHORITY")],
+        )?;
+
+        let transfer_mode = handle_exclusive_keys(
+            parser,
+            &[
+                ("reliable", "RELIABLE"),
+                ("unreliable", "UNRELIABLE"),
+                ("unreliable_ordered", "UNRELIABLE_ORDERED"),
+            ],
+        )?;
+
+        let call_local =
+            handle_exclusive_keys(parser, &[("call_local", "true"), ("call_remote", "false")])?
+                .map(|ident| ident == "true");
+
+        let channel = parser.handle_expr("channel")?;
+
+        if let Some(config) = parser.handle_expr("config")? {
+            if rpc_mode.is_some()
+                || transfer_mode.is_some()
+                || call_local.is_some()
+                || channel.is_some()
+            {
+                return bail!(
+                    parser.span(),
+                    "#[rpc]: `config` key cannot be combined with other keys"
+                );
+            }
+
+            return Ok(Self::Expression(config));
+        }
+
+        Ok(Self::SeparatedArgs {
+            rpc_mode,
+            transfer_mode,
+            call_local,
+            channel,
+        })
+    }
+
+    /// Generates an expression evaluating to `RpcConfig`.
+    fn make_config_expr(&self) -> TokenStream {
+        let rpc_config = quote! { ::godot::builtin::meta::registration::rpc_config::RpcConfig };
+
+        match self {
+            Self::SeparatedArgs {
+                rpc_mode,
+                transfer_mode,
+                call_local,
+                channel,
+            } => {
+                if rpc_mode.is_none()
+                    && transfer_mode.is_none()
+                    && call_local.is_none()
+                    && channel.is_none()
+                {
+                    return quote! { #rpc_config::default() };
+                }
+
+                let rpc_mode = rpc_mode.as_ref().map(|mode| {
+                    quote! { rpc_mode: ::godot::engine::multiplayer_api::RpcMode::#mode, }
+                });
+                let transfer_mode = transfer_mode.as_ref().map(|mode| {
+                    quote! { transfer_mode: ::godot::engine::multiplayer_peer::TransferMode::#mode, }
+                });
+                let call_local = call_local.map(|call_local| quote! { call_local: #call_local, });
+                let channel = channel
+                    .as_ref()
+                    .map(|channel| quote! { channel: #channel, });
+
+                quote! {
+                    #rpc_config {
+                        #rpc_mode
+                        #transfer_mode
+                        #call_local
+                        #channel
+                        ..#rpc_config::default()
+                    }
+                }
+            }
+            Self::Expression(expr) => quote! {
+                {
+                    let config: #rpc_config = #expr;
+                    config
+                }
+            },
+        }
+    }
+}
+
+/// Parses a group of mutually exclusive keys, returning the value associated with the one that is present.
+fn handle_exclusive_keys(
+    parser: &mut KvParser,
+    keys: &[(&str, &str)],
+) -> ParseResult<Option<Ident>> {
+    let mut found: Option<(&str, &str)> = None;
+
+    for &(key, value) in keys {
+        if !parser.handle_alone(key)? {
+            continue;
+        }
+
+        if let Some((prev_key, _)) = found {
+            return bail!(
+                parser.span(),
+                "#[rpc] attribute keys `{prev_key}` and `{key}` are mutually exclusive",
+            );
+        }
+
+        found = Some((key, value));
+    }
+
+    Ok(found.map(|(_, value)| format_ident!("{}", value)))
+}
+
+/// Generates the body of `__register_rpcs()`, configuring each `#[rpc]` method on the object's base.
+///
+/// Returns `None` if there are no RPC methods.
+pub fn make_rpc_registrations(class_name: &Ident, funcs: &[FuncDefinition]) -> Option<TokenStream> {
+    let registrations: Vec<TokenStream> = funcs
+        .iter()
+        .filter_map(|func| {
+            let rpc_info = func.rpc_info.as_ref()?;
+            let method_name = func.godot_name();
+            let config = rpc_info.make_config_expr();
+            let cfg_attrs = crate::util::extract_cfg_attrs(&func.external_attributes)
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            Some(quote! {
+                #(#cfg_attrs)*
+                {
+                    let config = #config;
+                    config.configure_node(&mut node, #method_name);
+                }
+            })
+        })
+        .collect();
+
+    if registrations.is_empty() {
+        return None;
+    }
+
+    Some(quote! {
+        fn __register_rpcs(base: &mut dyn ::std::any::Any) {
+            let mut node = base
+                .downcast_ref::<::godot::obj::Gd<<#class_name as ::godot::obj::GodotClass>::Base>>()
+                .expect("bad type erasure when registering RPCs")
+                .clone()
+                .upcast::<::godot::engine::Node>();
+
+            #( #registrations )*
+        }
+    })
+}
+
+/// Generates `rpc_{method}()` and `rpc_id_{method}()` helpers, which invoke each `#[rpc]` method remotely.
+pub fn make_rpc_helpers(class_name: &Ident, funcs: &[FuncDefinition]) -> TokenStream {
+    let helpers = funcs.iter().filter(|func| func.rpc_info.is_some()).map(|func| {
+        let signature = &func.signature;
+        let method_name = func.godot_name();
+        let rpc_fn = format_ident!("rpc_{}", signature.name);
+        let rpc_id_fn = format_ident!("rpc_id_{}", signature.name);
+
+        let (param_names, param_types): (Vec<_>, Vec<_>) = signature
+            .params
+            .inner
+            .iter()
+            .filter_map(|(param, _punct)| match param {
+                venial::FnParam::Typed(param) => Some((&param.name, &param.ty)),
+                venial::FnParam::Receiver(_) => None,
+            })
+            .unzip();
+
+        let cfg_attrs = crate::util::extract_cfg_attrs(&func.external_attributes)
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let rpc_doc = format!("Calls `{method_name}()` remotely on all peers, as configured by its `#[rpc]` attribute.");
+        let rpc_id_doc = format!("Calls `{method_name}()` remotely on the peer `peer_id`, as configured by its `#[rpc]` attribute.");
+
+        quote! {
+            #(#cfg_attrs)*
+            #[doc = #rpc_doc]
+            fn #rpc_fn(&mut self, #( #param_names: #param_types ),*) -> ::godot::engine::global::Error {
+                use ::godot::builtin::meta::ToGodot as _;
+                use ::godot::obj::WithBaseField as _;
+
+                let args: &[::godot::builtin::Variant] = &[ #( #param_names.to_variant() ),* ];
+                self.base_mut().rpc(::godot::builtin::StringName::from(#method_name), args)
+            }
+
+            #(#cfg_attrs)*
+            #[doc = #rpc_id_doc]
+            fn #rpc_id_fn(&mut self, peer_id: i64, #( #param_names: #param_types ),*) -> ::godot::engine::global::Error {
+                use ::godot::builtin::meta::ToGodot as _;
+                use ::godot::obj::WithBaseField as _;
+
+                let args: &[::godot::builtin::Variant] = &[ #( #param_names.to_variant() ),* ];
+                self.base_mut().rpc_id(peer_id, ::godot::builtin::StringName::from(#method_name), args)
+            }
+        }
+    });
+
+    quote! {
+        #[allow(dead_code)]
+        impl #class_name {
+            #( #helpers )*
+        }
+    }
+}
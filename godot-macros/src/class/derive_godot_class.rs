@@ -9,11 +9,11 @@ use proc_macro2::{Ident, Punct, TokenStream};
 use quote::{format_ident, quote};
 
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldVar, Fields,
-    SignatureInfo,
+    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldExport, FieldGroup,
+    FieldGroupKind, FieldVar, Fields, SignatureInfo,
 };
 use crate::util::{bail, ident, path_ends_with_complex, require_api_version, KvParser};
-use crate::{util, ParseResult};
+use crate::{docs, util, ParseResult};
 
 pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let class = item
@@ -32,6 +32,8 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let class_name_obj = util::class_name_obj(class_name);
 
     let is_editor_plugin = struct_cfg.is_editor_plugin;
+    let is_singleton = struct_cfg.is_singleton;
+    let is_inspector_plugin = struct_cfg.is_inspector_plugin;
     let is_hidden = struct_cfg.is_hidden;
     let base_ty = &struct_cfg.base_ty;
     let base_class = quote! { ::godot::engine::#base_ty };
@@ -40,6 +42,9 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
 
     let prv = quote! { ::godot::private };
     let godot_exports_impl = make_property_impl(class_name, &fields);
+    let property_editors_plugin = make_property_editors_plugin(class_name, &fields.all_fields);
+    let docs_registration =
+        docs::make_definition_docs(class_name, base_ty, &class.attributes, &fields.all_fields);
 
     let godot_withbase_impl = if let Some(Field { name, .. }) = &fields.base_field {
         quote! {
@@ -63,8 +68,12 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         TokenStream::new()
     };
 
-    let (user_class_impl, has_default_virtual) =
-        make_user_class_impl(class_name, struct_cfg.is_tool, &fields.all_fields);
+    let (user_class_impl, has_default_virtual) = make_user_class_impl(
+        class_name,
+        struct_cfg.is_tool,
+        struct_cfg.is_runtime,
+        &fields.all_fields,
+    );
 
     let mut init_expecter = TokenStream::new();
     let mut godot_init_impl = TokenStream::new();
@@ -104,6 +113,30 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     };
 
     let is_tool = struct_cfg.is_tool;
+    let is_runtime = struct_cfg.is_runtime;
+
+    let singleton_impl = if is_singleton {
+        quote! {
+            // The engine only stores a raw pointer to singletons, so a ref-counted instance would be destroyed right away.
+            const _: () = {
+                fn __singleton_class_must_not_be_ref_counted<T: ::godot::obj::Bounds<Memory = ::godot::obj::bounds::MemManual>>() {}
+
+                let _ = __singleton_class_must_not_be_ref_counted::<#class_name>;
+            };
+
+            impl #class_name {
+                /// Returns the engine singleton of this class, registered through `#[class(singleton)]`.
+                ///
+                /// # Panics
+                /// If the singleton has not been registered yet, e.g. because the class's init level is not yet loaded.
+                pub fn singleton() -> ::godot::obj::Gd<Self> {
+                    #prv::user_singleton::<Self>()
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
 
     Ok(quote! {
         impl ::godot::obj::GodotClass for #class_name {
@@ -112,6 +145,16 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             fn class_name() -> ::godot::builtin::meta::ClassName {
                 ::godot::builtin::meta::ClassName::from_ascii_cstr(#class_name_cstr)
             }
+
+            fn __rpc_register_fn() -> Option<#prv::ErasedRegisterFn> {
+                // Classes are registered before their first instance is created, so the lookup result is final.
+                static REGISTER_FN: ::std::sync::OnceLock<Option<#prv::ErasedRegisterFn>> =
+                    ::std::sync::OnceLock::new();
+
+                *REGISTER_FN.get_or_init(|| {
+                    #prv::find_rpc_register_fn(<Self as ::godot::obj::GodotClass>::class_name())
+                })
+            }
         }
 
         unsafe impl ::godot::obj::Bounds for #class_name {
@@ -125,6 +168,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #godot_exports_impl
         #user_class_impl
         #init_expecter
+        #singleton_impl
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_obj,
@@ -138,7 +182,10 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 free_fn: #prv::callbacks::free::<#class_name>,
                 default_get_virtual_fn: #default_get_virtual_fn,
                 is_tool: #is_tool,
+                is_runtime: #is_runtime,
                 is_editor_plugin: #is_editor_plugin,
+                is_singleton: #is_singleton,
+                is_inspector_plugin: #is_inspector_plugin,
                 is_hidden: #is_hidden,
                 is_instantiable: #is_instantiable,
             },
@@ -159,6 +206,8 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             }
         });
 
+        #docs_registration
+        #property_editors_plugin
         #prv::class_macros::#inherits_macro!(#class_name);
         #deprecated_base_warning
     })
@@ -187,11 +236,73 @@ struct ClassAttributes {
     base_ty: Ident,
     init_strategy: InitStrategy,
     is_tool: bool,
+    is_runtime: bool,
     is_editor_plugin: bool,
+    is_singleton: bool,
+    is_inspector_plugin: bool,
     is_hidden: bool,
     rename: Option<Ident>,
 }
 
+/// For fields with `#[export(editor = T)]`, generates a hidden inspector plugin which edits these properties with the given
+/// `EditorProperty` classes. It is added to the editor and removed again like any `#[class(inspector_plugin)]`.
+fn make_property_editors_plugin(class_name: &Ident, all_fields: &[Field]) -> TokenStream {
+    let (property_names, editors): (Vec<String>, Vec<&TokenStream>) = all_fields
+        .iter()
+        .filter_map(|field| Some((field.property_name(), field.editor.as_ref()?)))
+        .unzip();
+
+    if editors.is_empty() {
+        return TokenStream::new();
+    }
+
+    let plugin_name = format_ident!("__{}PropertyEditors", class_name);
+    let hidden = if cfg!(since_api = "4.2") {
+        quote! { hidden, }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        #[derive(::godot::register::GodotClass)]
+        #[class(tool, init, inspector_plugin, #hidden base = EditorInspectorPlugin)]
+        #[doc(hidden)]
+        struct #plugin_name {
+            base: ::godot::obj::Base<::godot::engine::EditorInspectorPlugin>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::engine::IEditorInspectorPlugin for #plugin_name {
+            fn can_handle(&self, object: ::godot::obj::Gd<::godot::engine::Object>) -> bool {
+                object.is_class(<#class_name as ::godot::obj::GodotClass>::class_name().to_gstring())
+            }
+
+            fn parse_property(
+                &mut self,
+                _object: ::godot::obj::Gd<::godot::engine::Object>,
+                _type: ::godot::builtin::VariantType,
+                name: ::godot::builtin::GString,
+                _hint_type: ::godot::engine::global::PropertyHint,
+                _hint_string: ::godot::builtin::GString,
+                _usage_flags: ::godot::engine::global::PropertyUsageFlags,
+                _wide: bool,
+            ) -> bool {
+                use ::godot::obj::WithBaseField as _;
+
+                let editor: ::godot::obj::Gd<::godot::engine::EditorProperty> = match name.to_string().as_str() {
+                    #(
+                        #property_names => ::godot::obj::Gd::upcast(<#editors as ::godot::obj::NewAlloc>::new_alloc()),
+                    )*
+                    _ => return false,
+                };
+
+                self.base_mut().add_property_editor(name, editor.upcast());
+                true
+            }
+        }
+    }
+}
+
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     let base_init = if let Some(Field { name, .. }) = fields.base_field {
         quote! { #name: base, }
@@ -223,6 +334,7 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
 fn make_user_class_impl(
     class_name: &Ident,
     is_tool: bool,
+    is_runtime: bool,
     all_fields: &[Field],
 ) -> (TokenStream, bool) {
     let onready_field_inits = all_fields
@@ -262,6 +374,7 @@ fn make_user_class_impl(
             fn __config() -> ::godot::private::ClassConfig {
                 ::godot::private::ClassConfig {
                     is_tool: #is_tool,
+                    is_runtime: #is_runtime,
                 }
             }
 
@@ -281,7 +394,11 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut base_ty = ident("RefCounted");
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
+    let mut is_runtime = false;
     let mut is_editor_plugin = false;
+    let mut is_singleton = false;
+    let mut is_inspector_plugin = false;
+    let mut is_abstract = false;
     let mut is_hidden = false;
     let mut rename: Option<Ident> = None;
 
@@ -302,11 +419,41 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             None => {}
         }
 
+        // #[class(abstract)]
+        if let Some(attr_key) = parser.handle_alone_with_span("abstract")? {
+            match init_strategy {
+                InitStrategy::Generated => {
+                    return bail!(
+                        attr_key,
+                        "#[class(abstract)] cannot be combined with `init`, as abstract classes cannot be instantiated"
+                    );
+                }
+                // Abstract classes have no constructor, so `no_init` is implied.
+                InitStrategy::UserDefined | InitStrategy::Absent => {
+                    init_strategy = InitStrategy::Absent;
+                }
+            }
+
+            is_abstract = true;
+        }
+
         // #[class(tool)]
         if parser.handle_alone("tool")? {
             is_tool = true;
         }
 
+        // #[class(runtime)]
+        if let Some(attr_key) = parser.handle_alone_with_span("runtime")? {
+            if is_tool {
+                return bail!(
+                    attr_key,
+                    "#[class(runtime)] and #[class(tool)] are mutually exclusive"
+                );
+            }
+
+            is_runtime = true;
+        }
+
         // #[class(editor_plugin)]
         if let Some(attr_key) = parser.handle_alone_with_span("editor_plugin")? {
             require_api_version!("4.1", &attr_key, "#[class(editor_plugin)]")?;
@@ -328,6 +475,50 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             }
         }
 
+        // #[class(singleton)]
+        if let Some(attr_key) = parser.handle_alone_with_span("singleton")? {
+            // Requires a constructor, since the library instantiates the class itself.
+            if is_abstract {
+                return bail!(
+                    attr_key,
+                    "#[class(singleton)] cannot be combined with `abstract`"
+                );
+            }
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    attr_key,
+                    "#[class(singleton)] cannot be combined with `no_init`"
+                );
+            }
+
+            is_singleton = true;
+        }
+
+        // #[class(inspector_plugin)]
+        if let Some(attr_key) = parser.handle_alone_with_span("inspector_plugin")? {
+            // Requires #[class(tool, base=EditorInspectorPlugin)], and a constructor since the library instantiates the plugin itself.
+            if !is_tool {
+                return bail!(
+                    attr_key,
+                    "#[class(inspector_plugin)] requires additional key `tool`"
+                );
+            }
+            if base_ty != ident("EditorInspectorPlugin") {
+                return bail!(
+                    attr_key,
+                    "#[class(inspector_plugin)] requires additional key-value `base=EditorInspectorPlugin`"
+                );
+            }
+            if is_abstract || matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    attr_key,
+                    "#[class(inspector_plugin)] cannot be combined with `abstract` or `no_init`"
+                );
+            }
+
+            is_inspector_plugin = true;
+        }
+
         // #[class(hidden)]
         // TODO consider naming this "internal"; godot-cpp uses that terminology:
         // https://github.com/godotengine/godot-cpp/blob/master/include/godot_cpp/core/class_db.hpp#L327
@@ -343,7 +534,10 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         base_ty,
         init_strategy,
         is_tool,
+        is_runtime,
         is_editor_plugin,
+        is_singleton,
+        is_inspector_plugin,
         is_hidden,
         rename,
     })
@@ -405,7 +599,20 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
 
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            // #[export(editor = T)] can be combined with any hint.
+            field.editor = parser.handle_expr("editor")?;
             let export = FieldExport::new_from_kv(&mut parser)?;
+
+            // Godot only interprets the node-type hint for `NodePath` properties.
+            if matches!(export, FieldExport::NodePath { .. })
+                && !path_ends_with_complex(&field.ty, "NodePath")
+            {
+                return bail!(
+                    &field.ty,
+                    "#[export(node_path)] can only be used on fields of type `NodePath`"
+                );
+            }
+
             field.export = Some(export);
             parser.finish()?;
         }
@@ -417,6 +624,17 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
             parser.finish()?;
         }
 
+        // #[export_category], #[export_group], #[export_subgroup]
+        for kind in [
+            FieldGroupKind::Category,
+            FieldGroupKind::Group,
+            FieldGroupKind::Subgroup,
+        ] {
+            if let Some(group) = parse_field_group(&named_field, kind)? {
+                field.groups.push(group);
+            }
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -429,6 +647,14 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
             parser.finish()?;
         }
 
+        // Groups are only meaningful in front of a registered property.
+        if !field.groups.is_empty() && field.var.is_none() && field.export.is_none() {
+            return bail!(
+                named_field,
+                "attributes #[export_category], #[export_group] and #[export_subgroup] require #[var] or #[export] on the same field"
+            );
+        }
+
         // Extra validation; eventually assign to base_fields or all_fields.
         if is_base {
             if field.is_onready
@@ -463,6 +689,28 @@ fn parse_fields(class: &venial::Struct, init_strategy: InitStrategy) -> ParseRes
     })
 }
 
+/// Parses `#[export_category(name = ...)]`, `#[export_group(name = ..., prefix = ...)]` or `#[export_subgroup(name = ..., prefix = ...)]`.
+fn parse_field_group(
+    named_field: &venial::NamedField,
+    kind: FieldGroupKind,
+) -> ParseResult<Option<FieldGroup>> {
+    let Some(mut parser) = KvParser::parse(&named_field.attributes, kind.attribute_name())? else {
+        return Ok(None);
+    };
+
+    let name = parser.handle_expr_required("name")?;
+
+    // Categories have no prefix; Godot lists all following properties in them until the next category.
+    let prefix = match kind {
+        FieldGroupKind::Category => None,
+        FieldGroupKind::Group | FieldGroupKind::Subgroup => parser.handle_expr("prefix")?,
+    };
+
+    parser.finish()?;
+
+    Ok(Some(FieldGroup { kind, name, prefix }))
+}
+
 fn handle_opposite_keys(
     parser: &mut KvParser,
     key: &str,
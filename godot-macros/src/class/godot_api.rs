@@ -5,16 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Delimiter, Group, Ident, TokenStream};
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
 use quote::spanned::Spanned;
 use quote::{format_ident, quote};
 
 use crate::class::{
-    into_signature_info, make_method_registration, make_virtual_callback, BeforeKind,
-    FuncDefinition, SignatureInfo,
+    into_signature_info, make_default_args_method, make_method_registration, make_rpc_helpers,
+    make_rpc_registrations, make_virtual_callback, map_self_to_class_name, BeforeKind,
+    FuncDefinition, RpcAttr, SignatureInfo,
 };
 use crate::util::{bail, require_api_version, KvParser};
-use crate::{util, ParseResult};
+use crate::{docs, util, ParseResult};
 
 pub fn attribute_godot_api(input_decl: venial::Item) -> ParseResult<TokenStream> {
     let decl = match input_decl {
@@ -73,12 +74,12 @@ fn bail_attr<R>(attr_name: Ident, msg: &str, method: &venial::Function) -> Parse
 }
 
 /// Holds information known from a signal's definition
-struct SignalDefinition {
+pub struct SignalDefinition {
     /// The signal's function signature.
-    signature: venial::Function,
+    pub signature: venial::Function,
 
     /// The signal's non-gdext attributes (all except #[signal]).
-    external_attributes: Vec<venial::Attribute>,
+    pub external_attributes: Vec<venial::Attribute>,
 }
 
 /// Codegen for `#[godot_api] impl MyType`
@@ -89,19 +90,49 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
 
     let (funcs, signals, out_virtual_impl) = process_godot_fns(&class_name, &mut original_impl)?;
 
+    let consts = process_godot_constants(&mut original_impl)?;
+    let docs_registration = docs::make_inherent_impl_docs(&class_name, &funcs, &signals, &consts);
+
+    let rpc_helpers = make_rpc_helpers(&class_name, &funcs);
+    let rpc_registrations = make_rpc_registrations(&class_name, &funcs);
+    let register_rpcs_fn = if rpc_registrations.is_some() {
+        quote! {
+            Some(#prv::ErasedRegisterFn {
+                raw: #prv::callbacks::register_user_rpcs::<#class_name>,
+            })
+        }
+    } else {
+        quote! { None }
+    };
+
+    let signal_accessors = make_signal_accessors(&class_name, &signals);
     let signal_registrations = make_signal_registrations(signals, &class_name_obj);
 
+    let default_args_methods: Vec<TokenStream> =
+        funcs.iter().filter_map(make_default_args_method).collect();
+    let default_args_impl = if default_args_methods.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            impl #class_name {
+                #( #default_args_methods )*
+            }
+        }
+    };
+
     let method_registrations: Vec<TokenStream> = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def))
         .collect::<ParseResult<Vec<TokenStream>>>()?; // <- FIXME transpose this
 
-    let constant_registration =
-        make_constant_registration(&mut original_impl, &class_name, &class_name_obj)?;
+    let constant_registration = make_constant_registration(&consts, &class_name, &class_name_obj)?;
 
     let result = quote! {
         #original_impl
         #out_virtual_impl
+        #signal_accessors
+        #rpc_helpers
+        #default_args_impl
 
         impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
             fn __register_methods() {
@@ -112,6 +143,8 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
             fn __register_constants() {
                 #constant_registration
             }
+
+            #rpc_registrations
         }
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -120,14 +153,81 @@ fn transform_inherent_impl(mut original_impl: venial::Impl) -> ParseResult<Token
                 register_methods_constants_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_methods_constants::<#class_name>,
                 },
+                register_rpcs_fn: #register_rpcs_fn,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
         });
+
+        #docs_registration
     };
 
     Ok(result)
 }
 
+/// Generates the `{Class}Signals` struct and a `signals()` method, providing typed access to each declared signal.
+fn make_signal_accessors(class_name: &Ident, signals: &[SignalDefinition]) -> TokenStream {
+    if signals.is_empty() {
+        return TokenStream::new();
+    }
+
+    let signals_struct = format_ident!("{}Signals", class_name);
+    let mut accessors = Vec::new();
+
+    for signal in signals.iter() {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+        } = signal;
+
+        let param_types = signature
+            .params
+            .inner
+            .iter()
+            .filter_map(|(param, _punct)| match param {
+                venial::FnParam::Typed(param) => Some(map_self_to_class_name::<_, TokenStream>(
+                    param.ty.tokens.clone(),
+                    class_name,
+                )),
+                venial::FnParam::Receiver(_) => None,
+            });
+
+        let signal_cfg_attrs: Vec<&venial::Attribute> =
+            util::extract_cfg_attrs(external_attributes)
+                .into_iter()
+                .collect();
+        let signal_name = &signature.name;
+        let signal_name_str = signal_name.to_string();
+
+        accessors.push(quote! {
+            #(#signal_cfg_attrs)*
+            fn #signal_name(self) -> ::godot::obj::TypedSignal<'c, #class_name, ( #(#param_types,)* )> {
+                ::godot::obj::TypedSignal::new(self.__owner, #signal_name_str)
+            }
+        });
+    }
+
+    quote! {
+        /// Typed access to the signals declared in the class' `#[godot_api]` block. Returned by `signals()`.
+        #[allow(dead_code)]
+        struct #signals_struct<'c> {
+            __owner: &'c mut #class_name,
+        }
+
+        #[allow(dead_code)]
+        impl<'c> #signals_struct<'c> {
+            #( #accessors )*
+        }
+
+        impl #class_name {
+            /// Returns an object to access the class' signals in a type-safe way.
+            #[allow(dead_code)]
+            fn signals(&mut self) -> #signals_struct<'_> {
+                #signals_struct { __owner: self }
+            }
+        }
+    }
+}
+
 fn make_signal_registrations(
     signals: Vec<SignalDefinition>,
     class_name_obj: &TokenStream,
@@ -201,11 +301,10 @@ fn make_signal_registrations(
 }
 
 fn make_constant_registration(
-    original_impl: &mut venial::Impl,
+    consts: &[venial::Constant],
     class_name: &Ident,
     class_name_obj: &TokenStream,
 ) -> ParseResult<TokenStream> {
-    let consts = process_godot_constants(original_impl)?;
     let mut integer_constant_cfg_attrs = Vec::new();
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
@@ -270,7 +369,19 @@ fn process_godot_fns(
             continue;
         };
 
+        let rpc_info = extract_rpc_attribute(function)?;
+        let default_parameters = extract_default_parameters(function)?;
+
         let Some(attr) = extract_attributes(&function, &function.attributes)? else {
+            if rpc_info.is_some() {
+                return bail!(&function.name, "#[rpc] must be used together with #[func]");
+            }
+            if let Some(first_default) = default_parameters.first() {
+                return bail!(
+                    first_default,
+                    "#[opt] is only supported on #[func] parameters"
+                );
+            }
             continue;
         };
 
@@ -297,6 +408,21 @@ fn process_godot_fns(
                 is_virtual,
                 has_gd_self,
             } => {
+                if rpc_info.is_some() && !has_gd_self && !has_self_receiver(function) {
+                    return bail!(
+                        &function.name,
+                        "#[rpc] requires a method with `self` receiver, or `#[func(gd_self)]`"
+                    );
+                }
+
+                if has_gd_self && default_parameters.len() >= count_typed_params(function) {
+                    return bail_attr(
+                        attr.attr_name,
+                        "with attribute key `gd_self`, the Gd<Self> parameter cannot have a default value",
+                        function,
+                    );
+                }
+
                 let external_attributes = function.attributes.clone();
 
                 // Signatures are the same thing without body.
@@ -325,6 +451,30 @@ fn process_godot_fns(
                     None
                 };
 
+                // A trailing `&[Variant]` parameter takes the remaining arguments; it is not part of the fixed signature.
+                let is_varargs = signature
+                    .params
+                    .inner
+                    .last()
+                    .map_or(false, |(param, _punct)| is_varargs_param(param));
+
+                if is_varargs {
+                    if is_virtual {
+                        return attr.bail("#[func(virtual)] cannot be variadic", function);
+                    }
+                    if rpc_info.is_some() {
+                        return attr.bail("#[rpc] cannot be used on variadic methods", function);
+                    }
+                    if !default_parameters.is_empty() {
+                        return attr.bail(
+                            "#[opt(default = ...)] cannot be combined with variadic `&[Variant]` parameter",
+                            function,
+                        );
+                    }
+
+                    signature.params.inner.pop();
+                }
+
                 // For virtual methods, rename/mangle existing user method and create a new method with the original name,
                 // which performs a dynamic dispatch.
                 if is_virtual {
@@ -344,9 +494,20 @@ fn process_godot_fns(
                     rename,
                     is_virtual,
                     has_gd_self,
+                    rpc_info,
+                    default_parameters,
+                    is_varargs,
                 });
             }
             ItemAttrType::Signal(ref _attr_val) => {
+                if rpc_info.is_some() {
+                    return attr.bail("#[rpc] is only supported on #[func] methods", function);
+                }
+
+                if !default_parameters.is_empty() {
+                    return attr.bail("#[opt] is only supported on #[func] parameters", function);
+                }
+
                 if function.return_ty.is_some() {
                     return attr.bail("return types are not supported", function);
                 }
@@ -491,6 +652,113 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<venial::C
     Ok(constant_signatures)
 }
 
+/// Parses and removes the `#[rpc]` attribute of a function, if present.
+fn extract_rpc_attribute(function: &mut venial::Function) -> ParseResult<Option<RpcAttr>> {
+    let Some(mut parser) = KvParser::parse(&function.attributes, "rpc")? else {
+        return Ok(None);
+    };
+
+    let rpc_info = RpcAttr::new_from_kv(&mut parser)?;
+    parser.finish()?;
+
+    function.attributes.retain(|attr| {
+        attr.get_single_path_segment()
+            .map_or(true, |name| name != "rpc")
+    });
+
+    Ok(Some(rpc_info))
+}
+
+/// Parses and removes the `#[opt(default = ...)]` attributes of a function's parameters.
+///
+/// Returns the default value expressions of the trailing parameters, in declaration order.
+fn extract_default_parameters(function: &mut venial::Function) -> ParseResult<Vec<TokenStream>> {
+    let mut default_parameters = vec![];
+
+    for (param, _punct) in function.params.inner.iter_mut() {
+        let venial::FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let default = match KvParser::parse(&param.attributes, "opt")? {
+            Some(mut parser) => {
+                let default = parser.handle_expr_required("default")?;
+                parser.finish()?;
+                Some(default)
+            }
+            None => None,
+        };
+
+        param.attributes.retain(|attr| {
+            attr.get_single_path_segment()
+                .map_or(true, |name| name != "opt")
+        });
+
+        match default {
+            Some(default) => default_parameters.push(default),
+            None if !default_parameters.is_empty() => {
+                return bail!(
+                    &param.name,
+                    "parameters without default value cannot follow parameters with #[opt(default = ...)]"
+                );
+            }
+            None => {}
+        }
+    }
+
+    Ok(default_parameters)
+}
+
+/// Whether a parameter has type `&[Variant]` (optionally with lifetime or qualified path), which makes a `#[func]` variadic.
+fn is_varargs_param(param: &venial::FnParam) -> bool {
+    let venial::FnParam::Typed(param) = param else {
+        return false;
+    };
+
+    let mut tokens = param.ty.tokens.iter();
+    if !matches!(tokens.next(), Some(TokenTree::Punct(p)) if p.as_char() == '&') {
+        return false;
+    }
+
+    // Skip lifetime `'a`, which consists of a `'` punct and an identifier.
+    let mut next = tokens.next();
+    if matches!(next, Some(TokenTree::Punct(p)) if p.as_char() == '\'') {
+        tokens.next();
+        next = tokens.next();
+    }
+
+    let Some(TokenTree::Group(slice)) = next else {
+        return false;
+    };
+    if slice.delimiter() != Delimiter::Bracket || tokens.next().is_some() {
+        return false;
+    }
+
+    let element_ty = venial::TypeExpr {
+        tokens: slice.stream().into_iter().collect(),
+    };
+    util::path_ends_with_complex(&element_ty, "Variant")
+}
+
+fn count_typed_params(function: &venial::Function) -> usize {
+    function
+        .params
+        .inner
+        .iter()
+        .filter(|(param, _punct)| matches!(param, venial::FnParam::Typed(_)))
+        .count()
+}
+
+fn has_self_receiver(function: &venial::Function) -> bool {
+    function
+        .params
+        .inner
+        .first()
+        .map_or(false, |(param, _punct)| {
+            matches!(param, venial::FnParam::Receiver(_))
+        })
+}
+
 fn extract_attributes<T>(
     error_scope: T,
     attributes: &[venial::Attribute],
@@ -611,6 +879,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let mut on_notification_impl = TokenStream::new();
     let mut get_property_impl = TokenStream::new();
     let mut set_property_impl = TokenStream::new();
+    let mut get_property_list_impl = TokenStream::new();
+    let mut property_get_revert_impl = TokenStream::new();
+    let mut validate_property_impl = TokenStream::new();
 
     let mut register_fn = None;
     let mut create_fn = None;
@@ -619,6 +890,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let mut on_notification_fn = None;
     let mut get_property_fn = None;
     let mut set_property_fn = None;
+    let mut get_property_list_fn = None;
+    let mut property_revert_fn = None;
+    let mut validate_property_fn = None;
 
     let mut virtual_methods = vec![];
     let mut virtual_method_cfg_attrs = vec![];
@@ -731,7 +1005,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                             use ::godot::obj::UserClass as _;
 
                             #[cfg(before_api = "4.3")]
-                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                            if ::godot::private::is_class_inactive(&Self::__config()) {
                                 return;
                             }
 
@@ -755,7 +1029,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                             use ::godot::obj::UserClass as _;
 
                             #[cfg(before_api = "4.3")]
-                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                            if ::godot::private::is_class_inactive(&Self::__config()) {
                                 return None;
                             }
 
@@ -778,7 +1052,7 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                             use ::godot::obj::UserClass as _;
 
                             #[cfg(before_api = "4.3")]
-                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                            if ::godot::private::is_class_inactive(&Self::__config()) {
                                 return false;
                             }
 
@@ -793,6 +1067,81 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                 });
             }
 
+            "get_property_list" => {
+                get_property_list_impl = quote! {
+                    #(#cfg_attrs)*
+                    impl ::godot::obj::cap::GodotGetPropertyList for #class_name {
+                        fn __godot_get_property_list(&mut self) -> Vec<::godot::builtin::meta::PropertyInfo> {
+                            use ::godot::obj::UserClass as _;
+
+                            #[cfg(before_api = "4.3")]
+                            if ::godot::private::is_class_inactive(&Self::__config()) {
+                                return Vec::new();
+                            }
+
+                            <Self as #trait_path>::get_property_list(self)
+                        }
+                    }
+                };
+
+                get_property_list_fn = Some(quote! {
+                    #(#cfg_attrs)*
+                    () => Some((
+                        #prv::callbacks::get_property_list::<#class_name>,
+                        #prv::callbacks::free_property_list::<#class_name>,
+                    )),
+                });
+            }
+
+            "property_get_revert" => {
+                property_get_revert_impl = quote! {
+                    #(#cfg_attrs)*
+                    impl ::godot::obj::cap::GodotPropertyGetRevert for #class_name {
+                        fn __godot_property_get_revert(&self, property: ::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                            use ::godot::obj::UserClass as _;
+
+                            #[cfg(before_api = "4.3")]
+                            if ::godot::private::is_class_inactive(&Self::__config()) {
+                                return None;
+                            }
+
+                            <Self as #trait_path>::property_get_revert(self, property)
+                        }
+                    }
+                };
+
+                property_revert_fn = Some(quote! {
+                    #(#cfg_attrs)*
+                    () => Some((
+                        #prv::callbacks::property_can_revert::<#class_name>,
+                        #prv::callbacks::property_get_revert::<#class_name>,
+                    )),
+                });
+            }
+
+            "validate_property" => {
+                validate_property_impl = quote! {
+                    #(#cfg_attrs)*
+                    impl ::godot::obj::cap::GodotValidateProperty for #class_name {
+                        fn __godot_validate_property(&self, property: &mut ::godot::builtin::meta::PropertyInfo) {
+                            use ::godot::obj::UserClass as _;
+
+                            #[cfg(before_api = "4.3")]
+                            if ::godot::private::is_class_inactive(&Self::__config()) {
+                                return;
+                            }
+
+                            <Self as #trait_path>::validate_property(self, property)
+                        }
+                    }
+                };
+
+                validate_property_fn = Some(quote! {
+                    #(#cfg_attrs)*
+                    () => Some(#prv::callbacks::validate_property::<#class_name>),
+                });
+            }
+
             // Other virtual methods, like ready, process etc.
             _ => {
                 let method = util::reduce_to_signature(method);
@@ -863,6 +1212,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
     let on_notification_fn = convert_to_match_expression_or_none(on_notification_fn);
     let get_property_fn = convert_to_match_expression_or_none(get_property_fn);
     let set_property_fn = convert_to_match_expression_or_none(set_property_fn);
+    let get_property_list_fn = convert_to_match_expression_or_none(get_property_list_fn);
+    let property_revert_fn = convert_to_match_expression_or_none(property_revert_fn);
+    let validate_property_fn = convert_to_match_expression_or_none(validate_property_fn);
 
     let result = quote! {
         #original_impl
@@ -872,6 +1224,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
         #register_class_impl
         #get_property_impl
         #set_property_impl
+        #get_property_list_impl
+        #property_get_revert_impl
+        #validate_property_impl
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
 
@@ -901,6 +1256,9 @@ fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStream>
                 user_on_notification_fn: #on_notification_fn,
                 user_set_fn: #set_property_fn,
                 user_get_fn: #get_property_fn,
+                user_get_property_list_fn: #get_property_list_fn,
+                user_property_revert_fn: #property_revert_fn,
+                user_validate_property_fn: #validate_property_fn,
                 get_virtual_fn: #prv::callbacks::get_virtual::<#class_name>,
             },
             init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
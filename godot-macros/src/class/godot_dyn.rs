@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::util::{bail, validate_self};
+use crate::ParseResult;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn attribute_godot_dyn(input_decl: venial::Item) -> ParseResult<TokenStream> {
+    let decl = match input_decl {
+        venial::Item::Impl(decl) => decl,
+        _ => bail!(
+            input_decl,
+            "#[godot_dyn] can only be applied on impl blocks",
+        )?,
+    };
+
+    if decl.impl_generic_params.is_some() {
+        bail!(
+            &decl,
+            "#[godot_dyn] currently does not support generic parameters",
+        )?;
+    }
+
+    let Some(trait_path) = decl.trait_ty.as_ref() else {
+        return bail!(
+            &decl,
+            "#[godot_dyn] requires a trait impl, e.g. `impl Trait for MyClass`",
+        );
+    };
+
+    let class_name = validate_self(&decl, "godot_dyn")?;
+    let prv = quote! { ::godot::private };
+
+    let new_code = quote! {
+        #decl
+
+        impl ::godot::obj::AsDyn<dyn #trait_path> for #class_name {
+            fn dyn_upcast(&self) -> &(dyn #trait_path + 'static) {
+                self
+            }
+
+            fn dyn_upcast_mut(&mut self) -> &mut (dyn #trait_path + 'static) {
+                self
+            }
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: <#class_name as ::godot::obj::GodotClass>::class_name(),
+            item: #prv::PluginItem::DynTraitImpl {
+                dyn_trait_typeid: ::std::any::TypeId::of::<dyn #trait_path>(),
+                erased_dynify_fn: #prv::ErasedDynifyFn {
+                    raw: #prv::erased_dynify::<#class_name, dyn #trait_path>,
+                },
+            },
+            init_level: <#class_name as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    };
+
+    Ok(new_code)
+}
@@ -7,12 +7,14 @@
 
 mod derive_godot_class;
 mod godot_api;
+mod godot_dyn;
 mod data_models {
     pub mod field;
     pub mod field_export;
     pub mod field_var;
     pub mod func;
     pub mod property;
+    pub mod rpc;
 }
 
 pub(crate) use data_models::field::*;
@@ -20,5 +22,7 @@ pub(crate) use data_models::field_export::*;
 pub(crate) use data_models::field_var::*;
 pub(crate) use data_models::func::*;
 pub(crate) use data_models::property::*;
+pub(crate) use data_models::rpc::*;
 pub(crate) use derive_godot_class::*;
 pub(crate) use godot_api::*;
+pub(crate) use godot_dyn::*;
@@ -7,9 +7,11 @@
 
 use proc_macro2::{Ident, Literal, Span, TokenTree};
 
-use crate::util::{bail, error};
+use crate::util::{bail, error, KvParser};
 use crate::ParseResult;
 
+use super::dictionary::parse_string_literal;
+
 /// Stores info from c-style enums for use in deriving `GodotConvert` and other related traits.
 #[derive(Clone, Debug)]
 pub struct CStyleEnum {
@@ -17,6 +19,10 @@ pub struct CStyleEnum {
     enumerator_names: Vec<Ident>,
     /// The discriminants of each variant, both explicit and implicit.
     enumerator_ords: Vec<Literal>,
+    /// The names used for string conversion, from `#[godot(rename = "...")]` or the variant name.
+    enumerator_godot_names: Vec<String>,
+    /// The first `#[godot(rename = "...")]` attribute, if any variant has one.
+    first_rename: Option<Literal>,
 }
 
 impl CStyleEnum {
@@ -30,11 +36,19 @@ impl CStyleEnum {
             .map(CStyleEnumerator::parse_enum_variant)
             .collect::<ParseResult<Vec<_>>>()?;
 
+        let first_rename = variants.iter().find_map(|variant| variant.rename.clone());
+        let godot_names = variants
+            .iter()
+            .map(CStyleEnumerator::godot_name)
+            .collect::<ParseResult<Vec<_>>>()?;
+
         let (names, discriminants) = Self::create_discriminant_mapping(variants)?;
 
         Ok(Self {
             enumerator_names: names,
             enumerator_ords: discriminants,
+            enumerator_godot_names: godot_names,
+            first_rename,
         })
     }
 
@@ -52,7 +66,7 @@ impl CStyleEnum {
 
             let discriminant = match enumerator.discriminant_as_i64()? {
                 Some(discriminant) => discriminant,
-                None => last_discriminant.unwrap_or(0) + 1,
+                None => last_discriminant.map_or(0, |last| last + 1),
             };
             last_discriminant = Some(discriminant);
 
@@ -76,6 +90,16 @@ impl CStyleEnum {
         &self.enumerator_ords
     }
 
+    /// Returns the names used for string conversion, in order of the variants.
+    pub fn godot_names(&self) -> &[String] {
+        &self.enumerator_godot_names
+    }
+
+    /// Returns the first `#[godot(rename = "...")]` attribute, to reject it for enums not converted via strings.
+    pub fn first_rename(&self) -> Option<&Literal> {
+        self.first_rename.as_ref()
+    }
+
     /// Return a hint string for use with `PropertyHint::ENUM` where each variant has an explicit integer hint.
     pub fn to_int_hint(&self) -> String {
         self.enumerator_names
@@ -88,11 +112,7 @@ impl CStyleEnum {
 
     /// Return a hint string for use with `PropertyHint::ENUM` where the variants are just kept as strings.
     pub fn to_string_hint(&self) -> String {
-        self.enumerator_names
-            .iter()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(",")
+        self.enumerator_godot_names.join(",")
     }
 }
 
@@ -103,6 +123,8 @@ pub struct CStyleEnumerator {
     name: Ident,
     /// The explicit discriminant of the variant, `None` means there was no explicit discriminant.
     discriminant: Option<TokenTree>,
+    /// The name from `#[godot(rename = "...")]`, if present.
+    rename: Option<Literal>,
 }
 
 impl CStyleEnumerator {
@@ -118,12 +140,38 @@ impl CStyleEnumerator {
             }
         }
 
+        let mut rename = None;
+        if let Some(mut parser) = KvParser::parse(&enum_variant.attributes, "godot")? {
+            rename = parse_string_literal(&mut parser, "rename")?;
+            parser.finish()?;
+        }
+
         Ok(Self {
             name: enum_variant.name.clone(),
             discriminant: enum_variant.value.as_ref().map(|val| &val.value).cloned(),
+            rename,
         })
     }
 
+    /// Returns the name used for string conversion.
+    fn godot_name(&self) -> ParseResult<String> {
+        let Some(rename) = &self.rename else {
+            return Ok(self.name.to_string());
+        };
+
+        // Only plain string literals are supported; escapes would need to be resolved.
+        let literal = rename.to_string();
+        match literal.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            Some(name) if !name.contains(['\\', ',', ':']) && !name.is_empty() => {
+                Ok(name.to_string())
+            }
+            _ => bail!(
+                rename,
+                "`rename` must be a non-empty string without escapes, `,` or `:`"
+            ),
+        }
+    }
+
     /// Returns the discriminant parsed as an i64 literal.
     fn discriminant_as_i64(&self) -> ParseResult<Option<i64>> {
         let Some(discriminant) = self.discriminant.as_ref() else {
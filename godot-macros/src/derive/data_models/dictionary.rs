@@ -0,0 +1,234 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Stores info from a struct with named fields, which is converted to/from a `Dictionary`.
+pub struct DictionaryStruct {
+    pub fields: Vec<DictionaryField>,
+}
+
+impl DictionaryStruct {
+    /// Parses a struct with named fields.
+    pub fn parse_struct(struct_: &venial::Struct) -> ParseResult<Self> {
+        match &struct_.fields {
+            venial::Fields::Named(_) => {
+                let fields = DictionaryField::parse_fields(&struct_.fields)?;
+                DictionaryField::check_unique_keys(&fields)?;
+
+                Ok(Self { fields })
+            }
+            _ => bail!(
+                &struct_.fields,
+                "`#[godot(dictionary)]` on structs requires named fields"
+            ),
+        }
+    }
+}
+
+/// Stores info from an enum whose variants may carry data, which is converted to/from a tagged `Dictionary`.
+pub struct DictionaryEnum {
+    /// The dictionary key under which the variant name is stored.
+    pub tag_key: Literal,
+    pub variants: Vec<DictionaryVariant>,
+}
+
+impl DictionaryEnum {
+    /// Parses an enum, where each variant can be a unit, tuple or struct variant.
+    pub fn parse_enum(enum_: &venial::Enum, tag_key: Option<Literal>) -> ParseResult<Self> {
+        let variants = enum_
+            .variants
+            .items()
+            .map(DictionaryVariant::parse_variant)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        let tag_key = tag_key.unwrap_or_else(|| Literal::string("type"));
+
+        // A field stored under the tag key would be overwritten by the tag (or vice versa).
+        let stored_fields = variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .filter(|field| !field.skip);
+
+        for field in stored_fields {
+            if field.key.to_string() == tag_key.to_string() {
+                return bail!(
+                    &field.member,
+                    "field key {tag_key} clashes with the variant tag; rename the field or choose another `tag`"
+                );
+            }
+        }
+
+        Ok(Self { tag_key, variants })
+    }
+}
+
+/// A variant of a [`DictionaryEnum`].
+pub struct DictionaryVariant {
+    /// The name of the variant.
+    pub name: Ident,
+    /// The value stored under the tag key, from `#[godot(rename = "...")]` or the variant name.
+    pub tag: Literal,
+    /// The fields of the variant; empty for unit variants.
+    pub fields: Vec<DictionaryField>,
+}
+
+impl DictionaryVariant {
+    fn parse_variant(variant: &venial::EnumVariant) -> ParseResult<Self> {
+        if let Some(value) = &variant.value {
+            return bail!(
+                &value.value,
+                "`#[godot(dictionary)]` does not support explicit discriminants"
+            );
+        }
+
+        let mut tag = None;
+        if let Some(mut parser) = KvParser::parse(&variant.attributes, "godot")? {
+            tag = parse_string_literal(&mut parser, "rename")?;
+            parser.finish()?;
+        }
+
+        let fields = DictionaryField::parse_fields(&variant.fields)?;
+        DictionaryField::check_unique_keys(&fields)?;
+
+        Ok(Self {
+            name: variant.name.clone(),
+            tag: tag.unwrap_or_else(|| Literal::string(&variant.name.to_string())),
+            fields,
+        })
+    }
+}
+
+/// A field of a [`DictionaryStruct`] or [`DictionaryVariant`].
+pub struct DictionaryField {
+    /// The member used to access the field: the field name, or the index for tuple fields.
+    ///
+    /// Tuple fields can be accessed with the alternate syntax `TupleStruct { 0: value }`, so this works uniformly for both.
+    pub member: TokenStream,
+    /// The type of the field.
+    pub ty: venial::TypeExpr,
+    /// The dictionary key, from `#[godot(rename = "...")]` or the field name (index for tuple fields).
+    pub key: Literal,
+    /// `#[godot(skip)]`: field is not stored in the dictionary, and initialized from `default` when converting back.
+    pub skip: bool,
+    /// `#[godot(default)]` or `#[godot(default = expr)]`: value used if the key is absent.
+    pub default: Option<TokenStream>,
+}
+
+impl DictionaryField {
+    fn parse_fields(fields: &venial::Fields) -> ParseResult<Vec<Self>> {
+        match fields {
+            venial::Fields::Unit => Ok(vec![]),
+            venial::Fields::Tuple(fields) => fields
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, (field, _punct))| {
+                    let member = Literal::usize_unsuffixed(index);
+                    Self::parse_field(
+                        &field.attributes,
+                        quote! { #member },
+                        &index.to_string(),
+                        &field.ty,
+                    )
+                })
+                .collect(),
+            venial::Fields::Named(fields) => fields
+                .fields
+                .iter()
+                .map(|(field, _punct)| {
+                    let name = &field.name;
+                    Self::parse_field(
+                        &field.attributes,
+                        quote! { #name },
+                        &name.to_string(),
+                        &field.ty,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Ensures that no two stored fields map to the same dictionary key, as one would overwrite the other.
+    fn check_unique_keys(fields: &[Self]) -> ParseResult<()> {
+        let mut keys = HashSet::new();
+
+        for field in fields.iter().filter(|field| !field.skip) {
+            let key = &field.key;
+            if !keys.insert(key.to_string()) {
+                return bail!(
+                    &field.member,
+                    "field key {key} is used by multiple fields; use `#[godot(rename = \"...\")]` to make keys unique"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_field(
+        attributes: &[venial::Attribute],
+        member: TokenStream,
+        default_key: &str,
+        ty: &venial::TypeExpr,
+    ) -> ParseResult<Self> {
+        let mut field = Self {
+            member,
+            ty: ty.clone(),
+            key: Literal::string(default_key),
+            skip: false,
+            default: None,
+        };
+
+        let Some(mut parser) = KvParser::parse(attributes, "godot")? else {
+            return Ok(field);
+        };
+
+        if let Some(key) = parse_string_literal(&mut parser, "rename")? {
+            field.key = key;
+        }
+
+        field.skip = parser.handle_alone("skip")?;
+        field.default = match parser.handle_any("default") {
+            None => None,
+            Some(None) => Some(quote! { ::std::default::Default::default() }),
+            Some(Some(value)) => Some(value.expr()?),
+        };
+
+        if field.skip && field.default.is_none() {
+            field.default = Some(quote! { ::std::default::Default::default() });
+        }
+
+        parser.finish()?;
+        Ok(field)
+    }
+}
+
+/// Handles `key = "string literal"`.
+pub fn parse_string_literal(parser: &mut KvParser, key: &str) -> ParseResult<Option<Literal>> {
+    let Some(value) = parser.handle_any(key) else {
+        return Ok(None);
+    };
+
+    let Some(value) = value else {
+        return bail!(
+            parser.span(),
+            "expected `{key}` to be followed by `= \"string\"`"
+        );
+    };
+
+    match value.single()? {
+        TokenTree::Literal(literal) if literal.to_string().starts_with('"') => Ok(Some(literal)),
+        other => bail!(other, "expected string literal for `{key}`"),
+    }
+}
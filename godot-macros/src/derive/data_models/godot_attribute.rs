@@ -5,18 +5,29 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::ToTokens;
 
 use crate::util::{bail, KvParser};
 use crate::ParseResult;
 
+use super::dictionary::parse_string_literal;
+
 /// Stores data related to the `#[godot(..)]` attribute.
 pub enum GodotAttribute {
     /// `#[godot(transparent)]`
     Transparent { span: Span },
-    /// `#[godot(via = via_type)]`
-    Via { span: Span, via_type: ViaType },
+    /// `#[godot(via = via_type)]`, optionally with `class = ClassName` and `bitfield`.
+    Via {
+        span: Span,
+        via_type: ViaType,
+        class_enum: Option<ClassEnumAttr>,
+    },
+    /// `#[godot(dictionary)]`, optionally with `tag = "key"` for enums.
+    Dictionary {
+        span: Span,
+        tag_key: Option<Literal>,
+    },
 }
 
 impl GodotAttribute {
@@ -36,15 +47,25 @@ impl GodotAttribute {
         }
 
         if let Some(via_type) = parser.handle_ident("via")? {
+            let via_type = ViaType::parse_ident(via_type)?;
+            let class_enum = ClassEnumAttr::parse(parser, &via_type)?;
+
             return Ok(Self::Via {
                 span,
-                via_type: ViaType::parse_ident(via_type)?,
+                via_type,
+                class_enum,
             });
         }
 
+        if parser.handle_alone("dictionary")? {
+            let tag_key = parse_string_literal(parser, "tag")?;
+
+            return Ok(Self::Dictionary { span, tag_key });
+        }
+
         bail!(
             span,
-            "expected either `#[godot(transparent)]` or `#[godot(via = <via_type>)]`"
+            "expected one of `#[godot(transparent)]`, `#[godot(via = <via_type>)]` or `#[godot(dictionary)]`"
         )
     }
 
@@ -55,7 +76,48 @@ impl GodotAttribute {
         match self {
             GodotAttribute::Transparent { span } => *span,
             GodotAttribute::Via { span, .. } => *span,
+            GodotAttribute::Dictionary { span, .. } => *span,
+        }
+    }
+}
+
+/// Registration of an enum inside a class, from `#[godot(via = via_type, class = ClassName)]`.
+pub struct ClassEnumAttr {
+    /// The class the enum is registered in.
+    pub class: Ident,
+    /// Whether the enum is registered as a bitfield (`#[godot(bitfield)]`) rather than a regular enum.
+    pub is_bitfield: bool,
+}
+
+impl ClassEnumAttr {
+    fn parse(parser: &mut KvParser, via_type: &ViaType) -> ParseResult<Option<Self>> {
+        let span = parser.span();
+        let class = parser.handle_ident("class")?;
+        let is_bitfield = parser.handle_alone("bitfield")?;
+
+        let Some(class) = class else {
+            if is_bitfield {
+                return bail!(span, "`#[godot(bitfield)]` requires `class = ...`");
+            }
+
+            return Ok(None);
+        };
+
+        // Godot enums and bitfields are always integer constants.
+        if let ViaType::GString {
+            gstring_ident: ident,
         }
+        | ViaType::StringName {
+            string_name_ident: ident,
+        } = via_type
+        {
+            return bail!(
+                ident,
+                "`#[godot(class = ...)]` requires an integer via type, not `{ident}`"
+            );
+        }
+
+        Ok(Some(Self { class, is_bitfield }))
     }
 }
 
@@ -63,6 +125,8 @@ impl GodotAttribute {
 pub enum ViaType {
     /// The via type is `GString`
     GString { gstring_ident: Ident },
+    /// The via type is `StringName`
+    StringName { string_name_ident: Ident },
     /// The via type is an integer
     Int { int_ident: Ident },
 }
@@ -71,8 +135,9 @@ impl ViaType {
     fn parse_ident(ident: Ident) -> ParseResult<Self> {
         let via_type = match ident.to_string().as_str() {
             "GString" => ViaType::GString { gstring_ident: ident },
+            "StringName" => ViaType::StringName { string_name_ident: ident },
             "i8" |"i16" | "i32" | "i64" | "u8" | "u16" | "u32" => ViaType::Int { int_ident: ident },
-            other => return bail!(ident, "Via type `{other}` is not supported, expected one of: GString, i8, i16, i32, i64, u8, u16, u32")
+            other => return bail!(ident, "Via type `{other}` is not supported, expected one of: GString, StringName, i8, i16, i32, i64, u8, u16, u32")
         };
 
         Ok(via_type)
@@ -83,6 +148,7 @@ impl ToTokens for ViaType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             ViaType::GString { gstring_ident } => gstring_ident.to_tokens(tokens),
+            ViaType::StringName { string_name_ident } => string_name_ident.to_tokens(tokens),
             ViaType::Int { int_ident } => int_ident.to_tokens(tokens),
         }
     }
@@ -6,13 +6,14 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 use crate::util::bail;
 use crate::ParseResult;
 
 use super::c_style_enum::CStyleEnum;
-use super::godot_attribute::{GodotAttribute, ViaType};
+use super::dictionary::{DictionaryEnum, DictionaryStruct};
+use super::godot_attribute::{ClassEnumAttr, GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
 
 /// Stores all relevant data to derive `GodotConvert` and other related traits.
@@ -69,7 +70,15 @@ pub enum ConvertType {
     /// Deriving for a newtype struct.
     NewType { field: NewtypeStruct },
     /// Deriving for an enum.
-    Enum { variants: CStyleEnum, via: ViaType },
+    Enum {
+        variants: CStyleEnum,
+        via: ViaType,
+        class_enum: Option<ClassEnumAttr>,
+    },
+    /// Deriving for a struct with named fields, converted via `Dictionary`.
+    DictionaryStruct { fields: DictionaryStruct },
+    /// Deriving for an enum with data-carrying variants, converted via tagged `Dictionary`.
+    DictionaryEnum { variants: DictionaryEnum },
 }
 
 impl ConvertType {
@@ -77,28 +86,53 @@ impl ConvertType {
         let attribute = GodotAttribute::parse_attribute(&item)?;
 
         match &item {
-            venial::Item::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(attribute.span(), "`GodotConvert` on structs only works with `#[godot(transparent)]` currently");
-                };
-
-                Ok(Self::NewType {
+            venial::Item::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
-            venial::Item::Enum(enum_) => {
-                let GodotAttribute::Via { via_type, .. } = attribute else {
-                    return bail!(
-                        attribute.span(),
-                        "`GodotConvert` on enums requires `#[godot(via = ...)]` currently"
-                    );
-                };
+                }),
+                GodotAttribute::Dictionary {
+                    tag_key: Some(_),
+                    span,
+                } => bail!(span, "`tag` is only supported for enums"),
+                GodotAttribute::Dictionary { .. } => Ok(Self::DictionaryStruct {
+                    fields: DictionaryStruct::parse_struct(struct_)?,
+                }),
+                GodotAttribute::Via { span, .. } => bail!(
+                    span,
+                    "`GodotConvert` on structs requires `#[godot(transparent)]` or `#[godot(dictionary)]`"
+                ),
+            },
+            venial::Item::Enum(enum_) => match attribute {
+                GodotAttribute::Via {
+                    via_type,
+                    class_enum,
+                    ..
+                } => {
+                    let variants = CStyleEnum::parse_enum(enum_)?;
 
-                Ok(Self::Enum {
-                    variants: CStyleEnum::parse_enum(enum_)?,
-                    via: via_type,
-                })
-            }
+                    if let (ViaType::Int { int_ident }, Some(rename)) =
+                        (&via_type, variants.first_rename())
+                    {
+                        return bail!(
+                            rename,
+                            "`#[godot(rename = ...)]` requires a string via type (GString, StringName), not `{int_ident}`"
+                        );
+                    }
+
+                    Ok(Self::Enum {
+                        variants,
+                        via: via_type,
+                        class_enum,
+                    })
+                }
+                GodotAttribute::Dictionary { tag_key, .. } => Ok(Self::DictionaryEnum {
+                    variants: DictionaryEnum::parse_enum(enum_, tag_key)?,
+                }),
+                GodotAttribute::Transparent { span } => bail!(
+                    span,
+                    "`GodotConvert` on enums requires `#[godot(via = ...)]` or `#[godot(dictionary)]`"
+                ),
+            },
             _ => bail!(
                 item,
                 "`GodotConvert` only supports structs and enums currently"
@@ -111,6 +145,9 @@ impl ConvertType {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
             ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::DictionaryStruct { .. } | ConvertType::DictionaryEnum { .. } => {
+                quote! { ::godot::builtin::Dictionary }
+            }
         }
     }
 }
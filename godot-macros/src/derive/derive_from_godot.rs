@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictionaryEnum, DictionaryField, DictionaryStruct, GodotConvert,
+    NewtypeStruct, ViaType,
+};
 
 /// Creates a `FromGodot` impl for the given `GodotConvert`.
 ///
@@ -23,12 +26,20 @@ pub fn make_fromgodot(convert: &GodotConvert) -> TokenStream {
         ConvertType::NewType { field } => make_fromgodot_for_newtype_struct(name, field),
         ConvertType::Enum {
             variants,
-            via: ViaType::GString { .. },
-        } => make_fromgodot_for_gstring_enum(name, variants),
+            via: via @ (ViaType::GString { .. } | ViaType::StringName { .. }),
+            ..
+        } => make_fromgodot_for_string_enum(name, variants, via),
         ConvertType::Enum {
             variants,
             via: ViaType::Int { int_ident },
+            ..
         } => make_fromgodot_for_int_enum(name, variants, int_ident),
+        ConvertType::DictionaryStruct { fields } => {
+            make_fromgodot_for_dictionary_struct(name, fields)
+        }
+        ConvertType::DictionaryEnum { variants } => {
+            make_fromgodot_for_dictionary_enum(name, variants)
+        }
     }
 }
 
@@ -69,23 +80,108 @@ fn make_fromgodot_for_int_enum(name: &Ident, enum_: &CStyleEnum, int: &Ident) ->
     }
 }
 
-/// Derives `FromGodot` for enums with a via type of `GString`.
-fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream {
+/// Derives `FromGodot` for enums with a via type of `GString` or `StringName`.
+fn make_fromgodot_for_string_enum(name: &Ident, enum_: &CStyleEnum, via: &ViaType) -> TokenStream {
     let names = enum_.names();
-    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
-    let bad_variant_error = format!("invalid {name} variant");
+    let names_str = enum_.godot_names();
+    let expected_names = names_str
+        .iter()
+        .map(|name| format!("\"{name}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let bad_variant_error = format!("invalid {name} variant, expected one of: {expected_names}");
 
     quote! {
         impl ::godot::builtin::meta::FromGodot for #name {
-            fn try_from_godot(via: ::godot::builtin::GString) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+            fn try_from_godot(via: ::godot::builtin::#via) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
                 match via.to_string().as_str() {
                     #(
                         #names_str => Ok(#name::#names),
                     )*
                     // Pass `via` and not `other`, to retain debug info of original type.
-                    other => Err(::godot::builtin::meta::ConvertError::with_error_value(#bad_variant_error, via))
+                    _ => Err(::godot::builtin::meta::ConvertError::with_error_value(#bad_variant_error, via))
                 }
             }
         }
     }
 }
+
+/// Derives `FromGodot` for structs with named fields, converted from a `Dictionary`.
+fn make_fromgodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let type_name = name.to_string();
+    let fields = struct_
+        .fields
+        .iter()
+        .map(|field| make_dictionary_field_init(field, &type_name));
+
+    quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::Dictionary) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+                Ok(Self {
+                    #( #fields )*
+                })
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for enums with data-carrying variants, converted from a `Dictionary` tagged with the variant name.
+fn make_fromgodot_for_dictionary_enum(name: &Ident, enum_: &DictionaryEnum) -> TokenStream {
+    let type_name = name.to_string();
+    let tag_key = &enum_.tag_key;
+
+    let arms = enum_.variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let tag = &variant.tag;
+        let fields = variant
+            .fields
+            .iter()
+            .map(|field| make_dictionary_field_init(field, &type_name));
+
+        quote! {
+            #tag => Ok(#name::#variant_name {
+                #( #fields )*
+            }),
+        }
+    });
+
+    quote! {
+        impl ::godot::builtin::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::Dictionary) -> ::std::result::Result<Self, ::godot::builtin::meta::ConvertError> {
+                let tag = ::godot::private::dict_get_tag(&via, #tag_key, #type_name)?;
+
+                match tag.as_str() {
+                    #( #arms )*
+                    other => Err(::godot::private::dict_unknown_tag(&via, #tag_key, other, #type_name)),
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `member: value,` initializer of a field read from the dictionary `via`.
+fn make_dictionary_field_init(field: &DictionaryField, type_name: &str) -> TokenStream {
+    let member = &field.member;
+
+    if field.skip {
+        // unwrap: skipped fields always have a default.
+        let default = field.default.as_ref().unwrap();
+        return quote! { #member: #default, };
+    }
+
+    let key = &field.key;
+    let ty = &field.ty;
+    let if_missing = match &field.default {
+        Some(default) => quote! { #default },
+        None => quote! {
+            return Err(::godot::private::dict_missing_field(&via, #key, #type_name))
+        },
+    };
+
+    quote! {
+        #member: match ::godot::private::dict_get_field::<#ty>(&via, #key, #type_name)? {
+            Some(value) => value,
+            None => #if_missing,
+        },
+    }
+}
@@ -5,12 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::GodotConvert;
+use crate::derive::data_models::{CStyleEnum, ClassEnumAttr, ConvertType, GodotConvert};
 use crate::derive::{make_fromgodot, make_togodot};
-use crate::ParseResult;
+use crate::{util, ParseResult};
 
 /// Derives `GodotConvert` for the given declaration.
 ///
@@ -24,12 +24,109 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
     let to_godot_impl = make_togodot(&convert);
     let from_godot_impl = make_fromgodot(&convert);
 
+    let (property_info_override, class_enum_impl) = match &convert.convert_type {
+        ConvertType::Enum {
+            variants,
+            class_enum: Some(class_enum),
+            ..
+        } => make_class_enum(name, &via_type, variants, class_enum),
+        _ => (TokenStream::new(), TokenStream::new()),
+    };
+
     Ok(quote! {
         impl ::godot::builtin::meta::GodotConvert for #name  {
             type Via = #via_type;
+
+            #property_info_override
         }
 
         #to_godot_impl
         #from_godot_impl
+        #class_enum_impl
     })
 }
+
+/// Registers the enum inside its class, and makes parameters and properties of this type refer to it as `Class.Enum`.
+///
+/// Returns the `godot_property_info()` override and the registration code.
+fn make_class_enum(
+    name: &Ident,
+    via_type: &TokenStream,
+    variants: &CStyleEnum,
+    class_enum: &ClassEnumAttr,
+) -> (TokenStream, TokenStream) {
+    let ClassEnumAttr { class, is_bitfield } = class_enum;
+
+    let class_name_obj = util::class_name_obj(class);
+    let prv = quote! { ::godot::private };
+
+    let enum_name_str = name.to_string();
+    let variant_names_str = variants.names().iter().map(ToString::to_string);
+    let discriminants = variants.discriminants();
+    let hint_string = variants.to_int_hint();
+
+    let (hint, usage, constant_kind) = if *is_bitfield {
+        (
+            quote! { FLAGS },
+            quote! { CLASS_IS_BITFIELD },
+            quote! { Bitfield { name, flags: constants } },
+        )
+    } else {
+        (
+            quote! { ENUM },
+            quote! { CLASS_IS_ENUM },
+            quote! { Enum { name, enumerators: constants } },
+        )
+    };
+
+    let property_info_override = quote! {
+        fn godot_property_info(property_name: &str) -> ::godot::builtin::meta::PropertyInfo {
+            use ::godot::engine::global::{PropertyHint, PropertyUsageFlags};
+
+            ::godot::builtin::meta::PropertyInfo {
+                class_name: #class_name_obj.nested(#enum_name_str),
+                hint: PropertyHint::#hint,
+                hint_string: ::godot::builtin::GString::from(#hint_string),
+                usage: PropertyUsageFlags::DEFAULT | PropertyUsageFlags::#usage,
+                ..<#via_type as ::godot::builtin::meta::GodotType>::property_info(property_name)
+            }
+        }
+    };
+
+    let class_enum_impl = quote! {
+        // The enum is registered in the scope of `#class`, which must be a user-defined class.
+        const _: () = {
+            fn __class_must_be_a_user_defined_godot_class<T: ::godot::obj::GodotClass<Declarer = ::godot::obj::bounds::DeclUser>>() {}
+
+            let _ = __class_must_be_a_user_defined_godot_class::<#class>;
+        };
+
+        impl ::godot::obj::cap::ImplementsClassEnum for #name {
+            fn __register_enum() {
+                use ::godot::builtin::meta::registration::constant::*;
+                use ::godot::builtin::StringName;
+
+                let name = StringName::from(#enum_name_str);
+                let constants = vec![
+                    #(
+                        IntegerConstant::new(StringName::from(#variant_names_str), #discriminants as i64),
+                    )*
+                ];
+
+                ExportConstant::new(#class_name_obj, ConstantKind::#constant_kind).register();
+            }
+        }
+
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: #class_name_obj,
+            item: #prv::PluginItem::ClassEnum {
+                register_enum_fn: #prv::ErasedRegisterFn {
+                    raw: #prv::callbacks::register_class_enum::<#name>,
+                },
+            },
+            init_level: <#class as ::godot::obj::GodotClass>::INIT_LEVEL,
+        });
+    };
+
+    (property_info_override, class_enum_impl)
+}
@@ -6,9 +6,12 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictionaryEnum, DictionaryField, DictionaryStruct, GodotConvert,
+    NewtypeStruct, ViaType,
+};
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
 ///
@@ -23,12 +26,20 @@ pub fn make_togodot(convert: &GodotConvert) -> TokenStream {
         ConvertType::NewType { field } => make_togodot_for_newtype_struct(name, field),
         ConvertType::Enum {
             variants,
-            via: ViaType::GString { .. },
-        } => make_togodot_for_string_enum(name, variants),
+            via: via @ (ViaType::GString { .. } | ViaType::StringName { .. }),
+            ..
+        } => make_togodot_for_string_enum(name, variants, via),
         ConvertType::Enum {
             variants,
             via: ViaType::Int { int_ident },
+            ..
         } => make_togodot_for_int_enum(name, variants, int_ident),
+        ConvertType::DictionaryStruct { fields } => {
+            make_togodot_for_dictionary_struct(name, fields)
+        }
+        ConvertType::DictionaryEnum { variants } => {
+            make_togodot_for_dictionary_enum(name, variants)
+        }
     }
 }
 
@@ -68,14 +79,14 @@ fn make_togodot_for_int_enum(name: &Ident, enum_: &CStyleEnum, int: &Ident) -> T
     }
 }
 
-/// Derives `ToGodot` for enums with a via type of `GString`.
-fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream {
+/// Derives `ToGodot` for enums with a via type of `GString` or `StringName`.
+fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum, via: &ViaType) -> TokenStream {
     let names = enum_.names();
-    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let names_str = enum_.godot_names();
 
     quote! {
         impl ::godot::builtin::meta::ToGodot for #name {
-            fn to_godot(&self) -> ::godot::builtin::GString {
+            fn to_godot(&self) -> ::godot::builtin::#via {
                 match self {
                     #(
                         #name::#names => #names_str.into(),
@@ -85,3 +96,68 @@ fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream
         }
     }
 }
+
+/// Derives `ToGodot` for structs with named fields, converted to a `Dictionary`.
+fn make_togodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let inserts = struct_
+        .fields
+        .iter()
+        .filter(|field| !field.skip)
+        .map(|field| {
+            let member = &field.member;
+            let key = &field.key;
+
+            quote! {
+                dict.set(#key, ::godot::builtin::meta::ToGodot::to_variant(&self.#member));
+            }
+        });
+
+    quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> ::godot::builtin::Dictionary {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                #( #inserts )*
+                dict
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for enums with data-carrying variants, converted to a `Dictionary` tagged with the variant name.
+fn make_togodot_for_dictionary_enum(name: &Ident, enum_: &DictionaryEnum) -> TokenStream {
+    let tag_key = &enum_.tag_key;
+
+    let arms = enum_.variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let tag = &variant.tag;
+        let stored_fields: Vec<&DictionaryField> =
+            variant.fields.iter().filter(|field| !field.skip).collect();
+
+        let members = stored_fields.iter().map(|field| &field.member);
+        let keys = stored_fields.iter().map(|field| &field.key);
+        let bindings: Vec<Ident> = (0..stored_fields.len())
+            .map(|index| format_ident!("__field_{index}"))
+            .collect();
+
+        quote! {
+            #name::#variant_name { #( #members: #bindings, )* .. } => {
+                dict.set(#tag_key, #tag);
+                #(
+                    dict.set(#keys, ::godot::builtin::meta::ToGodot::to_variant(#bindings));
+                )*
+            }
+        }
+    });
+
+    quote! {
+        impl ::godot::builtin::meta::ToGodot for #name {
+            fn to_godot(&self) -> ::godot::builtin::Dictionary {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                match self {
+                    #( #arms )*
+                }
+                dict
+            }
+        }
+    }
+}
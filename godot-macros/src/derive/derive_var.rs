@@ -42,6 +42,7 @@ pub fn derive_var(item: venial::Item) -> ParseResult<TokenStream> {
 /// Make an appropriate property hint implementation.
 ///
 /// For newtype structs we just defer to the wrapped type. For enums we use `PropertyHint::ENUM` with an appropriate hint string.
+/// Types converted via `Dictionary` use the hint of `Dictionary`.
 fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
     use super::data_models::ConvertType as Data;
     use super::data_models::ViaType;
@@ -53,18 +54,33 @@ fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
                 <#ty as ::godot::register::property::Var>::property_hint()
             }
         }
-        Data::Enum { variants, via } => {
+        Data::Enum {
+            variants,
+            via,
+            class_enum,
+        } => {
             let hint_string = match via {
-                ViaType::GString { .. } => variants.to_string_hint(),
+                ViaType::GString { .. } | ViaType::StringName { .. } => variants.to_string_hint(),
                 ViaType::Int { .. } => variants.to_int_hint(),
             };
 
+            // Bitfields can have several flags set at once.
+            let hint = match class_enum {
+                Some(class_enum) if class_enum.is_bitfield => quote! { FLAGS },
+                _ => quote! { ENUM },
+            };
+
             quote! {
                 ::godot::register::property::PropertyHintInfo {
-                    hint: ::godot::engine::global::PropertyHint::ENUM,
+                    hint: ::godot::engine::global::PropertyHint::#hint,
                     hint_string: #hint_string.into(),
                 }
             }
         }
+        Data::DictionaryStruct { .. } | Data::DictionaryEnum { .. } => {
+            quote! {
+                <::godot::builtin::Dictionary as ::godot::register::property::Var>::property_hint()
+            }
+        }
     }
 }
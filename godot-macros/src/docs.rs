@@ -0,0 +1,406 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Collects `///` doc comments and renders them as fragments of Godot's class reference XML.
+//!
+//! Rendering happens unconditionally; only the registration tokens are omitted if the `register-docs` feature is disabled.
+
+use crate::class::{map_self_to_class_name, Field, FuncDefinition, SignalDefinition};
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+/// Generates the docs registration for a `#[derive(GodotClass)]` struct.
+pub fn make_definition_docs(
+    class_name: &Ident,
+    base_ty: &Ident,
+    class_attributes: &[venial::Attribute],
+    fields: &[Field],
+) -> TokenStream {
+    let description = extract_docs(class_attributes);
+    let brief_description = description.split("\n\n").next().unwrap_or_default();
+
+    let base = base_ty.to_string();
+    let brief_description = xml_escape(&markdown_to_bbcode(brief_description));
+    let description = xml_escape(&markdown_to_bbcode(&description));
+    let members: Vec<XmlPiece> = fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some())
+        .flat_map(|field| make_member_xml(field, class_name))
+        .collect();
+    let members = make_render_fn(members);
+
+    make_docs_plugin(
+        class_name,
+        quote! {
+            Struct(::godot::private::StructDocs {
+                base: #base,
+                brief_description: #brief_description,
+                description: #description,
+                members: #members,
+            })
+        },
+    )
+}
+
+/// Generates the docs registration for a `#[godot_api] impl` block.
+pub fn make_inherent_impl_docs(
+    class_name: &Ident,
+    funcs: &[FuncDefinition],
+    signals: &[SignalDefinition],
+    constants: &[venial::Constant],
+) -> TokenStream {
+    let methods: Vec<XmlPiece> = funcs
+        .iter()
+        .flat_map(|func| make_method_xml(func, class_name))
+        .collect();
+    let signals: Vec<XmlPiece> = signals
+        .iter()
+        .flat_map(|signal| make_signal_xml(signal, class_name))
+        .collect();
+    let methods = make_render_fn(methods);
+    let signals = make_render_fn(signals);
+    let constants: String = constants.iter().map(make_constant_xml).collect();
+
+    make_docs_plugin(
+        class_name,
+        quote! {
+            InherentImpl(::godot::private::InherentImplDocs {
+                methods: #methods,
+                signals: #signals,
+                constants: #constants,
+            })
+        },
+    )
+}
+
+fn make_docs_plugin(class_name: &Ident, item: TokenStream) -> TokenStream {
+    if !cfg!(feature = "register-docs") {
+        return TokenStream::new();
+    }
+
+    let prv = quote! { ::godot::private };
+
+    quote! {
+        ::godot::sys::plugin_add!(__GODOT_DOCS_REGISTRY in #prv; #prv::DocsPlugin {
+            class_name: <#class_name as ::godot::obj::GodotClass>::class_name(),
+            item: #prv::DocsItem::#item,
+        });
+    }
+}
+
+fn make_member_xml(field: &Field, class_name: &Ident) -> Vec<XmlPiece> {
+    let name = field.name.to_string();
+    let description = xml_escape(&markdown_to_bbcode(&field.docs));
+
+    vec![
+        XmlPiece::Text(format!(r#"<member name="{name}" type=""#)),
+        XmlPiece::type_name(&field.ty, class_name),
+        XmlPiece::Text(format!(r#"">{description}</member>"#)),
+    ]
+}
+
+fn make_method_xml(func: &FuncDefinition, class_name: &Ident) -> Vec<XmlPiece> {
+    let signature = &func.signature;
+    let name = func
+        .rename
+        .clone()
+        .unwrap_or_else(|| signature.name.to_string());
+
+    let return_ty = match &signature.return_ty {
+        Some(return_ty) => XmlPiece::type_name(return_ty, class_name),
+        None => XmlPiece::Text("void".to_string()),
+    };
+
+    let description = xml_escape(&markdown_to_bbcode(&extract_docs(
+        &func.external_attributes,
+    )));
+
+    let mut pieces = vec![
+        XmlPiece::Text(format!(r#"<method name="{name}"><return type=""#)),
+        return_ty,
+        XmlPiece::Text(r#""/>"#.to_string()),
+    ];
+    pieces.extend(make_params_xml(&signature.params, class_name));
+    pieces.push(XmlPiece::Text(format!(
+        "<description>{description}</description></method>"
+    )));
+
+    pieces
+}
+
+fn make_signal_xml(signal: &SignalDefinition, class_name: &Ident) -> Vec<XmlPiece> {
+    let name = signal.signature.name.to_string();
+    let description = xml_escape(&markdown_to_bbcode(&extract_docs(
+        &signal.external_attributes,
+    )));
+
+    let mut pieces = vec![XmlPiece::Text(format!(r#"<signal name="{name}">"#))];
+    pieces.extend(make_params_xml(&signal.signature.params, class_name));
+    pieces.push(XmlPiece::Text(format!(
+        "<description>{description}</description></signal>"
+    )));
+
+    pieces
+}
+
+fn make_constant_xml(constant: &venial::Constant) -> String {
+    let name = constant.name.to_string();
+    let value = constant
+        .initializer
+        .as_ref()
+        .map(|value| xml_escape(&value.to_token_stream().to_string()))
+        .unwrap_or_default();
+    let description = xml_escape(&markdown_to_bbcode(&extract_docs(&constant.attributes)));
+
+    format!(r#"<constant name="{name}" value="{value}">{description}</constant>"#)
+}
+
+/// Renders typed parameters as `<param>` elements; the `self` receiver is skipped.
+fn make_params_xml(
+    params: &venial::Punctuated<venial::FnParam>,
+    class_name: &Ident,
+) -> Vec<XmlPiece> {
+    params
+        .inner
+        .iter()
+        .filter_map(|(param, _punct)| match param {
+            venial::FnParam::Typed(param) => Some(param),
+            venial::FnParam::Receiver(_) => None,
+        })
+        .enumerate()
+        .flat_map(|(index, param)| {
+            let name = &param.name;
+
+            [
+                XmlPiece::Text(format!(r#"<param index="{index}" name="{name}" type=""#)),
+                XmlPiece::type_name(&param.ty, class_name),
+                XmlPiece::Text(r#""/>"#.to_string()),
+            ]
+        })
+        .collect()
+}
+
+/// Concatenates all `#[doc = "..."]` attributes (which `///` comments desugar to) into one string.
+pub fn extract_docs(attributes: &[venial::Attribute]) -> String {
+    let lines: Vec<String> = attributes
+        .iter()
+        .filter(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "doc")
+        })
+        .filter_map(|attr| match &attr.value {
+            venial::AttributeValue::Equals(_punct, tokens) => match tokens.as_slice() {
+                [TokenTree::Literal(literal)] => unquote_string_literal(&literal.to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    // `///` comments usually start with a space, which is not part of the content.
+    let lines: Vec<&str> = lines
+        .iter()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect();
+
+    lines.join("\n").trim().to_string()
+}
+
+/// Returns the content of a (possibly raw) string literal, or `None` if the token is not a string literal.
+fn unquote_string_literal(literal: &str) -> Option<String> {
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let content = raw.get(hashes..raw.len() - hashes)?;
+
+        return content
+            .strip_prefix('"')
+            .and_then(|content| content.strip_suffix('"'))
+            .map(str::to_string);
+    }
+
+    let content = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => {}
+            Some('0') => {}
+            Some(other) => result.push(other), // Covers \\, \" and \'.
+            None => {}
+        }
+    }
+
+    Some(result)
+}
+
+/// Converts the Markdown subset commonly used in doc comments to Godot's BBCode.
+///
+/// Fenced code blocks become `[codeblock]`, inline code becomes `[code]`. Everything else is kept as-is.
+fn markdown_to_bbcode(markdown: &str) -> String {
+    let mut result = Vec::new();
+    let mut in_codeblock = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            result.push(if in_codeblock {
+                "[/codeblock]".to_string()
+            } else {
+                "[codeblock]".to_string()
+            });
+            in_codeblock = !in_codeblock;
+        } else if in_codeblock {
+            result.push(line.to_string());
+        } else {
+            result.push(inline_code_to_bbcode(line));
+        }
+    }
+
+    if in_codeblock {
+        result.push("[/codeblock]".to_string());
+    }
+
+    result.join("\n")
+}
+
+fn inline_code_to_bbcode(line: &str) -> String {
+    // Unbalanced backticks are likely not meant as code; leave the line untouched.
+    if line.matches('`').count() % 2 == 1 {
+        return line.to_string();
+    }
+
+    let mut result = String::with_capacity(line.len());
+
+    for (index, part) in line.split('`').enumerate() {
+        if index % 2 == 1 {
+            result.push_str("[code]");
+            result.push_str(part);
+            result.push_str("[/code]");
+        } else {
+            result.push_str(part);
+        }
+    }
+
+    result
+}
+
+/// Part of an XML fragment.
+enum XmlPiece {
+    /// Pre-rendered, XML-escaped text.
+    Text(String),
+
+    /// Rust type, whose Godot name is looked up when the docs are registered.
+    TypeName(TokenStream),
+}
+
+impl XmlPiece {
+    fn type_name(ty: &venial::TypeExpr, class_name: &Ident) -> Self {
+        // The fragment is rendered outside the impl block, where `Self` is not available.
+        let ty: TokenStream = map_self_to_class_name(ty.to_token_stream(), class_name);
+
+        Self::TypeName(ty)
+    }
+}
+
+/// Generates a function that renders `pieces` to a string.
+fn make_render_fn(pieces: Vec<XmlPiece>) -> TokenStream {
+    let pushes = pieces.into_iter().map(|piece| match piece {
+        XmlPiece::Text(text) => quote! { xml.push_str(#text); },
+        XmlPiece::TypeName(ty) => {
+            quote! { xml.push_str(&::godot::private::docs_type_name::<#ty>()); }
+        }
+    });
+
+    quote! {
+        || {
+            #[allow(unused_mut)]
+            let mut xml = String::new();
+            #( #pushes )*
+            xml
+        }
+    }
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    fn docs_of(input_tokens: TokenStream) -> String {
+        let input = quote! {
+            #input_tokens
+            fn func();
+        };
+        let decl = venial::parse_item(input).expect("decl");
+
+        extract_docs(&decl.as_function().expect("fn").attributes)
+    }
+
+    #[test]
+    fn test_extract_docs() {
+        let docs = docs_of(quote! {
+            #[doc = " First line."]
+            #[doc = ""]
+            #[doc = " Says \"hi\"."]
+            #[inline]
+        });
+
+        assert_eq!(docs, "First line.\n\nSays \"hi\".");
+    }
+
+    #[test]
+    fn test_extract_docs_raw_literal() {
+        let docs = docs_of(quote! {
+            #[doc = r#" Raw "quoted" text."#]
+        });
+
+        assert_eq!(docs, "Raw \"quoted\" text.");
+    }
+
+    #[test]
+    fn test_markdown_to_bbcode() {
+        let bbcode =
+            markdown_to_bbcode("Calls `jump()`.\n```\nlet x = 1;\n```\nUnbalanced ` tick.");
+
+        assert_eq!(
+            bbcode,
+            "Calls [code]jump()[/code].\n[codeblock]\nlet x = 1;\n[/codeblock]\nUnbalanced ` tick."
+        );
+    }
+
+    #[test]
+    fn test_xml_escape() {
+        assert_eq!(
+            xml_escape(r#"Gd<Node> & "x""#),
+            "Gd&lt;Node&gt; &amp; &quot;x&quot;"
+        );
+    }
+}
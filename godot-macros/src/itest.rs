@@ -54,7 +54,7 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
             return bad_signature(&func);
         }
     } else {
-        quote! { __unused_context: &crate::framework::TestContext }
+        quote! { __unused_context: &::godot::test::TestContext }
     };
 
     let body = &func.body;
@@ -64,7 +64,7 @@ pub fn attribute_itest(input_item: venial::Item) -> ParseResult<TokenStream> {
             #body
         }
 
-        ::godot::sys::plugin_add!(__GODOT_ITEST in crate::framework; crate::framework::RustTestCase {
+        ::godot::sys::plugin_add!(__GODOT_ITEST in ::godot::test; ::godot::test::RustTestCase {
             name: #test_name_str,
             skipped: #skipped,
             focused: #focused,
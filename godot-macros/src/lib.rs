@@ -8,6 +8,7 @@
 mod bench;
 mod class;
 mod derive;
+mod docs;
 mod gdextension;
 mod itest;
 mod util;
@@ -102,6 +103,32 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Abstract classes
+///
+/// Base classes that are only meant to be extended (e.g. by GDScript classes) can be declared with `#[class(abstract)]`. Like `no_init`,
+/// this registers the class as abstract in Godot: it appears in the editor's class hierarchy and works with `is_class()` checks, but
+/// cannot be instantiated. GDScript reports `MyStruct.new()` as an error during parsing, and `ClassDB.instantiate()` fails at runtime.
+///
+/// `abstract` implies `no_init` and cannot be combined with `init`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(abstract, base=Node)]
+/// struct Enemy {
+///     base: Base<Node>,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(abstract, init, base=Node)]
+/// struct Enemy {
+///     base: Base<Node>,
+/// }
+/// ```
+///
 /// # Inheritance
 ///
 /// Unlike C++, Rust doesn't really have inheritance, but the GDExtension API lets us "inherit"
@@ -299,6 +326,19 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// `#[export(node_path = (Class1, ...))]`, the equivalent of `@export_node_path`, restricts which node types can be selected. It is only
+/// valid on fields of type `NodePath`:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     #[export(node_path = (Camera3D))]
+///     camera: GString,
+/// }
+/// ```
+///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the
 /// `hint`, `hint_string`, and `usage_flags` keys in the attribute. These are constants in the `PropertyHint`
 /// and `PropertyUsageFlags` enums, respectively.
@@ -320,16 +360,57 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Property groups
+///
+/// To organize properties in the inspector, GDScript's `@export_category`, `@export_group` and `@export_subgroup` have equivalent
+/// field attributes. They are placed on the first field of the section and require `#[var]` or `#[export]` on that field.
+/// All properties declared after it belong to the same section, until the next section of the same (or a higher) level starts.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // @export_category("Player")
+///     // @export_group("Movement", "move_")
+///     #[export_category(name = "Player")]
+///     #[export_group(name = "Movement", prefix = "move_")]
+///     #[export]
+///     move_speed: f32,
+///
+///     #[export]
+///     move_acceleration: f32,
+///
+///     // @export_subgroup("Jumping")
+///     #[export_subgroup(name = "Jumping")]
+///     #[export]
+///     move_jump_height: f32,
+///
+///     // @export_group("") -- ends the group.
+///     #[export_group(name = "")]
+///     #[export]
+///     health: i32,
+/// }
+/// ```
+///
+/// With `prefix`, the inspector only lists properties starting with the prefix in the group, and strips it from their displayed names.
+///
 /// # Signals
 ///
-/// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
-/// It will be fundamentally reworked.
+/// The `#[signal]` attribute declares a signal. The functions it decorates (the signals) can accept parameters, but have no body
+/// and no return type.
+///
+/// For every class with signals, a type-safe API is generated: `self.signals()` returns an object with one method per signal, each
+/// returning a [`TypedSignal`](../obj/struct.TypedSignal.html). Parameter types and count are checked at compile time, both when
+/// emitting and when connecting receivers.
 ///
 /// ```no_run
 /// # use godot::prelude::*;
 /// #[derive(GodotClass)]
-/// # #[class(init)]
-/// struct MyClass {}
+/// # #[class(init, base=Node)]
+/// struct MyClass {
+///     base: Base<Node>,
+/// }
 ///
 /// #[godot_api]
 /// impl MyClass {
@@ -338,9 +419,34 @@ use crate::util::ident;
 ///
 ///     #[signal]
 ///     fn some_signal_with_parameters(my_parameter: Gd<Node>);
+///
+///     #[func]
+///     fn trigger(&mut self, node: Gd<Node>) {
+///         self.signals().some_signal().emit();
+///         self.signals().some_signal_with_parameters().emit(node);
+///     }
+///
+///     #[func]
+///     fn on_some_signal(&mut self) {
+///         godot_print!("received");
+///     }
+///
+///     #[func]
+///     fn connect_all(&mut self) {
+///         // Connect to a method of the same object (Godot 4.2+).
+///         self.signals().some_signal().connect_self(Self::on_some_signal);
+///
+///         // Connect to a closure (Godot 4.2+).
+///         self.signals()
+///             .some_signal_with_parameters()
+///             .connect(|node: Gd<Node>| godot_print!("got {node}"));
+///     }
 /// }
 /// ```
 ///
+/// Emitting and connecting require a `Base<T>` field. Signals can still be used in an untyped way via `Object::emit_signal()` and
+/// `Object::connect()`.
+///
 /// # Further class customization
 ///
 /// ## Running code in the editor
@@ -353,6 +459,13 @@ use crate::util::ident;
 ///
 /// This is very similar to [GDScript's `@tool` feature](https://docs.godotengine.org/en/stable/tutorials/plugins/running_code_in_the_editor.html).
 ///
+/// Conversely, `#[class(runtime)]` ensures that a class never runs in the editor, even if the library opts into
+/// [`EditorRunBehavior::AllClasses`](../init/enum.EditorRunBehavior.html#variant.AllClasses). The two keys are mutually exclusive.
+///
+/// Since Godot 4.3, classes that don't run in the editor are registered as "runtime classes": the editor then creates placeholder
+/// instances, which keep property values but don't execute any Rust code. On Godot 4.1 and 4.2, gdext emulates this by skipping
+/// virtual callbacks (`ready()`, `process()`, ...) in the editor.
+///
 /// ## Editor plugins
 ///
 /// If you annotate a class with `#[class(editor_plugin)]`, it will be turned into an editor plugin. The
@@ -367,6 +480,88 @@ use crate::util::ident;
 /// This should usually be combined with `#[class(tool)]` so that the code you write will actually run in the
 /// editor.
 ///
+/// ## Inspector plugins
+///
+/// Similarly, `#[class(inspector_plugin)]` turns an `EditorInspectorPlugin` subclass into an inspector plugin, which can add custom
+/// property editors to the inspector. When the library is loaded in the editor, an instance is created and added to the inspector;
+/// it is removed again when the library is unloaded, so no `EditorPlugin` is needed to manage it.
+///
+/// The class requires the `tool` key, `base=EditorInspectorPlugin` and a constructor:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::{EditorInspectorPlugin, IEditorInspectorPlugin, Object};
+/// #[derive(GodotClass)]
+/// #[class(tool, init, inspector_plugin, base=EditorInspectorPlugin)]
+/// pub struct ColorInspector {}
+///
+/// #[godot_api]
+/// impl IEditorInspectorPlugin for ColorInspector {
+///     fn can_handle(&self, object: Gd<Object>) -> bool {
+///         object.get_class() == "ColorPalette".into()
+///     }
+/// }
+/// ```
+///
+/// Omitting any of these is a compile error:
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, inspector_plugin, base=EditorInspectorPlugin)] // missing `tool`
+/// pub struct ColorInspector {}
+/// ```
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(tool, init, inspector_plugin, base=EditorPlugin)] // wrong base
+/// pub struct ColorInspector {}
+/// ```
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(tool, no_init, inspector_plugin, base=EditorInspectorPlugin)] // no constructor
+/// pub struct ColorInspector {}
+/// ```
+///
+/// For the common case of editing single properties with a custom `EditorProperty`, no plugin needs to be written by hand.
+/// `#[export(editor = T)]` generates a hidden inspector plugin for the class, which uses a new instance of `T` to edit this property.
+/// `T` must be a class inheriting `EditorProperty` with a constructor, usually declared with `#[class(tool, init, base=EditorProperty)]`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::{EditorProperty, IEditorProperty};
+/// #[derive(GodotClass)]
+/// #[class(tool, init, base=EditorProperty)]
+/// pub struct GradientEditor {
+///     base: Base<EditorProperty>,
+/// }
+///
+/// #[godot_api]
+/// impl IEditorProperty for GradientEditor {
+///     fn update_property(&mut self) {
+///         // Read the edited value with `self.base().get_edited_object()` and `get_edited_property()`.
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Sky {
+///     #[export(editor = GradientEditor)]
+///     colors: PackedColorArray,
+/// }
+/// ```
+///
+/// The editor class must inherit `EditorProperty`:
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// pub struct Sky {
+///     #[export(editor = Node)]
+///     colors: PackedColorArray,
+/// }
+/// ```
+///
 /// ## Class renaming
 ///
 /// You may want to have structs with the same name. With Rust, this is allowed using `mod`. However in GDScript,
@@ -405,6 +600,40 @@ use crate::util::ident;
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Engine singletons
+///
+/// With `#[class(singleton)]`, the library creates one instance of the class once it is registered, and adds it as an engine singleton
+/// under the class name (see `Engine::register_singleton()`). GDScript can then access it by name, like built-in singletons such as
+/// `Input`. When the library is unloaded, the singleton is unregistered and freed. This replaces hand-configured autoloads for global
+/// services.
+///
+/// The class needs a constructor (`init` or a user-defined one) and must not inherit `RefCounted`, as the engine does not keep singletons
+/// alive. From Rust, the instance is available through the generated `singleton()` function:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Object, singleton)]
+/// pub struct SaveManager {
+///     slot: i32,
+/// }
+///
+/// fn current_slot() -> i32 {
+///     SaveManager::singleton().bind().slot
+/// }
+/// ```
+///
+/// Ref-counted singleton classes are rejected at compile time:
+///
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted, singleton)]
+/// pub struct SaveManager {
+///     slot: i32,
+/// }
+/// ```
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -439,7 +668,21 @@ use crate::util::ident;
 /// #     fn init(base: godot::obj::Base<Self::Base>) -> Self { todo!() }
 /// # }
 /// ```
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init, signal))]
+#[proc_macro_derive(
+    GodotClass,
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_category,
+        export_group,
+        export_subgroup,
+        init,
+        signal
+    )
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -625,6 +868,102 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Default parameters
+///
+/// Trailing parameters of a `#[func]` can be given a default value with `#[opt(default = expr)]`. The expression must have the
+/// parameter's type and may refer to `Self`, e.g. `Self::SOME_CONST`. Defaults are registered with Godot, so GDScript callers may
+/// omit those arguments. Parameters without default value cannot follow ones that have one.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Greeter {}
+///
+/// #[godot_api]
+/// impl Greeter {
+///     #[func]
+///     fn greet(
+///         &self,
+///         name: GString,
+///         #[opt(default = GString::from("Hello"))] greeting: GString,
+///         #[opt(default = 1)] times: i64,
+///     ) {
+///         for _ in 0..times {
+///             godot_print!("{greeting}, {name}!");
+///         }
+///     }
+/// }
+/// ```
+///
+/// From GDScript, `greeter.greet("Godot")` is then equivalent to `greeter.greet("Godot", "Hello", 1)`.
+/// Calls from Rust still need to pass all arguments.
+///
+/// ## Variadic methods
+///
+/// If the last parameter of a `#[func]` has type `&[Variant]`, the method is registered as variadic: it accepts any number of
+/// additional arguments after the fixed parameters, which are collected into that slice. Calls with fewer arguments than fixed
+/// parameters fail with a call error. Variadic methods cannot be virtual, RPCs or have default parameters.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Logger {}
+///
+/// #[godot_api]
+/// impl Logger {
+///     #[func]
+///     fn log(&self, level: GString, args: &[Variant]) {
+///         let message = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+///         godot_print!("[{level}] {}", message.join(" "));
+///     }
+/// }
+/// ```
+///
+/// In GDScript, this can be called as `logger.log("info", "a", 1, [2, 3])`.
+///
+/// ## RPC attributes
+///
+/// Methods of classes inheriting `Node` can additionally be annotated with `#[rpc]`, which configures them as remote procedure calls
+/// for Godot's high-level multiplayer API. The configuration is applied automatically whenever an instance is constructed, so there is
+/// no need to call `Node::rpc_config()` by hand.
+///
+/// The attribute accepts the following keys; omitted ones use Godot's defaults (written in **bold**):
+/// - Who can call the method: `any_peer` or **`authority`**.
+/// - How packets are delivered: **`reliable`**, `unreliable` or `unreliable_ordered`.
+/// - Whether the method is also called locally: `call_local` or **`call_remote`**.
+/// - Which channel to send on: `channel = expr` (**0**).
+///
+/// Alternatively, `#[rpc(config = expr)]` accepts a full [`RpcConfig`](../builtin/meta/registration/rpc_config/struct.RpcConfig.html)
+/// expression, which can't be combined with the other keys.
+///
+/// For each RPC method `foo`, two typed helpers are generated on the class: `rpc_foo(args...)` calls it on all peers, while
+/// `rpc_id_foo(peer_id, args...)` calls it on a single peer. Both return the `Error` reported by Godot.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     #[rpc(any_peer, call_local, unreliable, channel = 2)]
+///     fn hit(&mut self, damage: i64) {
+///         godot_print!("Hit for {damage}");
+///     }
+///
+///     #[func]
+///     fn attack(&mut self) {
+///         self.rpc_hit(10);
+///     }
+/// }
+/// ```
+///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
@@ -633,14 +972,53 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
 }
 
-/// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs.
+/// Makes a user class available as trait object in [`DynGd`](../obj/struct.DynGd.html).
+///
+/// Apply this attribute on an `impl Trait for MyClass` block, where `MyClass` is a `#[derive(GodotClass)]` struct. Besides keeping the impl
+/// as-is, this implements [`AsDyn<dyn Trait>`](../obj/trait.AsDyn.html) and registers the implementation with Godot's class registry,
+/// so that objects of `MyClass` can be converted to `DynGd<_, dyn Trait>` even if their concrete class is not statically known
+/// (e.g. when obtained as `Gd<Node>` from the scene tree, or from a `Variant`).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// trait Health {
+///     fn hitpoints(&self) -> i32;
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Monster {
+///     hp: i32,
+/// }
+///
+/// #[godot_dyn]
+/// impl Health for Monster {
+///     fn hitpoints(&self) -> i32 {
+///         self.hp
+///     }
+/// }
+///
+/// fn total_hitpoints(objects: Vec<Gd<RefCounted>>) -> i32 {
+///     objects
+///         .into_iter()
+///         .filter_map(|obj| obj.try_dynify::<dyn Health>().ok())
+///         .map(|obj| obj.dyn_bind().hitpoints())
+///         .sum()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
+    translate(input, class::attribute_godot_dyn)
+}
+
+/// Derive macro for [`GodotConvert`](../builtin/meta/trait.GodotConvert.html) on structs and enums.
 ///
 /// This derive macro also derives [`ToGodot`](../builtin/meta/trait.ToGodot.html) and [`FromGodot`](../builtin/meta/trait.FromGodot.html).
 ///
 /// # Choosing a Via type
 ///
 /// To specify the `Via` type that your type should be converted to, you must use the `godot` attribute.
-/// There are currently two modes supported.
+/// There are currently three modes supported.
 ///
 /// ## `transparent`
 ///
@@ -705,12 +1083,16 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// type.
 ///
 /// The types you can use this with currently are:
-/// - `GString`
+/// - `GString`, `StringName`
 /// - `i8`, `i16`, `i32`, `i64`
 /// - `u8`, `u16`, `u32`
 ///
 /// When using one of the integer types, each variant of the enum will be converted into its discriminant.
 ///
+/// When using one of the string types, each variant will be converted into its name. The name can be changed with
+/// `#[godot(rename = "...")]` on the variant. Converting an unknown name fails with a `ConvertError` that lists the valid names.
+/// With `#[derive(Var, Export)]`, such enums are exported as a string dropdown in the editor.
+///
 /// ### Examples
 ///
 /// ```no_run
@@ -731,6 +1113,19 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// ```no_run
 /// use godot::prelude::*;
 /// #[derive(GodotConvert)]
+/// #[godot(via = StringName)]
+/// enum Difficulty {
+///     Easy,
+///     #[godot(rename = "Very Hard")]
+///     Hard,
+/// }
+///
+/// assert_eq!(Difficulty::Hard.to_godot(), StringName::from("Very Hard"));
+/// ```
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// #[derive(GodotConvert)]
 /// #[godot(via = i64)]
 /// enum MyEnum {
 ///     A,
@@ -759,6 +1154,158 @@ pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## `dictionary`
+///
+/// Structs with named fields and enums whose variants carry data can be converted to and from a [`Dictionary`](../builtin/struct.Dictionary.html)
+/// with `#[godot(dictionary)]`. This is useful for passing plain data between Rust and GDScript.
+///
+/// - For structs, each field is stored under its name.
+/// - For enums, the variant name is stored under the key `"type"`, which can be changed with `#[godot(dictionary, tag = "key")]`.
+///   Fields of struct variants are stored under their names, fields of tuple variants under their index (`"0"`, `"1"`, ...).
+///
+/// Each field type must itself implement `ToGodot` and `FromGodot`. Additional keys in the dictionary are ignored.
+///
+/// The following attributes are supported on fields:
+/// - `#[godot(rename = "key")]`: stores the field under a different key. On enum variants, this changes the variant name stored in the tag.
+/// - `#[godot(default)]` or `#[godot(default = expr)]`: if the key is absent when converting from Godot, uses `Default::default()`
+///   or the given expression, instead of failing.
+/// - `#[godot(skip)]`: the field is not stored in the dictionary; when converting from Godot, it is initialized with `Default::default()`
+///   (or the expression given by `default = expr`).
+///
+/// When converting from Godot, a missing key or a value of the wrong type results in a [`ConvertError`](../builtin/meta/struct.ConvertError.html)
+/// naming the type and the offending key.
+///
+/// ### Examples
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// struct PlayerStats {
+///     name: GString,
+///     #[godot(rename = "hp")]
+///     hitpoints: i64,
+///     #[godot(default)]
+///     level: i64,
+///     #[godot(skip)]
+///     cache: Vec<u8>,
+/// }
+///
+/// let stats = PlayerStats {
+///     name: "Godette".into(),
+///     hitpoints: 100,
+///     level: 3,
+///     cache: vec![],
+/// };
+///
+/// let dict = stats.to_godot();
+/// assert_eq!(dict.get("hp"), Some(100i64.to_variant()));
+/// assert!(dict.get("cache").is_none());
+/// ```
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary, tag = "kind")]
+/// enum Shape {
+///     Circle { radius: f32 },
+///     Rect(Vector2),
+///     #[godot(rename = "none")]
+///     Empty,
+/// }
+///
+/// let dict = Shape::Circle { radius: 2.0 }.to_godot();
+/// assert_eq!(dict.get("kind"), Some("Circle".to_variant()));
+/// assert_eq!(dict.get("radius"), Some(2.0f32.to_variant()));
+///
+/// let dict = Shape::Rect(Vector2::new(1.0, 2.0)).to_godot();
+/// assert_eq!(dict.get("0"), Some(Vector2::new(1.0, 2.0).to_variant()));
+/// ```
+///
+/// No field may be stored under the tag key, as it would clash with the variant name:
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// enum Item {
+///     Weapon {
+///         #[godot(rename = "type")]
+///         category: GString,
+///     },
+/// }
+/// ```
+///
+/// Likewise, two fields cannot be stored under the same key:
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// struct Player {
+///     name: GString,
+///     #[godot(rename = "name")]
+///     nickname: GString,
+/// }
+/// ```
+///
+/// ## Enums registered in a class
+///
+/// With an integer `via` type, an enum can additionally be registered inside a class with `class = <ClassName>`. This corresponds to
+/// GDScript's `enum` declarations: the variants are accessible as `ClassName.EnumName.VARIANT`, and parameters, return values and
+/// properties of this type are documented as `ClassName.EnumName` instead of `int`.
+///
+/// Adding the `bitfield` key registers the enum as a bitfield, whose flags can be combined.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[export]
+///     state: State,
+/// }
+///
+/// #[derive(GodotConvert, Var, Export, Default)]
+/// #[godot(via = i64, class = Player)]
+/// enum State {
+///     #[default]
+///     Idle,
+///     Running,
+/// }
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = u32, class = Player, bitfield)]
+/// enum Ability {
+///     Jump = 1,
+///     Swim = 2,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     // Shows up as `set_state(state: Player.State)` in the editor docs.
+///     #[func]
+///     fn set_state(&mut self, state: State) {
+///         self.state = state;
+///     }
+/// }
+/// ```
+///
+/// The class must be a user-defined class, i.e. declared with `#[derive(GodotClass)]`. Engine classes cannot host user enums:
+/// ```compile_fail
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = i64, class = Node)]
+/// enum State {
+///     Idle,
+///     Running,
+/// }
+/// ```
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -783,7 +1330,8 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
 
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
-/// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
+/// The function is registered with the runner declared by `godot::test::integration_tests!`. It may take no parameters, or a single
+/// `ctx: &TestContext` parameter. The keys `#[itest(skip)]` and `#[itest(focus)]` exclude the test, or run only focused tests.
 #[proc_macro_attribute]
 pub fn itest(meta: TokenStream, input: TokenStream) -> TokenStream {
     translate_meta("itest", meta, input, itest::attribute_itest)
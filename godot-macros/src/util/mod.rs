@@ -31,16 +31,6 @@ pub fn class_name_obj(class: &impl ToTokens) -> TokenStream {
     quote! { <#class as ::godot::obj::GodotClass>::class_name() }
 }
 
-pub fn property_variant_type(property_type: &impl ToTokens) -> TokenStream {
-    let property_type = property_type.to_token_stream();
-    quote! { <<#property_type as ::godot::builtin::meta::GodotConvert>::Via as ::godot::builtin::meta::GodotType>::Ffi::variant_type() }
-}
-
-pub fn property_variant_class_name(property_type: &impl ToTokens) -> TokenStream {
-    let property_type = property_type.to_token_stream();
-    quote! { <<#property_type as ::godot::builtin::meta::GodotConvert>::Via as ::godot::builtin::meta::GodotType>::class_name() }
-}
-
 pub fn bail_fn<R, T>(msg: impl AsRef<str>, tokens: T) -> ParseResult<R>
 where
     T: Spanned,
@@ -202,7 +192,7 @@ pub(crate) fn validate_trait_impl_virtual<'a>(
     })
 }
 
-fn validate_self(original_impl: &venial::Impl, attr: &str) -> ParseResult<Ident> {
+pub(crate) fn validate_self(original_impl: &venial::Impl, attr: &str) -> ParseResult<Ident> {
     if let Some(segment) = extract_typename(&original_impl.self_ty) {
         if segment.generic_args.is_none() {
             Ok(segment.ident)
@@ -263,7 +253,7 @@ pub(crate) fn extract_cfg_attrs(
 #[cfg(before_api = "4.3")]
 pub fn make_virtual_tool_check() -> TokenStream {
     quote! {
-        if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+        if ::godot::private::is_class_inactive(&Self::__config()) {
             return None;
         }
     }
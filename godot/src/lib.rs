@@ -161,6 +161,17 @@
 //!   to explicitly opt-in to any instabilities or rough edges that may result. Due to a limitation in Godot, it might currently not
 //!   work Firefox browser.<br><br>
 //!
+//! * **`register-docs`**
+//!
+//!   Collect `///` doc comments on classes, `#[var]`/`#[export]` fields, `#[func]` methods, signals and constants, and register them
+//!   as class reference with the editor help, so that they show up in Godot's built-in documentation (F1). Requires Godot 4.3 or later;
+//!   for older versions, the docs are collected but not registered.<br><br>
+//!
+//! * **`testing`**
+//!
+//!   Enables the `godot::test` module, to write integration tests with `#[itest]` that run inside a headless Godot instance. Typically enabled
+//!   for a dedicated test crate or through a feature of your own, rather than in the shipped extension.<br><br>
+//!
 //! # Public API
 //!
 //! Some symbols in the API are not intended for users, however Rust's visibility feature is not strong enough to express that in all cases
@@ -178,7 +189,7 @@
 //! This allows us to decide whether it fits the scope of the library and to design proper APIs for it.
 
 #[doc(inline)]
-pub use godot_core::{builtin, engine, log, obj};
+pub use godot_core::{builtin, engine, log, obj, script, task};
 
 #[doc(hidden)]
 pub use godot_core::sys;
@@ -204,12 +215,15 @@ pub mod init {
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
     pub use godot_core::property;
-    pub use godot_macros::{godot_api, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
 }
 
-/// Testing facilities (unstable).
-#[doc(hidden)]
+/// Integration tests running inside Godot.
+///
+/// Only available with the `testing` Cargo feature.
+#[cfg(feature = "testing")]
 pub mod test {
+    pub use godot_core::testing::*;
     pub use godot_macros::{bench, itest};
 }
 
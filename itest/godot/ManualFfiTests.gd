@@ -327,6 +327,12 @@ func test_gd_self_obj_succeeds():
 
 	assert_eq(gd_self_obj.succeed_at_updating_internal_value(10), 10)
 
+func test_func_varargs():
+	var obj := FuncObj.new()
+	assert_eq(obj.varargs_join(", ", "a", 1, [2]), "a, 1, [2]")
+	assert_eq(obj.varargs_join(", "), "")
+	assert_eq(FuncObj.varargs_count(1, 2, 3), 3)
+
 func sample_func():
 	pass
 
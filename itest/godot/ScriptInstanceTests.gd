@@ -73,3 +73,22 @@ func test_script_instance_to_string():
 	var object = create_script_instance()
 
 	assert_eq(object.to_string(), "script instance to string")
+
+
+func test_script_instance_property_revert():
+	var object = create_script_instance()
+
+	assert(object.property_can_revert("script_property_b"))
+	assert(!object.property_can_revert("script_property_a"))
+	assert_eq(object.property_get_revert("script_property_b"), false)
+
+
+func test_script_instance_notification():
+	var object = create_script_instance()
+
+	object.notification(12345)
+	assert_eq(object.script_last_notification, [12345, false])
+
+	if Engine.get_version_info().hex >= 0x040200:
+		object.notification(12345, true)
+		assert_eq(object.script_last_notification, [12345, true])
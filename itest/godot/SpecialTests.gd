@@ -55,3 +55,22 @@ func test_collision_object_2d_input_event():
 
 	window.queue_free()
 
+# Test that `godot::task::next_frame()` and `godot::task::timer()` resolve as frames and time pass. Like the test above, this needs
+# frames to pass during the test. The task runtime is only available from Godot 4.2 on.
+func test_async_next_frame_and_timer():
+	if not ClassDB.class_exists("AsyncTestTasks"):
+		return
+
+	var tasks = ClassDB.instantiate("AsyncTestTasks")
+	var tree: SceneTree = Engine.get_main_loop()
+
+	tasks.start_next_frame()
+	tasks.start_timer(0.2)
+	assert_that(not tasks.is_completed("next_frame"), "next_frame() should be pending")
+	assert_that(not tasks.is_completed("timer"), "timer() should be pending")
+
+	await tree.process_frame
+	assert_that(tasks.is_completed("next_frame"), "next_frame() should resolve on the next frame")
+
+	await tree.create_timer(0.3).timeout
+	assert_that(tasks.is_completed("timer"), "timer() should resolve once the time has passed")
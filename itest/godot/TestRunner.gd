@@ -64,20 +64,24 @@ func _ready():
 			if method_name.begins_with("test_"):
 				gdscript_tests.push_back(await suite.run_test(suite, method_name))
 
+	# Accessed by property_template_test.rs through the test context.
 	var property_tests = load("res://gen/GenPropertyTests.gd").new()
+	property_tests.name = "PropertyTests"
+	get_tree().root.add_child(property_tests)
 
 	var success: bool = rust_runner.run_all_tests(
 		gdscript_tests,
 		gdscript_suites.size(),
 		allow_focus,
 		self,
-		filters,
-		property_tests
+		filters
 	)
 
 	if success:
 		rust_runner.run_all_benchmarks(self)
 
+	rust_runner.free()
+
 	var exit_code: int = 0 if success else 1
 	get_tree().quit(exit_code)
 
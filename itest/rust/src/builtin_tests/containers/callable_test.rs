@@ -161,10 +161,12 @@ impl CallableRefcountTest {
 #[cfg(since_api = "4.2")]
 mod custom_callable {
     use super::*;
-    use crate::framework::assert_eq_self;
-    use godot::builtin::Dictionary;
+    use crate::framework::{assert_eq_self, suppress_godot_print};
+    use godot::builtin::{Dictionary, Vector2};
+    use std::cell::Cell;
     use std::fmt;
     use std::hash::Hash;
+    use std::rc::Rc;
     use std::sync::{Arc, Mutex};
 
     #[itest]
@@ -197,6 +199,125 @@ mod custom_callable {
         assert_ne!(a, c, "same function, different instance -> not equal");
     }
 
+    #[itest]
+    fn callable_from_typed_fn() {
+        let callable = Callable::from_typed_fn("concat", |a: i32, b: GString| -> GString {
+            format!("{a}{b}").into()
+        });
+
+        assert!(callable.is_valid());
+        assert!(callable.is_custom());
+
+        let result = callable.callv(varray![7, "up"]);
+        assert_eq!(result, "7up".to_variant());
+
+        let unit = Callable::from_typed_fn("unit", || {});
+        assert_eq!(unit.callv(varray![]), Variant::nil());
+    }
+
+    #[itest]
+    fn callable_from_typed_fn_mismatch() {
+        let callable = Callable::from_typed_fn("double", |a: i32| a * 2);
+
+        let prev_print_level = godot::private::set_error_print_level(0);
+        let wrong_type = callable.callv(varray!["string"]);
+        let wrong_count = callable.callv(varray![1, 2]);
+        godot::private::set_error_print_level(prev_print_level);
+
+        assert_eq!(wrong_type, Variant::nil());
+        assert_eq!(wrong_count, Variant::nil());
+        assert_eq!(callable.callv(varray![21]), 42.to_variant());
+    }
+
+    #[itest]
+    fn callable_from_local_fn() {
+        let node = Node2D::new_alloc();
+        let captured = node.clone();
+
+        let untyped = Callable::from_local_fn("get_position", move |_args: &[&Variant]| {
+            Ok(captured.get_position().to_variant())
+        });
+
+        let mut captured = node.clone();
+        let typed = Callable::from_local_typed_fn("set_position", move |x: f32, y: f32| {
+            captured.set_position(Vector2::new(x, y));
+        });
+
+        typed.callv(varray![1.0, 2.0]);
+        assert_eq!(
+            untyped.callv(varray![]),
+            Vector2::new(1.0, 2.0).to_variant()
+        );
+
+        node.free();
+    }
+
+    #[itest]
+    fn callable_from_local_fn_other_thread() {
+        // Non-Send capture: the closures must neither run nor be dropped on another thread.
+        let calls = Rc::new(Cell::new(0));
+
+        let captured = calls.clone();
+        let untyped = Callable::from_local_fn("count", move |_args: &[&Variant]| {
+            captured.set(captured.get() + 1);
+            Ok(Variant::nil())
+        });
+
+        let captured = calls.clone();
+        let typed = Callable::from_local_typed_fn("count_typed", move |n: i32| {
+            captured.set(captured.get() + n);
+        });
+
+        let mut callables = Some(SendCallables(untyped, typed));
+        suppress_godot_print(|| {
+            let callables = callables.take().unwrap();
+
+            let results = std::thread::spawn(move || {
+                let (untyped, typed) = callables.into_inner();
+                let results = (untyped.callv(varray![]), typed.callv(varray![5]));
+
+                // Last references are released here, on the worker thread.
+                drop((untyped, typed));
+                results
+            })
+            .join()
+            .unwrap();
+
+            assert_eq!(results, (Variant::nil(), Variant::nil()));
+        });
+
+        assert_eq!(calls.get(), 0, "closures must not run on other thread");
+        assert_eq!(
+            Rc::strong_count(&calls),
+            3,
+            "closures must be leaked, not dropped, on other thread"
+        );
+
+        // On the owning thread, calls and drops work as usual.
+        let captured = calls.clone();
+        let local = Callable::from_local_typed_fn("count_local", move |n: i32| {
+            captured.set(captured.get() + n);
+        });
+        local.callv(varray![5]);
+        drop(local);
+
+        assert_eq!(calls.get(), 5);
+        assert_eq!(Rc::strong_count(&calls), 3);
+    }
+
+    /// Moves callables to another thread, to test thread confinement of the closures inside.
+    struct SendCallables(Callable, Callable);
+
+    impl SendCallables {
+        // Method instead of destructuring, so the closure captures the whole (Send) struct, not its fields.
+        fn into_inner(self) -> (Callable, Callable) {
+            (self.0, self.1)
+        }
+    }
+
+    // SAFETY: Callable itself is reference-counted in a thread-safe way; the test checks that the Rust closures are not accessed.
+    unsafe impl Send for SendCallables {}
+
     fn sum(args: &[&Variant]) -> Result<Variant, ()> {
         let sum: i32 = args.iter().map(|arg| arg.to::<i32>()).sum();
         Ok(sum.to_variant())
@@ -8,14 +8,14 @@
 use std::collections::{HashMap, HashSet};
 
 use godot::builtin::meta::{FromGodot, ToGodot};
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, Variant, VariantDictionary};
 use godot::sys::GdextBuild;
 
 use crate::framework::{expect_panic, itest};
 
 #[itest]
 fn dictionary_default() {
-    assert_eq!(Dictionary::default().len(), 0);
+    assert_eq!(Dictionary::new().len(), 0);
 }
 
 #[itest]
@@ -25,13 +25,13 @@ fn dictionary_new() {
 
 #[itest]
 fn dictionary_from_iterator() {
-    let dictionary = Dictionary::from_iter([("foo", 1), ("bar", 2)]);
+    let dictionary = VariantDictionary::from_iter([("foo", 1), ("bar", 2)]);
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
     assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
 
-    let dictionary = Dictionary::from_iter([(1, "foo"), (2, "bar")]);
+    let dictionary = VariantDictionary::from_iter([(1, "foo"), (2, "bar")]);
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get(1), Some("foo".to_variant()), "key = 1");
@@ -101,7 +101,7 @@ fn dictionary_clone() {
 
     #[allow(clippy::redundant_clone)]
     let clone = dictionary.clone();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("final", 4);
+    VariantDictionary::from_variant(&clone.get("bar").unwrap()).insert("final", 4);
     assert_eq!(subdictionary.get("final"), Some(4.to_variant()));
 }
 
@@ -147,7 +147,7 @@ fn dictionary_duplicate_deep() {
         "bar": subdictionary.clone()
     };
     let clone = dictionary.duplicate_deep();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
+    VariantDictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(true.to_variant()),
@@ -166,7 +166,7 @@ fn dictionary_duplicate_shallow() {
         "bar": subdictionary.clone()
     };
     let mut clone = dictionary.duplicate_shallow();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
+    VariantDictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(4.to_variant()),
@@ -667,3 +667,116 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn dictionary_typed_access() {
+    let mut dict = Dictionary::<GString, i64>::default();
+    assert!(dict.is_empty());
+
+    dict.set("one", 1_i64);
+    assert_eq!(dict.insert("two", 2_i64), None);
+    assert_eq!(dict.insert("two", 22_i64), Some(2));
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.get("one"), Some(1));
+    assert_eq!(dict.get("three"), None);
+    assert!(dict.contains_key("two"));
+    assert_eq!(dict.find_key_by_value(22_i64), Some(GString::from("two")));
+
+    assert_eq!(dict.remove("one"), Some(1));
+    assert_eq!(dict.remove("one"), None);
+    assert_eq!(dict.len(), 1);
+}
+
+#[itest]
+fn dictionary_typed_iter() {
+    let dict: Dictionary<GString, i64> = [("a", 1_i64), ("b", 2), ("c", 3)].into_iter().collect();
+
+    let map: HashMap<GString, i64> = dict.iter_shared().collect();
+    let expected: HashMap<GString, i64> =
+        HashMap::from([("a".into(), 1), ("b".into(), 2), ("c".into(), 3)]);
+    assert_eq!(map, expected);
+
+    let keys: HashSet<GString> = dict.keys_shared().collect();
+    let expected: HashSet<GString> = HashSet::from(["a".into(), "b".into(), "c".into()]);
+    assert_eq!(keys, expected);
+
+    assert_eq!(format!("{dict}"), "{ a: 1, b: 2, c: 3 }");
+}
+
+#[itest]
+fn dictionary_typed_duplicate() {
+    let dict: Dictionary<i64, GString> = [(1_i64, "one"), (2, "two")].into_iter().collect();
+
+    let mut copy = dict.duplicate_shallow();
+    copy.set(3_i64, "three");
+    assert_eq!(copy.len(), 3);
+    assert_eq!(dict.len(), 2);
+
+    let mut merged = dict.duplicate_deep();
+    merged.extend_dictionary(copy, false);
+    assert_eq!(merged.get(3_i64), Some(GString::from("three")));
+}
+
+// Before Godot 4.4, typed dictionaries are untyped in the engine; conversion validates each entry.
+#[itest]
+#[cfg(not(since_api = "4.4"))]
+fn dictionary_typed_from_variant() {
+    let valid = dict! { "a": 1, "b": 2 };
+    let typed = valid.to_variant().try_to::<Dictionary<GString, i64>>();
+    assert_eq!(typed.expect("valid entries").get("b"), Some(2));
+
+    let bad_value = dict! { "a": 1, "b": "two" };
+    let err = bad_value
+        .to_variant()
+        .try_to::<Dictionary<GString, i64>>()
+        .expect_err("value of wrong type");
+    assert_eq!(err.value(), Some(&"two".to_variant()));
+
+    let bad_key = dict! { "a": 1, 2: 2 };
+    bad_key
+        .to_variant()
+        .try_to::<Dictionary<GString, i64>>()
+        .expect_err("key of wrong type");
+
+    // Untyped values accept anything.
+    let mixed = bad_value
+        .to_variant()
+        .try_to::<Dictionary<GString, Variant>>();
+    assert_eq!(mixed.expect("untyped values").len(), 2);
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dictionary_typed_in_engine() {
+    let typed = Dictionary::<GString, i64>::default();
+    assert!(typed.as_inner().is_typed());
+
+    let untyped = Dictionary::new();
+    assert!(!untyped.as_inner().is_typed());
+}
+
+// Since Godot 4.4, conversion requires the engine-side key and value types to match, like for arrays.
+#[itest]
+#[cfg(since_api = "4.4")]
+fn dictionary_typed_from_variant() {
+    let typed: Dictionary<GString, i64> = [("a", 1_i64), ("b", 2)].into_iter().collect();
+    let roundtrip = typed.to_variant().try_to::<Dictionary<GString, i64>>();
+    assert_eq!(roundtrip.expect("same type").get("b"), Some(2));
+
+    // Entries would convert, but the dictionary is untyped in the engine.
+    let untyped = dict! { "a": 1, "b": 2 };
+    untyped
+        .to_variant()
+        .try_to::<Dictionary<GString, i64>>()
+        .expect_err("untyped dictionary");
+
+    typed
+        .to_variant()
+        .try_to::<Dictionary<GString, Variant>>()
+        .expect_err("different value type");
+    typed
+        .to_variant()
+        .try_to::<Dictionary>()
+        .expect_err("typed dictionary is not untyped");
+}
@@ -6,6 +6,10 @@
  */
 
 use std::cell::Cell;
+#[cfg(since_api = "4.2")]
+use std::sync::atomic::{AtomicI64, Ordering};
+#[cfg(since_api = "4.2")]
+use std::sync::Arc;
 
 use godot::builtin::meta::ToGodot;
 use godot::builtin::{Callable, GString, Signal, StringName, Variant};
@@ -149,3 +153,132 @@ fn connect_signal() {
 
     receiver.free();
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed signals
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct TypedEmitter {
+    last_received: i64,
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl TypedEmitter {
+    #[signal]
+    fn value_changed(value: i64);
+
+    #[signal]
+    fn renamed(source: Gd<Self>, name: GString);
+
+    #[func]
+    fn set_value(&mut self, value: i64) {
+        self.signals().value_changed().emit(value);
+    }
+
+    #[func]
+    fn on_value_changed(&mut self, value: i64) {
+        self.last_received = value;
+    }
+}
+
+#[itest]
+fn typed_signal_name() {
+    let mut emitter = TypedEmitter::new_alloc();
+
+    let name = emitter.bind_mut().signals().value_changed().name();
+    assert_eq!(name, StringName::from("value_changed"));
+
+    let signal = emitter.bind_mut().signals().renamed().to_untyped();
+    assert_eq!(signal.name(), StringName::from("renamed"));
+    assert_eq!(signal.object_id(), Some(emitter.instance_id()));
+
+    emitter.free();
+}
+
+#[itest]
+fn typed_signal_emit_untyped_receiver() {
+    let mut emitter = TypedEmitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    emitter
+        .bind_mut()
+        .signals()
+        .value_changed()
+        .connect_callable(receiver.callable("receive_1_arg"));
+    emitter.bind_mut().set_value(987);
+
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn typed_signal_connect_self() {
+    let mut emitter = TypedEmitter::new_alloc();
+
+    emitter
+        .bind_mut()
+        .signals()
+        .value_changed()
+        .connect_self(TypedEmitter::on_value_changed);
+
+    // Handler re-borrows the emitting object, while emit() is called from a &mut self method.
+    emitter.bind_mut().set_value(42);
+    assert_eq!(emitter.bind().last_received, 42);
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn typed_signal_connect_obj() {
+    let mut emitter = TypedEmitter::new_alloc();
+    let mut other = TypedEmitter::new_alloc();
+
+    emitter.bind_mut().signals().value_changed().connect_obj(
+        &other,
+        |this: &mut TypedEmitter, value: i64| {
+            this.last_received = value * 2;
+        },
+    );
+
+    emitter.bind_mut().signals().value_changed().emit(21);
+    assert_eq!(other.bind().last_received, 42);
+    assert_eq!(emitter.bind().last_received, 0);
+
+    other.free();
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn typed_signal_connect_closure() {
+    let mut emitter = TypedEmitter::new_alloc();
+    let received = Arc::new(AtomicI64::new(0));
+
+    let received_clone = received.clone();
+    emitter.bind_mut().signals().renamed().connect(
+        move |source: Gd<TypedEmitter>, name: GString| {
+            assert_eq!(name, GString::from("new name"));
+            received_clone.store(source.instance_id().to_i64(), Ordering::SeqCst);
+        },
+    );
+
+    let source = emitter.clone();
+    emitter
+        .bind_mut()
+        .signals()
+        .renamed()
+        .emit(source, GString::from("new name"));
+
+    assert_eq!(
+        received.load(Ordering::SeqCst),
+        emitter.instance_id().to_i64()
+    );
+
+    emitter.free();
+}
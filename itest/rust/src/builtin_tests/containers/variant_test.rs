@@ -355,7 +355,7 @@ fn variant_type_correct() {
         VariantType::Array
     );
     assert_eq!(
-        Dictionary::default().to_variant().get_type(),
+        Dictionary::new().to_variant().get_type(),
         VariantType::Dictionary
     );
 }
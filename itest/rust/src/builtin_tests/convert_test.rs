@@ -5,13 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{HashMap, HashSet};
+
 use godot::builtin::meta::{ConvertError, FromGodot, GodotConvert, ToGodot};
 use godot::builtin::{
-    dict, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
+    array, dict, varray, Array, Dictionary, GString, Variant, VariantArray, Vector2, Vector2Axis,
 };
 use godot::engine::{Node, Resource};
 use godot::obj::{Gd, NewAlloc};
 
+use crate::common::roundtrip;
 use crate::framework::itest;
 
 /// Ensure conversions we define have an associated value, and no underlying rust cause.
@@ -232,3 +235,99 @@ fn custom_convert_error_from_variant() {
         format!("{:?}", i64::MAX)
     );
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust collections
+
+#[itest]
+fn collection_roundtrip() {
+    roundtrip(vec![1, 2, 3]);
+    roundtrip(Vec::<GString>::new());
+    roundtrip([4i64, 5, 6]);
+    roundtrip(HashSet::from([GString::from("a"), GString::from("b")]));
+    roundtrip(HashMap::from([
+        (GString::from("a"), 1i64),
+        (GString::from("b"), 2i64),
+    ]));
+    roundtrip((7i64,));
+    roundtrip((7i64, GString::from("x"), Vector2::new(1.0, 2.0)));
+    roundtrip(vec![(1i64, true), (2i64, false)]);
+}
+
+#[itest]
+fn collection_via_types() {
+    let array: Array<i64> = vec![1i64, 2, 3].to_godot();
+    assert_eq!(array, array![1, 2, 3]);
+
+    let tuple: VariantArray = (1i64, GString::from("two")).to_godot();
+    assert_eq!(tuple, varray![1, "two"]);
+
+    let map: Dictionary = HashMap::from([(1i64, GString::from("one"))]).to_godot();
+    assert_eq!(map, dict! { 1: "one" });
+}
+
+#[itest]
+fn collection_element_errors() {
+    let err = array![1i64, 300, 3]
+        .to_variant()
+        .try_to::<Vec<u8>>()
+        .expect_err("300 does not fit into u8");
+    assert!(
+        err.to_string().starts_with("element 1:"),
+        "unexpected error: {err}"
+    );
+    assert_eq!(
+        format!("{:?}", err.value().unwrap()),
+        format!("{:?}", 300.to_variant())
+    );
+
+    let err = varray![1, "two"]
+        .to_variant()
+        .try_to::<(i64, i64)>()
+        .expect_err("string is not an integer");
+    assert!(
+        err.to_string().starts_with("element 1:"),
+        "unexpected error: {err}"
+    );
+
+    let err = dict! { "a": 1, 2: 2 }
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("integer key is not a string");
+    assert!(
+        err.to_string().starts_with("key 2:"),
+        "unexpected error: {err}"
+    );
+
+    let err = dict! { "a": "one" }
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("string value is not an integer");
+    assert!(
+        err.to_string().starts_with("value for key a:"),
+        "unexpected error: {err}"
+    );
+}
+
+#[itest]
+fn collection_length_errors() {
+    let err = array![1i64, 2]
+        .to_variant()
+        .try_to::<[i64; 3]>()
+        .expect_err("array is too short");
+    assert!(
+        err.to_string()
+            .starts_with("expected array of length 3, got length 2"),
+        "unexpected error: {err}"
+    );
+
+    let err = varray![1, 2, 3]
+        .to_variant()
+        .try_to::<(i64, i64)>()
+        .expect_err("array is too long");
+    assert!(
+        err.to_string()
+            .starts_with("expected array of length 2, got length 3"),
+        "unexpected error: {err}"
+    );
+}
@@ -35,6 +35,8 @@ mod string {
 
 mod script {
     mod script_instance_tests;
+    #[cfg(since_api = "4.2")]
+    mod script_language_test;
 }
 
 mod color_test;
@@ -8,16 +8,25 @@
 use std::ffi::c_void;
 
 use godot::builtin::meta::{ClassName, FromGodot, MethodInfo, PropertyInfo, ToGodot};
-use godot::builtin::{GString, StringName, Variant, VariantType};
+use godot::builtin::{varray, GString, StringName, Variant, VariantType};
 use godot::engine::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
 use godot::engine::{
-    create_script_instance, IScriptExtension, Object, Script, ScriptExtension, ScriptInstance,
-    ScriptLanguage,
+    create_script_instance, IScriptExtension, Object, RefCounted, Script, ScriptExtension,
+    ScriptInstance, ScriptLanguage, SiMut,
 };
-use godot::obj::{Base, Gd, WithBaseField};
+use godot::obj::{Base, Gd, NewGd, WithBaseField};
 use godot::register::{godot_api, GodotClass};
 use godot::sys;
 
+#[cfg(since_api = "4.2")]
+use godot::builtin::Dictionary;
+#[cfg(since_api = "4.2")]
+use godot::engine::{Engine, Node, Os, ScriptPlaceholders};
+#[cfg(since_api = "4.2")]
+use godot::obj::{EngineBitfield, NewAlloc};
+
+use crate::framework::itest;
+
 #[derive(GodotClass)]
 #[class(base = ScriptExtension, init)]
 struct TestScript {
@@ -26,8 +35,28 @@ struct TestScript {
 
 #[godot_api]
 impl IScriptExtension for TestScript {
-    unsafe fn instance_create(&self, _for_object: Gd<Object>) -> *mut c_void {
-        create_script_instance(TestScriptInstance::new(self.to_gd().upcast()))
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        create_script_instance(TestScriptInstance::new(self.to_gd().upcast()), for_object)
+    }
+
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+}
+
+/// Same as [`TestScript`], but stores the instance in a `Box`.
+#[derive(GodotClass)]
+#[class(base = ScriptExtension, init)]
+struct BoxedTestScript {
+    base: Base<ScriptExtension>,
+}
+
+#[godot_api]
+impl IScriptExtension for BoxedTestScript {
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        let instance = Box::new(TestScriptInstance::new(self.to_gd().upcast()));
+
+        create_script_instance(instance, for_object)
     }
 
     fn can_instantiate(&self) -> bool {
@@ -35,9 +64,63 @@ impl IScriptExtension for TestScript {
     }
 }
 
+/// Script whose instances are placeholders, as created in the editor for scripts that are not in tool mode.
+#[cfg(since_api = "4.2")]
+#[derive(GodotClass)]
+#[class(base = ScriptExtension, init)]
+struct PlaceholderTestScript {
+    placeholders: ScriptPlaceholders,
+    base: Base<ScriptExtension>,
+}
+
+#[cfg(since_api = "4.2")]
+#[godot_api]
+impl IScriptExtension for PlaceholderTestScript {
+    // Godot only calls `placeholder_instance_create()` in the editor, so regular instances are placeholders here.
+    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
+        let language = Engine::singleton()
+            .get_script_language(0)
+            .expect("GDScript language is registered");
+
+        self.placeholders.create(
+            language,
+            self.to_gd().upcast(),
+            for_object,
+            &[placeholder_property("placeholder_a")],
+            &[(StringName::from("placeholder_a"), 10.to_variant())],
+        )
+    }
+
+    unsafe fn placeholder_erased(&mut self, placeholder: *mut c_void) {
+        self.placeholders.erase(placeholder);
+    }
+
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+
+    fn is_placeholder_fallback_enabled(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(since_api = "4.2")]
+fn placeholder_property(name: &str) -> PropertyInfo {
+    PropertyInfo {
+        variant_type: VariantType::Int,
+        property_name: StringName::from(name),
+        class_name: ClassName::none(),
+        hint: PropertyHint::NONE,
+        hint_string: GString::new(),
+        usage: PropertyUsageFlags::DEFAULT,
+    }
+}
+
 struct TestScriptInstance {
     /// A field to store the value of the `script_property_b` during tests.
     script_property_b: bool,
+    /// The last notification received, as `[what, reversed]`.
+    last_notification: Option<(i32, bool)>,
     prop_list: Vec<PropertyInfo>,
     method_list: Vec<MethodInfo>,
     script: Gd<Script>,
@@ -48,6 +131,7 @@ impl TestScriptInstance {
         Self {
             script,
             script_property_b: false,
+            last_notification: None,
             prop_list: vec![PropertyInfo {
                 variant_type: VariantType::Int,
                 property_name: StringName::from("script_property_a"),
@@ -99,9 +183,9 @@ impl ScriptInstance for TestScriptInstance {
         GString::from("TestScript")
     }
 
-    fn set_property(&mut self, name: StringName, value: &Variant) -> bool {
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
         if name.to_string() == "script_property_b" {
-            self.script_property_b = FromGodot::from_variant(value);
+            this.script_property_b = FromGodot::from_variant(value);
             true
         } else {
             false
@@ -112,6 +196,9 @@ impl ScriptInstance for TestScriptInstance {
         match name.to_string().as_str() {
             "script_property_a" => Some(Variant::from(10)),
             "script_property_b" => Some(Variant::from(self.script_property_b)),
+            "script_last_notification" => self
+                .last_notification
+                .map(|(what, reversed)| varray![what, reversed].to_variant()),
             _ => None,
         }
     }
@@ -125,7 +212,7 @@ impl ScriptInstance for TestScriptInstance {
     }
 
     fn call(
-        &mut self,
+        mut this: SiMut<Self>,
         method: StringName,
         args: &[&Variant],
     ) -> Result<Variant, sys::GDExtensionCallErrorType> {
@@ -137,6 +224,24 @@ impl ScriptInstance for TestScriptInstance {
                 Ok(format!("{arg_a}{arg_b}").to_variant())
             }
 
+            // Calls back into this script instance, then modifies it again.
+            "script_call_reentrant" => {
+                let result = this.base_mut().call(
+                    "script_method_a".into(),
+                    &["re".to_variant(), 1.to_variant()],
+                );
+
+                this.script_property_b = true;
+                Ok(result)
+            }
+
+            "script_set_reentrant" => {
+                this.base_mut()
+                    .set("script_property_b".into(), true.to_variant());
+
+                Ok(Variant::nil())
+            }
+
             _ => Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD),
         }
     }
@@ -182,7 +287,167 @@ impl ScriptInstance for TestScriptInstance {
         None
     }
 
-    fn property_set_fallback(&mut self, _name: StringName, _value: &Variant) -> bool {
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
         false
     }
+
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        match name.to_string().as_str() {
+            "script_property_b" => Some(Variant::from(false)),
+            _ => None,
+        }
+    }
+
+    fn on_notification(mut this: SiMut<Self>, what: i32, reversed: bool) {
+        this.last_notification = Some((what, reversed));
+
+        // Also runs for NOTIFICATION_PREDELETE of RefCounted owners, which must not be resurrected by accessing them.
+        let _ = this.base().get_class();
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn get_class_category(&self) -> Option<PropertyInfo> {
+        Some(PropertyInfo {
+            variant_type: VariantType::Nil,
+            property_name: StringName::from("TestScriptCategory"),
+            class_name: ClassName::none(),
+            hint: PropertyHint::NONE,
+            hint_string: GString::new(),
+            usage: PropertyUsageFlags::CATEGORY,
+        })
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn validate_property(&self, property: &mut PropertyInfo) {
+        // Native property of the owner, which Godot validates through the script instance.
+        if property.property_name == StringName::from("editor_description") {
+            property.hint_string = GString::from("validated by script");
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+fn create_script_owner() -> Gd<RefCounted> {
+    let mut object = RefCounted::new_gd();
+    object.set_script(TestScript::new_gd().to_variant());
+    object
+}
+
+/// Returns the position and info of the property `name` in the object's property list.
+#[cfg(since_api = "4.2")]
+fn find_property(object: &Object, name: &str) -> Option<(usize, Dictionary)> {
+    object
+        .get_property_list()
+        .iter_shared()
+        .enumerate()
+        .find(|(_, property)| property.get_or_nil("name") == name.to_variant())
+}
+
+#[itest]
+fn script_instance_reentrant_call() {
+    let mut object = create_script_owner();
+
+    let result = object.call("script_call_reentrant".into(), &[]);
+
+    assert_eq!(result, "re1".to_variant());
+    assert_eq!(object.get("script_property_b".into()), true.to_variant());
+}
+
+#[itest]
+fn script_instance_boxed_reentrant_call() {
+    let mut object = RefCounted::new_gd();
+    object.set_script(BoxedTestScript::new_gd().to_variant());
+
+    let result = object.call("script_call_reentrant".into(), &[]);
+
+    assert_eq!(result, "re1".to_variant());
+    assert_eq!(object.get("script_property_b".into()), true.to_variant());
+}
+
+#[itest]
+fn script_instance_reentrant_set() {
+    let mut object = create_script_owner();
+
+    object.call("script_set_reentrant".into(), &[]);
+
+    assert_eq!(object.get("script_property_b".into()), true.to_variant());
+}
+
+#[itest]
+#[cfg(since_api = "4.3")]
+fn script_instance_method_argument_count() {
+    let object = create_script_owner();
+
+    assert_eq!(
+        object.get_method_argument_count("script_method_a".into()),
+        2
+    );
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn script_instance_class_category() {
+    // Godot only adds categories to the property list in editor builds.
+    if !Os::singleton().has_feature("editor".into()) {
+        return;
+    }
+
+    let object = create_script_owner();
+
+    let (category_index, category) =
+        find_property(object.upcast_ref(), "TestScriptCategory").expect("class category is listed");
+    let (property_index, _) =
+        find_property(object.upcast_ref(), "script_property_a").expect("property");
+
+    assert_eq!(
+        category.get_or_nil("usage"),
+        (PropertyUsageFlags::CATEGORY.ord() as i64).to_variant()
+    );
+    assert_eq!(category_index + 1, property_index);
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn script_instance_validate_property() {
+    let mut object = Node::new_alloc();
+    object.set_script(TestScript::new_gd().to_variant());
+
+    let (_, property) =
+        find_property(object.upcast_ref(), "editor_description").expect("native property");
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        "validated by script".to_variant()
+    );
+
+    object.free();
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn script_placeholder_create_update_erase() {
+    let script = PlaceholderTestScript::new_gd();
+    let mut object = RefCounted::new_gd();
+    object.set_script(script.to_variant());
+
+    assert_eq!(script.bind().placeholders.len(), 1);
+    assert_eq!(object.get("placeholder_a".into()), 10.to_variant());
+
+    // Godot keeps the values of existing properties and only takes values for new ones.
+    let properties = [
+        placeholder_property("placeholder_a"),
+        placeholder_property("placeholder_b"),
+    ];
+    let values = [(StringName::from("placeholder_b"), 20.to_variant())];
+
+    // SAFETY: `PlaceholderTestScript::placeholder_erased()` forwards to `erase()`.
+    unsafe { script.bind().placeholders.update(&properties, &values) };
+
+    assert_eq!(object.get("placeholder_a".into()), 10.to_variant());
+    assert_eq!(object.get("placeholder_b".into()), 20.to_variant());
+
+    // Destroying the owner destroys the placeholder, which Godot reports through `placeholder_erased()`.
+    drop(object);
+    assert!(script.bind().placeholders.is_empty());
 }
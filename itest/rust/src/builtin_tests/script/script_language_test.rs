@@ -0,0 +1,281 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use godot::builtin::meta::{FromGodot, MethodInfo, PropertyInfo, ToGodot};
+use godot::builtin::{GString, StringName, Variant, VariantType};
+use godot::engine::global::Error;
+use godot::engine::{load, save, Node, Object, Script, ScriptInstance, ScriptLanguage, SiMut};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use godot::script::{script_language, ScriptError, ScriptImpl, ScriptLanguageImpl};
+use godot::sys;
+
+use crate::framework::{itest, suppress_godot_print};
+
+/// Minimal language: an `extends <Class>` line, followed by `var <name> = <int>` lines.
+struct TestLang;
+
+impl ScriptLanguageImpl for TestLang {
+    type Script = TestLangScript;
+
+    const NAME: &'static str = "TestLang";
+    const EXTENSION: &'static str = "tlang";
+
+    fn compile(source: &str, _path: &str) -> Result<TestLangScript, ScriptError> {
+        let mut script = TestLangScript {
+            base_type: StringName::from("Object"),
+            vars: Vec::new(),
+        };
+
+        for (index, line) in source.lines().enumerate() {
+            let line_no = index as i32 + 1;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+
+            match tokens.as_slice() {
+                [] => {}
+                ["extends", base] => script.base_type = StringName::from(*base),
+                ["var", name, "=", value] => {
+                    let value = value
+                        .parse::<i64>()
+                        .map_err(|_| ScriptError::new(line_no, 1, "expected integer"))?;
+
+                    script.vars.push((StringName::from(*name), value));
+                }
+                _ => return Err(ScriptError::new(line_no, 1, "unexpected statement")),
+            }
+        }
+
+        Ok(script)
+    }
+}
+
+struct TestLangScript {
+    base_type: StringName,
+    vars: Vec<(StringName, i64)>,
+}
+
+impl ScriptImpl for TestLangScript {
+    type Instance = TestLangInstance;
+
+    fn create_instance(
+        self: Rc<Self>,
+        script: Gd<Script>,
+        _for_object: Gd<Object>,
+    ) -> Self::Instance {
+        let values = self.vars.iter().cloned().collect();
+
+        TestLangInstance {
+            compiled: self,
+            script,
+            values,
+        }
+    }
+
+    fn base_type(&self) -> StringName {
+        self.base_type.clone()
+    }
+
+    fn properties(&self) -> Vec<PropertyInfo> {
+        self.vars
+            .iter()
+            .map(|(name, _)| PropertyInfo::new_var::<i64>(&name.to_string()))
+            .collect()
+    }
+
+    fn property_default(&self, property: &StringName) -> Option<Variant> {
+        self.vars
+            .iter()
+            .find(|(name, _)| name == property)
+            .map(|(_, value)| value.to_variant())
+    }
+}
+
+struct TestLangInstance {
+    compiled: Rc<TestLangScript>,
+    script: Gd<Script>,
+    values: HashMap<StringName, i64>,
+}
+
+impl ScriptInstance for TestLangInstance {
+    fn class_name(&self) -> GString {
+        GString::from("TestLangScript")
+    }
+
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        match this.values.get_mut(&name) {
+            Some(slot) => {
+                *slot = i64::from_variant(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        self.values.get(&name).map(|value| value.to_variant())
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.compiled.properties()
+    }
+
+    fn get_method_list(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    fn call(
+        _this: SiMut<Self>,
+        _method: StringName,
+        _args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD)
+    }
+
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+
+    fn has_method(&self, _method: StringName) -> bool {
+        false
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_property_type(&self, name: StringName) -> VariantType {
+        if self.values.contains_key(&name) {
+            VariantType::Int
+        } else {
+            VariantType::Nil
+        }
+    }
+
+    fn to_string(&self) -> GString {
+        GString::from("TestLangInstance")
+    }
+
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.clone(), value.to_variant()))
+            .collect()
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        godot::script::language::<TestLang>().expect("TestLang registered")
+    }
+
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+
+    fn on_refcount_incremented(&self) {}
+
+    fn property_get_fallback(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
+
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
+        false
+    }
+}
+
+script_language!(TestLang {
+    language: TestLangLanguage,
+    script: TestLangResource,
+    loader: TestLangLoader,
+    saver: TestLangSaver,
+});
+
+fn make_script(source: &str) -> Gd<Script> {
+    TestLangLanguage::register();
+
+    let mut script = TestLangResource::new_gd().upcast::<Script>();
+    script.set_source_code(source.into());
+    assert_eq!(script.reload(), Error::OK);
+    script
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[itest]
+fn script_language_registered() {
+    TestLangLanguage::register();
+    TestLangLanguage::register(); // Idempotent.
+
+    assert!(godot::script::is_registered::<TestLang>());
+
+    let script = make_script("extends Node");
+    assert_eq!(script.get_language(), godot::script::language::<TestLang>());
+}
+
+#[itest]
+fn script_language_instance_properties() {
+    let script = make_script("extends Node\nvar health = 100\nvar mana = 20");
+    assert!(script.can_instantiate());
+    assert_eq!(script.get_instance_base_type(), StringName::from("Node"));
+
+    let properties = script.get_script_property_list();
+    assert_eq!(properties.len(), 2);
+    assert_eq!(properties.get(0).get("name"), Some("health".to_variant()));
+    assert_eq!(
+        script.get_property_default_value("mana".into()),
+        20.to_variant()
+    );
+
+    let mut node = Node::new_alloc();
+    node.set_script(script.to_variant());
+
+    assert_eq!(node.get("health".into()), 100.to_variant());
+    node.set("health".into(), 75.to_variant());
+    assert_eq!(node.get("health".into()), 75.to_variant());
+
+    node.free();
+}
+
+#[itest]
+fn script_language_compile_error() {
+    TestLangLanguage::register();
+
+    let source = "extends Node\nvar health = lots";
+    assert_eq!(
+        TestLang::compile(source, "").err(),
+        Some(ScriptError::new(2, 1, "expected integer"))
+    );
+
+    let mut script = TestLangResource::new_gd().upcast::<Script>();
+    script.set_source_code(source.into());
+
+    // Reloading prints the compile error.
+    let mut err = Error::OK;
+    suppress_godot_print(|| err = script.reload());
+
+    assert_eq!(err, Error::ERR_PARSE_ERROR);
+    assert!(!script.can_instantiate());
+}
+
+#[itest]
+fn script_language_save_load() {
+    const FILE_NAME: &str = "test_script.tlang";
+    let path = format!("res://{FILE_NAME}");
+    let source = "extends Node\nvar health = 100\n";
+
+    let script = make_script(source);
+    save(script, &path);
+
+    let loaded = load::<Script>(&path);
+    assert_eq!(loaded.get_source_code(), GString::from(source));
+    assert_eq!(loaded.get_class(), GString::from("TestLangResource"));
+    assert!(loaded.can_instantiate());
+
+    let file_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    std::fs::remove_file(file_path.join(FILE_NAME)).expect("remove test script");
+}
@@ -21,13 +21,3 @@ where
 
     assert_eq!(value, back);
 }
-
-/// Signal to the compiler that a value is used (to avoid optimization).
-pub fn bench_used<T: Sized>(value: T) {
-    // The following check would be used to prevent `()` arguments, ensuring that a value from the bench is actually going into the blackbox.
-    // However, we run into this issue, despite no array being used: https://github.com/rust-lang/rust/issues/43408.
-    //   error[E0401]: can't use generic parameters from outer function
-    // sys::static_assert!(std::mem::size_of::<T>() != 0, "returned unit value in benchmark; make sure to use a real value");
-
-    std::hint::black_box(value);
-}
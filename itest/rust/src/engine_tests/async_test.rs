@@ -0,0 +1,259 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::pin;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot::builtin::meta::ToGodot;
+use godot::builtin::{GString, Variant};
+use godot::engine::{Engine, Object, SceneTree};
+use godot::obj::{Gd, NewAlloc};
+use godot::register::{godot_api, GodotClass};
+use godot::task;
+
+use crate::framework::itest;
+
+fn make_emitter() -> Gd<Object> {
+    let mut object = Object::new_alloc();
+    object.add_user_signal("done".into());
+    object.add_user_signal("pair".into());
+    object
+}
+
+#[itest]
+fn async_task_resumes_on_signal() {
+    let mut object = make_emitter();
+    let received = Rc::new(Cell::new(0));
+
+    let signal = object.signal("done");
+    let received_clone = received.clone();
+    let handle = task::spawn(async move {
+        let (value,): (i64,) = signal.to_future().await;
+        received_clone.set(value);
+    });
+
+    // Runs until the first await, then waits for the emission.
+    assert!(handle.is_pending());
+    assert_eq!(received.get(), 0);
+
+    object.emit_signal("done".into(), &[42.to_variant()]);
+    assert_eq!(received.get(), 42);
+    assert!(!handle.is_pending());
+
+    object.free();
+}
+
+#[itest]
+fn async_task_awaits_sequentially() {
+    let mut object = make_emitter();
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let (done, pair) = (object.signal("done"), object.signal("pair"));
+    let log_clone = log.clone();
+    let handle = task::spawn(async move {
+        let args: Vec<Variant> = pair.await;
+        let args: Vec<i64> = args.iter().map(Variant::to).collect();
+        log_clone.borrow_mut().push(format!("pair {args:?}"));
+
+        let (text,): (GString,) = done.to_future().await;
+        log_clone.borrow_mut().push(format!("done {text}"));
+    });
+
+    // Emitting the second signal first has no effect.
+    object.emit_signal("done".into(), &["early".to_variant()]);
+    assert!(log.borrow().is_empty());
+
+    object.emit_signal("pair".into(), &[1.to_variant(), 2.to_variant()]);
+    object.emit_signal("done".into(), &["late".to_variant()]);
+
+    assert_eq!(*log.borrow(), ["pair [1, 2]", "done late"]);
+    assert!(!handle.is_pending());
+
+    object.free();
+}
+
+#[itest]
+fn async_task_cancel() {
+    let mut object = make_emitter();
+    let resumed = Rc::new(Cell::new(false));
+
+    let signal = object.signal("done");
+    let resumed_clone = resumed.clone();
+    let handle = task::spawn(async move {
+        signal.await;
+        resumed_clone.set(true);
+    });
+
+    let signal = object.signal("done");
+    assert_eq!(signal.connections().len(), 1);
+
+    handle.cancel();
+
+    // Dropping the future removes the connection.
+    assert_eq!(signal.connections().len(), 0);
+
+    object.emit_signal("done".into(), &[]);
+    assert!(!resumed.get());
+
+    object.free();
+}
+
+#[itest]
+fn async_typed_signal_future() {
+    let mut object = make_emitter();
+
+    let mut future = pin!(object.signal("pair").to_future::<(i64, GString)>());
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    object.emit_signal("pair".into(), &[7.to_variant(), "seven".to_variant()]);
+    assert_eq!(
+        poll_once(future.as_mut()),
+        Poll::Ready((7, GString::from("seven")))
+    );
+
+    object.free();
+}
+
+#[itest]
+fn async_fallible_future_object_freed() {
+    let object = make_emitter();
+
+    let mut future = pin!(object.signal("done").to_fallible_future::<()>());
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    // Freeing the object removes the connection, which lets the future fail instead of waiting forever.
+    object.free();
+    assert!(matches!(
+        poll_once(future.as_mut()),
+        Poll::Ready(Err(task::FallibleSignalFutureError::ObjectFreed))
+    ));
+}
+
+#[itest]
+fn async_fallible_future_bad_arguments() {
+    let mut object = make_emitter();
+
+    let mut future = pin!(object.signal("pair").to_fallible_future::<(i64, i64)>());
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    object.emit_signal("pair".into(), &[1.to_variant(), "two".to_variant()]);
+    assert!(matches!(
+        poll_once(future.as_mut()),
+        Poll::Ready(Err(task::FallibleSignalFutureError::BadArguments(_)))
+    ));
+
+    object.free();
+}
+
+#[itest]
+fn async_next_frame() {
+    let resumed = Rc::new(Cell::new(false));
+
+    let resumed_clone = resumed.clone();
+    let handle = task::spawn(async move {
+        task::next_frame().await;
+        resumed_clone.set(true);
+    });
+    assert!(handle.is_pending());
+
+    // Frames don't pass during a test; emit the signal manually instead.
+    emit_process_frame();
+    assert!(resumed.get());
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn async_task_woken_from_other_thread() {
+    let object = make_emitter();
+    let received = Rc::new(Cell::new(0));
+
+    let signal = object.signal("done");
+    let received_clone = received.clone();
+    let handle = task::spawn(async move {
+        let (value,): (i64,) = signal.to_future().await;
+        received_clone.set(value);
+    });
+
+    let id = object.instance_id();
+    std::thread::spawn(move || {
+        let mut object = Gd::<Object>::from_instance_id(id);
+        object.emit_signal("done".into(), &[42.to_variant()]);
+    })
+    .join()
+    .unwrap();
+
+    // The result is queued, but the task is only resumed on the main thread, at the beginning of the next frame.
+    assert!(handle.is_pending());
+    assert_eq!(received.get(), 0);
+
+    emit_process_frame();
+    assert_eq!(received.get(), 42);
+    assert!(!handle.is_pending());
+
+    object.free();
+}
+
+/// Spawns tasks that need time to pass, which is not possible during a Rust test. Checked by `SpecialTests.gd`.
+#[derive(GodotClass)]
+#[class(init)]
+struct AsyncTestTasks {
+    completed: Rc<RefCell<Vec<GString>>>,
+}
+
+#[godot_api]
+impl AsyncTestTasks {
+    #[func]
+    fn start_next_frame(&mut self) {
+        let completed = self.completed.clone();
+        task::spawn(async move {
+            task::next_frame().await;
+            completed.borrow_mut().push("next_frame".into());
+        });
+    }
+
+    #[func]
+    fn start_timer(&mut self, seconds: f64) {
+        let completed = self.completed.clone();
+        task::spawn(async move {
+            task::timer(seconds).await;
+            completed.borrow_mut().push("timer".into());
+        });
+    }
+
+    #[func]
+    fn is_completed(&self, name: GString) -> bool {
+        self.completed.borrow().contains(&name)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn emit_process_frame() {
+    let mut tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    tree.emit_signal("process_frame".into(), &[]);
+}
+
+/// Polls a future once outside of the task runtime.
+fn poll_once<F: Future>(future: std::pin::Pin<&mut F>) -> Poll<F::Output> {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    future.poll(&mut Context::from_waker(&waker))
+}
@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::meta::{FromGodot, ToGodot};
+use godot::builtin::Variant;
+use godot::engine::{INode, IRefCounted, Node, RefCounted};
+use godot::obj::{Base, DynGd, Gd, NewAlloc, NewGd};
+use godot::register::{godot_api, godot_dyn, GodotClass};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn dyn_gd_into_dyn() {
+    let obj = RefcHealth::new_gd();
+    let mut dyn_obj = obj.into_dyn::<dyn Health>();
+
+    assert_eq!(dyn_obj.dyn_bind().hitpoints(), 100);
+
+    dyn_obj.dyn_bind_mut().deal_damage(30);
+    assert_eq!(dyn_obj.dyn_bind().hitpoints(), 70);
+    assert_eq!(dyn_obj.bind().hp, 70);
+}
+
+#[itest]
+fn dyn_gd_try_dynify_from_base() {
+    let node = NodeHealth::new_alloc().upcast::<Node>();
+
+    let mut dyn_node = node
+        .try_dynify::<dyn Health>()
+        .expect("NodeHealth implements Health");
+
+    dyn_node.dyn_bind_mut().deal_damage(5);
+    assert_eq!(dyn_node.dyn_bind().hitpoints(), 15);
+
+    // Methods of the static class are available through Deref.
+    assert_eq!(dyn_node.get_child_count(), 0);
+
+    dyn_node.into_gd().free();
+}
+
+#[itest]
+fn dyn_gd_try_dynify_fails() {
+    let obj = RefCounted::new_gd();
+    let id = obj.instance_id();
+
+    let err = obj
+        .try_dynify::<dyn Health>()
+        .expect_err("RefCounted does not implement Health");
+
+    // Original object is returned.
+    assert_eq!(err.instance_id(), id);
+}
+
+#[itest]
+fn dyn_gd_upcast_and_cast() {
+    let dyn_obj = RefcHealth::new_gd().into_dyn::<dyn Health>();
+    let upcast: DynGd<RefCounted, dyn Health> = dyn_obj.clone().upcast();
+
+    assert_eq!(upcast.dyn_bind().hitpoints(), 100);
+    assert_eq!(upcast.instance_id(), dyn_obj.instance_id());
+
+    let downcast = upcast
+        .try_cast::<RefcHealth>()
+        .expect("dynamic class is RefcHealth");
+
+    assert_eq!(downcast, dyn_obj);
+}
+
+#[itest]
+fn dyn_gd_clone_shares_instance() {
+    let mut dyn_obj = RefcHealth::new_gd().into_dyn::<dyn Health>();
+    let copy = dyn_obj.clone();
+
+    dyn_obj.dyn_bind_mut().deal_damage(1);
+    assert_eq!(copy.dyn_bind().hitpoints(), 99);
+}
+
+#[itest]
+fn dyn_gd_variant_roundtrip() {
+    let dyn_obj = RefcHealth::new_gd().into_dyn::<dyn Health>();
+    let variant = dyn_obj.to_variant();
+
+    let back = DynGd::<RefCounted, dyn Health>::from_variant(&variant);
+    assert_eq!(back.dyn_bind().hitpoints(), 100);
+    assert_eq!(back.instance_id(), dyn_obj.instance_id());
+
+    let plain: Gd<RefCounted> = back.into();
+    assert_eq!(plain.instance_id(), dyn_obj.instance_id());
+}
+
+#[itest]
+fn dyn_gd_variant_wrong_class() {
+    let variant = RefCounted::new_gd().to_variant();
+    let result = DynGd::<RefCounted, dyn Health>::try_from_variant(&variant);
+    assert!(result.is_err());
+
+    let result = DynGd::<RefCounted, dyn Health>::try_from_variant(&Variant::nil());
+    assert!(result.is_err());
+}
+
+#[itest]
+fn dyn_gd_double_bind_panics() {
+    let mut dyn_obj = RefcHealth::new_gd().into_dyn::<dyn Health>();
+    let copy = dyn_obj.clone();
+
+    let _guard = copy.dyn_bind();
+    expect_panic("dyn_bind_mut() while bound", || {
+        let _ = dyn_obj.dyn_bind_mut();
+    });
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Test classes
+
+trait Health {
+    fn hitpoints(&self) -> i32;
+
+    fn deal_damage(&mut self, damage: i32);
+}
+
+#[derive(GodotClass)]
+#[class(base=RefCounted)]
+struct RefcHealth {
+    hp: i32,
+}
+
+#[godot_api]
+impl IRefCounted for RefcHealth {
+    fn init(_base: Base<RefCounted>) -> Self {
+        Self { hp: 100 }
+    }
+}
+
+#[godot_dyn]
+impl Health for RefcHealth {
+    fn hitpoints(&self) -> i32 {
+        self.hp
+    }
+
+    fn deal_damage(&mut self, damage: i32) {
+        self.hp -= damage;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(base=Node)]
+struct NodeHealth {
+    hp: i32,
+}
+
+#[godot_api]
+impl INode for NodeHealth {
+    fn init(_base: Base<Node>) -> Self {
+        Self { hp: 20 }
+    }
+}
+
+#[godot_dyn]
+impl Health for NodeHealth {
+    fn hitpoints(&self) -> i32 {
+        self.hp
+    }
+
+    fn deal_damage(&mut self, damage: i32) {
+        self.hp -= damage;
+    }
+}
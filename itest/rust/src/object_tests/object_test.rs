@@ -13,7 +13,7 @@ use godot::builtin::meta::{FromGodot, ToGodot};
 use godot::builtin::{GString, StringName, Variant, Vector3};
 use godot::engine::utilities::instance_from_id;
 use godot::engine::{
-    file_access, Area2D, Camera3D, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
+    file_access, Area2D, Camera3D, ClassDb, Engine, FileAccess, IRefCounted, Node, Node3D, Object,
     RefCounted,
 };
 use godot::obj::{Base, Gd, Inherits, InstanceId, NewAlloc, NewGd, RawGd};
@@ -1054,6 +1054,68 @@ fn double_use_reference() {
 #[class(no_init, base = EditorPlugin, editor_plugin, tool)]
 struct CustomEditorPlugin;
 
+// Same for inspector plugins. Since the itest doesn't run in the editor, the plugin is not instantiated.
+#[derive(GodotClass)]
+#[class(init, base = EditorInspectorPlugin, inspector_plugin, tool)]
+struct CustomInspectorPlugin;
+
+// Generates a hidden inspector plugin using `CustomEditorProperty` for `value`. It's not added to the editor, but can be checked directly.
+#[derive(GodotClass)]
+#[class(init, base = EditorProperty, tool)]
+struct CustomEditorProperty {
+    base: Base<godot::engine::EditorProperty>,
+}
+
+#[derive(GodotClass)]
+#[class(init, base = Node)]
+struct CustomEditedNode {
+    #[export(editor = CustomEditorProperty)]
+    value: i64,
+}
+
+#[itest]
+fn object_property_editors_plugin() {
+    use godot::engine::IEditorInspectorPlugin;
+
+    let class_name = StringName::from("__CustomEditedNodePropertyEditors");
+    assert!(ClassDb::singleton().class_exists(class_name));
+
+    let plugin = __CustomEditedNodePropertyEditors::new_gd();
+    let edited = CustomEditedNode::new_alloc();
+    let other = Node::new_alloc();
+
+    assert!(plugin.bind().can_handle(edited.clone().upcast()));
+    assert!(!plugin.bind().can_handle(other.clone().upcast()));
+
+    edited.free();
+    other.free();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(abstract, base=Node)]
+struct AbstractNode {
+    base: Base<Node>,
+}
+
+#[itest]
+fn object_abstract_class() {
+    let class_db = ClassDb::singleton();
+    let class_name = StringName::from("AbstractNode");
+
+    assert!(class_db.class_exists(class_name.clone()));
+    assert!(!class_db.can_instantiate(class_name.clone()));
+    assert!(class_db.is_parent_class(class_name, "Node".into()));
+
+    // Rust can still construct instances explicitly, bypassing the engine's constructor.
+    let obj = Gd::from_init_fn(|base| AbstractNode { base });
+    assert!(obj.is_class("AbstractNode".into()));
+    assert!(obj.is_class("Node".into()));
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[itest]
@@ -24,7 +24,8 @@ use crate::register_tests::gen_ffi::PropertyTestsRust;
 #[itest]
 fn property_template_test(ctx: &TestContext) {
     let rust_properties = PropertyTestsRust::new_alloc();
-    let gdscript_properties = ctx.property_tests.clone();
+    // Added to the scene tree by `TestRunner.gd`.
+    let gdscript_properties = ctx.scene_tree.get_node_as::<Node>("/root/PropertyTests");
 
     // Accumulate errors so we can catch all of them in one go.
     let mut errors: Vec<String> = Vec::new();
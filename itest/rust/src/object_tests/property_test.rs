@@ -5,10 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{HashMap, HashSet};
+
 use godot::builtin::meta::{GodotConvert, ToGodot};
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
+use godot::builtin::{
+    array, dict, varray, Color, Dictionary, GString, NodePath, Variant, VariantType,
+};
 use godot::engine::global::{PropertyHint, PropertyUsageFlags};
-use godot::engine::{INode, IRefCounted, Node, Object, RefCounted, Texture};
+use godot::engine::{
+    Camera2D, Camera3D, INode, IRefCounted, Node, Node3D, Object, RefCounted, Texture,
+};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
 use godot::register::property::{Export, PropertyHintInfo, Var};
 use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
@@ -301,6 +307,9 @@ struct CheckAllExports {
 
     #[export(color_no_alpha)]
     color_no_alpha: Color,
+
+    #[export(node_path = (Camera3D, Camera2D))]
+    node_path: NodePath,
 }
 
 #[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
@@ -363,6 +372,43 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug, Default)]
+#[godot(via = GString)]
+pub enum TestStringEnum {
+    #[default]
+    Easy,
+    #[godot(rename = "Very Hard")]
+    Hard,
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+pub struct DeriveExportString {
+    #[export]
+    pub difficulty: TestStringEnum,
+}
+
+#[itest]
+fn derive_export_string_enum() {
+    let class = DeriveExportString::new_gd();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "difficulty".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::String as i32);
+    check_property(&property, "hint", PropertyHint::ENUM.ord());
+    check_property(&property, "hint_string", "Easy,Very Hard");
+
+    let mut class = class.upcast::<Object>();
+    class.set("difficulty".into(), "Very Hard".to_variant());
+    assert_eq!(
+        class.get("difficulty".into()),
+        GString::from("Very Hard").to_variant()
+    );
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -415,6 +461,226 @@ fn export_resource() {
     class.free();
 }
 
+#[derive(GodotClass)]
+#[class(init, base=Node3D)]
+pub struct CustomNode {}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportNode {
+    #[export]
+    pub target: Option<Gd<Node3D>>,
+
+    #[export]
+    pub custom: Option<Gd<CustomNode>>,
+
+    #[export(node_path = (Camera3D, CustomNode))]
+    pub target_path: NodePath,
+
+    #[export(node_path)]
+    pub any_path: NodePath,
+}
+
+#[itest]
+fn export_node() {
+    let class = ExportNode::new_alloc();
+
+    let property = find_property(&class, "target");
+    check_property(&property, "type", VariantType::Object as i32);
+    check_property(&property, "hint", PropertyHint::NODE_TYPE.ord());
+    check_property(&property, "hint_string", "Node3D");
+
+    let property = find_property(&class, "custom");
+    check_property(&property, "type", VariantType::Object as i32);
+    check_property(&property, "hint", PropertyHint::NODE_TYPE.ord());
+    check_property(&property, "hint_string", "CustomNode");
+
+    let property = find_property(&class, "target_path");
+    check_property(&property, "type", VariantType::NodePath as i32);
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "Camera3D,CustomNode");
+
+    let property = find_property(&class, "any_path");
+    check_property(&property, "hint", PropertyHint::NODE_PATH_VALID_TYPES.ord());
+    check_property(&property, "hint_string", "");
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct ExportGroups {
+    #[export_category(name = "Stats")]
+    #[export]
+    pub level: i32,
+
+    #[export_group(name = "Movement", prefix = "move_")]
+    #[export]
+    pub move_speed: f32,
+
+    #[export_subgroup(name = "Jump", prefix = "move_jump_")]
+    #[export]
+    pub move_jump_height: f32,
+
+    #[export_group(name = "")]
+    #[var]
+    pub health: i32,
+}
+
+#[itest]
+fn export_groups() {
+    let class = ExportGroups::new_alloc();
+
+    let property_names: Vec<String> = class
+        .get_property_list()
+        .iter_shared()
+        .map(|property| property.get_or_nil("name").to::<GString>().to_string())
+        .collect();
+
+    let find_property = |name: &str| -> (usize, Dictionary) {
+        let index = property_names
+            .iter()
+            .position(|property_name| property_name == name)
+            .unwrap_or_else(|| panic!("property `{name}` not found"));
+
+        let property = class.get_property_list().get(index);
+        (index, property)
+    };
+
+    let (category_index, category) = find_property("Stats");
+    check_property(&category, "usage", PropertyUsageFlags::CATEGORY.ord());
+    check_property(&category, "type", VariantType::Nil as i32);
+
+    let (group_index, group) = find_property("Movement");
+    check_property(&group, "usage", PropertyUsageFlags::GROUP.ord());
+    check_property(&group, "hint_string", "move_");
+
+    let (subgroup_index, subgroup) = find_property("Jump");
+    check_property(&subgroup, "usage", PropertyUsageFlags::SUBGROUP.ord());
+    check_property(&subgroup, "hint_string", "move_jump_");
+
+    let (ungroup_index, ungroup) = find_property("");
+    check_property(&ungroup, "usage", PropertyUsageFlags::GROUP.ord());
+
+    // Sections must directly precede the first property they contain, in declaration order.
+    assert_eq!(find_property("level").0, category_index + 1);
+    assert_eq!(find_property("move_speed").0, group_index + 1);
+    assert_eq!(find_property("move_jump_height").0, subgroup_index + 1);
+    assert_eq!(find_property("health").0, ungroup_index + 1);
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+pub struct CollectionProperties {
+    #[var]
+    pub numbers: Vec<i64>,
+
+    #[var]
+    pub fixed: [f32; 3],
+
+    #[var]
+    pub tags: HashSet<GString>,
+
+    #[var]
+    pub scores: HashMap<GString, i64>,
+
+    #[var]
+    pub pair: (i64, GString),
+
+    #[export]
+    pub exported_numbers: Vec<i64>,
+
+    #[export]
+    pub exported_pair: (i64, bool),
+}
+
+#[itest]
+fn collection_property_hints() {
+    let class = CollectionProperties::new_gd();
+    let properties = class.get_property_list();
+    let find_property = |name: &str| {
+        properties
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("property `{name}` not found"))
+    };
+
+    // Sequences are typed arrays.
+    for (name, element_type) in [("numbers", "int"), ("fixed", "float"), ("tags", "String")] {
+        let property = find_property(name);
+        check_property(&property, "type", VariantType::Array as i32);
+
+        #[cfg(since_api = "4.2")]
+        {
+            check_property(&property, "hint", PropertyHint::ARRAY_TYPE.ord());
+            check_property(&property, "hint_string", element_type);
+        }
+        #[cfg(before_api = "4.2")]
+        let _ = element_type;
+    }
+
+    // Maps are untyped dictionaries, tuples are untyped arrays.
+    let property = find_property("scores");
+    check_property(&property, "type", VariantType::Dictionary as i32);
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+
+    let property = find_property("pair");
+    check_property(&property, "type", VariantType::Array as i32);
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+
+    let property = find_property("exported_numbers");
+    check_property(&property, "type", VariantType::Array as i32);
+    check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(
+        &property,
+        "hint_string",
+        format!("{}:int", VariantType::Int as i32),
+    );
+
+    let property = find_property("exported_pair");
+    check_property(&property, "type", VariantType::Array as i32);
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+    check_property(&property, "hint_string", "Array");
+}
+
+#[itest]
+fn collection_property_get_set() {
+    let class = CollectionProperties::new_gd();
+    let mut object = class.clone().upcast::<Object>();
+
+    object.set("numbers".into(), array![1, 2, 3].to_variant());
+    object.set("fixed".into(), array![0.5, 1.5, 2.5].to_variant());
+    object.set("tags".into(), array![GString::from("a")].to_variant());
+    object.set("scores".into(), dict! { "a": 7 }.to_variant());
+    object.set("pair".into(), varray![4, "four"].to_variant());
+
+    {
+        let class = class.bind();
+        assert_eq!(class.numbers, vec![1, 2, 3]);
+        assert_eq!(class.fixed, [0.5, 1.5, 2.5]);
+        assert_eq!(class.tags, HashSet::from([GString::from("a")]));
+        assert_eq!(class.scores, HashMap::from([(GString::from("a"), 7)]));
+        assert_eq!(class.pair, (4, GString::from("four")));
+    }
+
+    assert_eq!(
+        object.get("numbers".into()),
+        array![1i64, 2, 3].to_variant()
+    );
+    assert_eq!(object.get("scores".into()), dict! { "a": 7 }.to_variant());
+    assert_eq!(object.get("pair".into()), varray![4, "four"].to_variant());
+}
+
+fn find_property(class: &Gd<ExportNode>, name: &str) -> Dictionary {
+    class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == name.to_variant())
+        .unwrap_or_else(|| panic!("property `{name}` not found"))
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
@@ -7,8 +7,9 @@
 
 use crate::framework::itest;
 use godot::builtin::GString;
-use godot::engine::{Input, Os};
+use godot::engine::{Engine, Input, Object, Os};
 use godot::obj::Gd;
+use godot::register::{godot_api, GodotClass};
 
 #[itest]
 fn singleton_is_unique() {
@@ -43,3 +44,45 @@ fn singleton_is_operational() {
     let read_value = os.get_environment(key);
     assert_eq!(read_value, value);
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// User-defined singletons
+
+#[derive(GodotClass)]
+#[class(init, base=Object, singleton)]
+pub struct UserSingleton {
+    #[init(default = 7)]
+    counter: i32,
+}
+
+#[godot_api]
+impl UserSingleton {
+    #[func]
+    fn increment(&mut self) -> i32 {
+        self.counter += 1;
+        self.counter
+    }
+}
+
+#[itest]
+fn singleton_user_registered() {
+    let engine = Engine::singleton();
+    assert!(engine.has_singleton("UserSingleton".into()));
+
+    let by_name: Gd<Object> = engine
+        .get_singleton("UserSingleton".into())
+        .expect("singleton registered");
+    let typed: Gd<UserSingleton> = UserSingleton::singleton();
+
+    assert_eq!(by_name.instance_id(), typed.instance_id());
+}
+
+#[itest]
+fn singleton_user_is_stateful() {
+    let mut singleton = UserSingleton::singleton();
+    let before = singleton.bind().counter;
+
+    let after = singleton.bind_mut().increment();
+    assert_eq!(after, before + 1);
+    assert_eq!(UserSingleton::singleton().bind().counter, after);
+}
@@ -9,12 +9,13 @@
 
 use crate::framework::{itest, TestContext};
 
-use godot::builtin::meta::ToGodot;
+use godot::builtin::meta::{ClassName, PropertyInfo, ToGodot};
 use godot::builtin::{
-    real, varray, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
-    PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, RealConv,
-    StringName, Variant, VariantArray, Vector2, Vector3,
+    real, varray, Color, Dictionary, GString, PackedByteArray, PackedColorArray,
+    PackedFloat32Array, PackedInt32Array, PackedStringArray, PackedVector2Array,
+    PackedVector3Array, RealConv, StringName, Variant, VariantArray, VariantType, Vector2, Vector3,
 };
+use godot::engine::global::{PropertyHint, PropertyUsageFlags};
 use godot::engine::notify::NodeNotification;
 use godot::engine::resource_loader::CacheMode;
 use godot::engine::{
@@ -78,6 +79,19 @@ impl INode2D for VirtualReadyTest {
     }
 }
 
+#[derive(GodotClass, Debug)]
+#[class(init, base=Node, runtime)]
+struct RuntimeReadyTest {
+    ready_called: bool,
+}
+
+#[godot_api]
+impl INode for RuntimeReadyTest {
+    fn ready(&mut self) {
+        self.ready_called = true;
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass, Debug)]
@@ -282,6 +296,76 @@ impl IRefCounted for SetTest {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[derive(GodotClass)]
+#[class(init)]
+struct PropertyListTest {
+    #[var]
+    hidden: i64,
+    #[var]
+    ranged: i64,
+
+    dynamic: i64,
+}
+
+#[godot_api]
+impl IRefCounted for PropertyListTest {
+    fn get_property(&self, property: StringName) -> Option<Variant> {
+        match String::from(property).as_str() {
+            "dynamic" => Some(self.dynamic.to_variant()),
+            _ => None,
+        }
+    }
+
+    fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+        match String::from(property).as_str() {
+            "dynamic" => {
+                self.dynamic = value.to();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_property_list(&mut self) -> Vec<PropertyInfo> {
+        vec![PropertyInfo {
+            variant_type: VariantType::Int,
+            class_name: ClassName::none(),
+            property_name: "dynamic".into(),
+            hint: PropertyHint::NONE,
+            hint_string: "".into(),
+            usage: PropertyUsageFlags::DEFAULT,
+        }]
+    }
+
+    fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+        match String::from(property).as_str() {
+            "dynamic" => Some(42.to_variant()),
+            _ => None,
+        }
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn validate_property(&self, property: &mut PropertyInfo) {
+        match String::from(&property.property_name).as_str() {
+            "hidden" => property.usage = PropertyUsageFlags::NO_EDITOR,
+            "ranged" => {
+                property.hint = PropertyHint::RANGE;
+                property.hint_string = "0,10".into();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Finds the entry named `name` in the result of `Object::get_property_list()`.
+fn find_property(obj: &Gd<PropertyListTest>, name: &str) -> Option<Dictionary> {
+    obj.get_property_list()
+        .iter_shared()
+        .find(|dict| dict.get("name") == Some(name.to_variant()))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[itest]
 fn test_to_string() {
     let _obj = VirtualMethodTest::new_gd();
@@ -300,6 +384,18 @@ fn test_ready(test_context: &TestContext) {
     assert_eq!(obj.bind().implementation_value, 1);
 }
 
+#[itest]
+fn test_ready_runtime_class(test_context: &TestContext) {
+    let obj = RuntimeReadyTest::new_alloc();
+    assert!(!obj.bind().ready_called);
+
+    // Outside the editor, #[class(runtime)] behaves like any other class.
+    let mut test_node = test_context.scene_tree.clone();
+    test_node.add_child(obj.clone().upcast());
+
+    assert!(obj.bind().ready_called);
+}
+
 #[itest]
 fn test_ready_multiple_fires(test_context: &TestContext) {
     let obj = VirtualReadyTest::new_alloc();
@@ -563,6 +659,54 @@ fn test_set_sets_correct() {
     assert_eq!(obj.bind().settable, 500);
 }
 
+#[itest]
+fn test_get_property_list() {
+    let mut obj = PropertyListTest::new_gd();
+
+    let dynamic = find_property(&obj, "dynamic").expect("dynamic property listed");
+    assert_eq!(dynamic.get("type"), Some(VariantType::Int.to_variant()));
+
+    obj.set("dynamic".into(), 7.to_variant());
+    assert_eq!(obj.get("dynamic".into()), 7.to_variant());
+
+    // Repeated queries allocate and free the list each time.
+    for _ in 0..3 {
+        assert!(find_property(&obj, "dynamic").is_some());
+    }
+}
+
+#[itest]
+fn test_property_get_revert() {
+    let obj = PropertyListTest::new_gd();
+
+    assert!(obj.property_can_revert("dynamic".into()));
+    assert_eq!(obj.property_get_revert("dynamic".into()), 42.to_variant());
+
+    assert!(!obj.property_can_revert("ranged".into()));
+    assert_eq!(obj.property_get_revert("ranged".into()), Variant::nil());
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn test_validate_property() {
+    use godot::obj::{EngineBitfield, EngineEnum};
+
+    let obj = PropertyListTest::new_gd();
+
+    let hidden = find_property(&obj, "hidden").expect("hidden property listed");
+    assert_eq!(
+        hidden.get("usage"),
+        Some(PropertyUsageFlags::NO_EDITOR.ord().to_variant())
+    );
+
+    let ranged = find_property(&obj, "ranged").expect("ranged property listed");
+    assert_eq!(
+        ranged.get("hint"),
+        Some(PropertyHint::RANGE.ord().to_variant())
+    );
+    assert_eq!(ranged.get("hint_string"), Some("0,10".to_variant()));
+}
+
 // Used in `test_collision_object_2d_input_event` in `SpecialTests.gd`.
 #[derive(GodotClass)]
 #[class(init, base = RigidBody2D)]
@@ -174,6 +174,7 @@ godot::sys::plugin_add!(
             register_methods_constants_fn: ::godot::private::ErasedRegisterFn {
                 raw: ::godot::private::callbacks::register_user_methods_constants::<HasOtherConstants>,
             },
+            register_rpcs_fn: None,
         },
         init_level: HasOtherConstants::INIT_LEVEL,
     }
@@ -239,3 +240,177 @@ test_enum_export!(
     #[itest]
     fn bitfield_export_correct_values() { .. }
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Enums registered through #[derive(GodotConvert)]
+
+#[derive(GodotClass)]
+#[class(init)]
+struct HasClassEnums {
+    #[export]
+    direction: Direction,
+}
+
+#[derive(GodotConvert, Var, Export, Default, Eq, PartialEq, Debug)]
+#[godot(via = i64, class = HasClassEnums)]
+enum Direction {
+    #[default]
+    Up,
+    Down,
+    Left = 10,
+    Right,
+}
+
+#[derive(GodotConvert, Var, Export, Eq, PartialEq, Debug)]
+#[godot(via = u32, class = HasClassEnums, bitfield)]
+enum Permission {
+    Read = 1,
+    Write = 2,
+    Execute = 4,
+}
+
+#[godot_api]
+impl HasClassEnums {
+    #[func]
+    fn turn(&mut self, direction: Direction) -> Direction {
+        std::mem::replace(&mut self.direction, direction)
+    }
+
+    #[func]
+    fn has_permission(&self, _permission: Permission) -> bool {
+        true
+    }
+}
+
+#[itest]
+fn class_enum_registered() {
+    let class_name = HasClassEnums::class_name().to_string_name();
+    let expected = [("Up", 0), ("Down", 1), ("Left", 10), ("Right", 11)];
+
+    let db = ClassDb::singleton();
+    assert!(db
+        .class_has_enum_ex(class_name.clone(), "Direction".into())
+        .no_inheritance(true)
+        .done());
+
+    let enumerators = db
+        .class_get_enum_constants_ex(class_name.clone(), "Direction".into())
+        .no_inheritance(true)
+        .done();
+
+    assert_eq!(enumerators.len(), expected.len());
+    for (name, value) in expected {
+        assert!(enumerators.contains(name.into()));
+        assert_eq!(
+            db.class_get_integer_constant(class_name.clone(), name.into()),
+            value
+        );
+    }
+
+    assert!(!db
+        .is_class_enum_bitfield_ex(class_name, "Direction".into())
+        .no_inheritance(true)
+        .done());
+}
+
+#[itest]
+fn class_bitfield_registered() {
+    let class_name = HasClassEnums::class_name().to_string_name();
+
+    let db = ClassDb::singleton();
+    assert!(db
+        .is_class_enum_bitfield_ex(class_name.clone(), "Permission".into())
+        .no_inheritance(true)
+        .done());
+
+    for (name, value) in [("Read", 1), ("Write", 2), ("Execute", 4)] {
+        assert_eq!(
+            db.class_get_integer_constant(class_name.clone(), name.into()),
+            value
+        );
+    }
+}
+
+#[itest]
+fn class_enum_type_info() {
+    use godot::engine::global::{PropertyHint, PropertyUsageFlags};
+
+    let class_name = HasClassEnums::class_name().to_string_name();
+    let db = ClassDb::singleton();
+
+    let find_method = |name: &str| {
+        db.class_get_method_list_ex(class_name.clone())
+            .no_inheritance(true)
+            .done()
+            .iter_shared()
+            .find(|method| method.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("method `{name}` not found"))
+    };
+
+    let turn = find_method("turn");
+    let arg = turn
+        .get_or_nil("args")
+        .to::<VariantArray>()
+        .get(0)
+        .to::<Dictionary>();
+    let ret = turn.get_or_nil("return").to::<Dictionary>();
+
+    for info in [arg, ret] {
+        assert_eq!(
+            info.get_or_nil("class_name"),
+            "HasClassEnums.Direction".to_variant()
+        );
+        assert_eq!(
+            info.get_or_nil("type"),
+            (VariantType::Int as i32).to_variant()
+        );
+
+        let usage = info.get_or_nil("usage").to::<u64>();
+        assert_ne!(usage & PropertyUsageFlags::CLASS_IS_ENUM.ord(), 0);
+    }
+
+    let has_permission = find_method("has_permission");
+    let arg = has_permission
+        .get_or_nil("args")
+        .to::<VariantArray>()
+        .get(0)
+        .to::<Dictionary>();
+    assert_eq!(
+        arg.get_or_nil("class_name"),
+        "HasClassEnums.Permission".to_variant()
+    );
+
+    let usage = arg.get_or_nil("usage").to::<u64>();
+    assert_ne!(usage & PropertyUsageFlags::CLASS_IS_BITFIELD.ord(), 0);
+
+    // Exported property carries both the enum hint and the qualified name.
+    let property = db
+        .class_get_property_list_ex(class_name)
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|property| property.get_or_nil("name") == "direction".to_variant())
+        .expect("property `direction` not found");
+
+    assert_eq!(
+        property.get_or_nil("class_name"),
+        "HasClassEnums.Direction".to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint"),
+        PropertyHint::ENUM.ord().to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        "Up:0,Down:1,Left:10,Right:11".to_variant()
+    );
+}
+
+#[itest]
+fn class_enum_roundtrip() {
+    let mut obj = HasClassEnums::new_gd();
+
+    let previous = obj.call("turn".into(), &[Direction::Left.to_variant()]);
+    assert_eq!(previous, Direction::Up.to_variant());
+    assert_eq!(obj.bind().direction, Direction::Left);
+}
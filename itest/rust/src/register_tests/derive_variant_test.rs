@@ -7,8 +7,8 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::meta::ToGodot;
-use godot::builtin::{GString, Vector2};
+use godot::builtin::meta::{FromGodot, ToGodot};
+use godot::builtin::{dict, Dictionary, GString, StringName, Vector2};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -86,6 +86,48 @@ fn enum_inty() {
     assert_eq!(EnumInty::E.to_godot(), 2);
 }
 
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = StringName)]
+enum EnumStringNamey {
+    Idle,
+    #[godot(rename = "run_fast")]
+    Running,
+}
+
+#[itest]
+fn enum_string_name() {
+    roundtrip(EnumStringNamey::Idle);
+    roundtrip(EnumStringNamey::Running);
+
+    assert_eq!(EnumStringNamey::Idle.to_godot(), StringName::from("Idle"));
+    assert_eq!(
+        EnumStringNamey::Running.to_godot(),
+        StringName::from("run_fast")
+    );
+    assert_eq!(
+        EnumStringNamey::from_godot(StringName::from("run_fast")),
+        EnumStringNamey::Running
+    );
+}
+
+#[itest]
+fn enum_string_invalid_name() {
+    let err = EnumStringNamey::try_from_godot(StringName::from("Running"))
+        .expect_err("Rust name is not accepted when renamed");
+
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        r#"invalid EnumStringNamey variant, expected one of: "Idle", "run_fast""#
+    );
+    assert_eq!(err.value(), Some(&StringName::from("Running").to_variant()));
+
+    let err = EnumStringy::try_from_godot(GString::from("E")).expect_err("unknown name");
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        r#"invalid EnumStringy variant, expected one of: "A", "B", "C", "D""#
+    );
+}
+
 macro_rules! test_inty {
     ($T:ident, $test_name:ident, $class_name:ident) => {
         #[derive(GodotConvert, Clone, PartialEq, Debug)]
@@ -110,3 +152,164 @@ test_inty!(i64, test_enum_i64, EnumI64);
 test_inty!(u8, test_enum_u8, EnumU8);
 test_inty!(u16, test_enum_u16, EnumU16);
 test_inty!(u32, test_enum_u32, EnumU32);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Dictionary conversion tests
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+struct DictStruct {
+    name: GString,
+    #[godot(rename = "pos")]
+    position: Vector2,
+    #[godot(default)]
+    level: i64,
+    #[godot(default = 3)]
+    lives: i32,
+    #[godot(skip)]
+    cache: Vec<u8>,
+    nested: EnumInty,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary)]
+enum DictEnum {
+    Unit,
+    Tuple(i64, GString),
+    Struct {
+        inner: DictStruct,
+        #[godot(default)]
+        flag: bool,
+    },
+    #[godot(rename = "renamed")]
+    Renamed,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(dictionary, tag = "kind")]
+enum DictEnumCustomTag {
+    A { value: i64 },
+}
+
+fn make_dict_struct() -> DictStruct {
+    DictStruct {
+        name: "Godette".into(),
+        position: Vector2::new(1.0, 2.0),
+        level: 7,
+        lives: 1,
+        cache: vec![],
+        nested: EnumInty::B,
+    }
+}
+
+#[itest]
+fn dictionary_struct_roundtrip() {
+    roundtrip(make_dict_struct());
+
+    let dict = make_dict_struct().to_godot();
+    let expected = dict! {
+        "name": "Godette",
+        "pos": Vector2::new(1.0, 2.0),
+        "level": 7,
+        "lives": 1,
+        "nested": 11,
+    };
+
+    assert_eq!(dict, expected);
+}
+
+#[itest]
+fn dictionary_struct_skip_and_default() {
+    let mut obj = make_dict_struct();
+    obj.cache = vec![1, 2, 3];
+
+    let back = DictStruct::from_godot(obj.to_godot());
+    assert_eq!(back.cache, Vec::<u8>::new());
+
+    let dict = dict! {
+        "name": "Godot",
+        "pos": Vector2::ZERO,
+        "nested": 10,
+        "unknown": "ignored",
+    };
+
+    let obj = DictStruct::from_godot(dict);
+    assert_eq!(obj.level, 0);
+    assert_eq!(obj.lives, 3);
+    assert_eq!(obj.nested, EnumInty::A);
+}
+
+#[itest]
+fn dictionary_struct_errors() {
+    let mut dict = make_dict_struct().to_godot();
+    dict.remove("pos");
+
+    let err = DictStruct::try_from_godot(dict).expect_err("missing key");
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        "DictStruct: missing key `pos`"
+    );
+
+    let mut dict = make_dict_struct().to_godot();
+    dict.set("name", 12);
+
+    let err = DictStruct::try_from_godot(dict).expect_err("mistyped key");
+    assert!(err
+        .cause()
+        .unwrap()
+        .to_string()
+        .starts_with("DictStruct: invalid value for key `name`"));
+    assert_eq!(err.value(), Some(&12i64.to_variant()));
+}
+
+#[itest]
+fn dictionary_enum_roundtrip() {
+    roundtrip(DictEnum::Unit);
+    roundtrip(DictEnum::Tuple(-5, "text".into()));
+    roundtrip(DictEnum::Struct {
+        inner: make_dict_struct(),
+        flag: true,
+    });
+    roundtrip(DictEnum::Renamed);
+    roundtrip(DictEnumCustomTag::A { value: 42 });
+
+    assert_eq!(DictEnum::Unit.to_godot(), dict! { "type": "Unit" });
+    assert_eq!(DictEnum::Renamed.to_godot(), dict! { "type": "renamed" });
+    assert_eq!(
+        DictEnum::Tuple(-5, "text".into()).to_godot(),
+        dict! { "type": "Tuple", "0": -5, "1": "text" }
+    );
+    assert_eq!(
+        DictEnumCustomTag::A { value: 42 }.to_godot(),
+        dict! { "kind": "A", "value": 42 }
+    );
+}
+
+#[itest]
+fn dictionary_enum_errors() {
+    let err = DictEnum::try_from_godot(Dictionary::new()).expect_err("missing tag");
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        "DictEnum: missing key `type`"
+    );
+
+    let err = DictEnum::try_from_godot(dict! { "type": "Other" }).expect_err("unknown tag");
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        "DictEnum: unknown variant `Other` for key `type`"
+    );
+
+    let err =
+        DictEnum::try_from_godot(dict! { "type": "Tuple", "0": 1 }).expect_err("missing field");
+    assert_eq!(
+        err.cause().unwrap().to_string(),
+        "DictEnum: missing key `1`"
+    );
+
+    // Nested errors mention both the outer key and the inner one.
+    let err = DictEnum::try_from_godot(dict! { "type": "Struct", "inner": Dictionary::new() })
+        .expect_err("nested missing field");
+    let message = err.cause().unwrap().to_string();
+    assert!(message.starts_with("DictEnum: invalid value for key `inner`"));
+    assert!(message.contains("DictStruct: missing key `name`"));
+}
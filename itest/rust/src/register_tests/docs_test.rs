@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Only enabled if docs are collected.
+#![cfg(feature = "register-docs")]
+
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// Class with `docs`.
+///
+/// Second paragraph.
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DocumentedNode {
+    /// Current health.
+    #[var]
+    health: i64,
+
+    #[var]
+    target: Option<Gd<Node>>,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl DocumentedNode {
+    /// Health when spawned.
+    #[constant]
+    const MAX_HEALTH: i64 = 100;
+
+    /// Emitted when `target` changes.
+    #[signal]
+    fn target_changed(target: Gd<Node>);
+
+    /// Looks for a target within `range`.
+    #[func]
+    fn find_target(&self, range: f32) -> Option<Gd<Node>> {
+        let _ = range;
+        self.target.clone()
+    }
+
+    #[func]
+    fn duplicate_self(&self) -> Gd<Self> {
+        DocumentedNode::new_alloc()
+    }
+
+    #[func]
+    fn reset(&mut self) {
+        self.health = Self::MAX_HEALTH;
+    }
+}
+
+fn documented_node_xml() -> String {
+    let class_name = format!(r#"<class name="{}""#, DocumentedNode::class_name());
+
+    godot::private::gather_xml_docs()
+        .find(|xml| xml.contains(&class_name))
+        .expect("docs of DocumentedNode are registered")
+}
+
+#[itest]
+fn docs_class_description() {
+    let xml = documented_node_xml();
+
+    assert!(xml.contains(r#"inherits="Node""#));
+    assert!(xml.contains("<brief_description>Class with [code]docs[/code].</brief_description>"));
+    assert!(xml
+        .contains("<description>Class with [code]docs[/code].\n\nSecond paragraph.</description>"));
+}
+
+#[itest]
+fn docs_godot_type_names() {
+    let xml = documented_node_xml();
+
+    // Rust types are shown as the Godot types they are converted to.
+    assert!(xml.contains(r#"<member name="health" type="int">Current health.</member>"#));
+    assert!(xml.contains(r#"<member name="target" type="Node"></member>"#));
+    assert!(xml.contains(
+        r#"<method name="find_target"><return type="Node"/><param index="0" name="range" type="float"/><description>Looks for a target within [code]range[/code].</description></method>"#
+    ));
+    assert!(xml.contains(r#"<method name="duplicate_self"><return type="DocumentedNode"/>"#));
+    assert!(xml.contains(r#"<method name="reset"><return type="void"/>"#));
+    assert!(xml
+        .contains(r#"<signal name="target_changed"><param index="0" name="target" type="Node"/>"#));
+    assert!(
+        xml.contains(r#"<constant name="MAX_HEALTH" value="100">Health when spawned.</constant>"#)
+    );
+}
@@ -11,6 +11,7 @@
 use crate::framework::itest;
 use godot::engine::ClassDb;
 use godot::prelude::*;
+use std::collections::HashMap;
 
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
@@ -33,6 +34,66 @@ impl FuncObj {
         GString::from("static")
     }
 
+    #[func]
+    fn with_defaults(
+        &self,
+        required: i64,
+        #[opt(default = GString::from("default"))] text: GString,
+        #[opt(default = 2.5)] scale: f32,
+    ) -> VariantArray {
+        varray![required, text, scale]
+    }
+
+    #[func]
+    fn static_with_default(#[opt(default = 7)] value: i32) -> i32 {
+        value
+    }
+
+    const DEFAULT_COUNT: i64 = 3;
+
+    #[func]
+    fn with_self_default(&self, #[opt(default = Self::DEFAULT_COUNT)] count: i64) -> i64 {
+        count
+    }
+
+    #[func]
+    fn varargs_join(&self, separator: GString, args: &[Variant]) -> GString {
+        let parts = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        parts.join(&separator.to_string()).into()
+    }
+
+    #[func]
+    fn varargs_count(args: &[Variant]) -> i64 {
+        args.len() as i64
+    }
+
+    // Parameter names and lifetimes must not interfere with the generated code.
+    #[func]
+    fn varargs_first(&self, varargs: i64, rest: &'_ [godot::builtin::Variant]) -> Variant {
+        rest.first()
+            .cloned()
+            .unwrap_or_else(|| varargs.to_variant())
+    }
+
+    #[func]
+    fn sum_numbers(&self, numbers: Vec<i64>) -> i64 {
+        numbers.iter().sum()
+    }
+
+    #[func]
+    fn count_words(&self, words: Vec<GString>) -> HashMap<GString, i64> {
+        let mut counts = HashMap::new();
+        for word in words {
+            *counts.entry(word).or_default() += 1;
+        }
+        counts
+    }
+
+    #[func]
+    fn swap_pair(pair: (i64, GString)) -> (GString, i64) {
+        (pair.1, pair.0)
+    }
+
     #[cfg(all())]
     fn returns_hello_world(&self) -> GString {
         GString::from("Hello world!")
@@ -294,6 +355,195 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_default_parameters() {
+    let mut obj = FuncObj::new_gd();
+
+    let result = obj.call("with_defaults".into(), &[1.to_variant()]);
+    assert_eq!(result, varray![1, "default", 2.5].to_variant());
+
+    let result = obj.call(
+        "with_defaults".into(),
+        &[1.to_variant(), "given".to_variant()],
+    );
+    assert_eq!(result, varray![1, "given", 2.5].to_variant());
+
+    let result = obj.call(
+        "with_defaults".into(),
+        &[1.to_variant(), "given".to_variant(), 4.0f32.to_variant()],
+    );
+    assert_eq!(result, varray![1, "given", 4.0].to_variant());
+
+    // Rust calls still pass all arguments.
+    assert_eq!(
+        obj.bind().with_defaults(3, "x".into(), 1.0),
+        varray![3, "x", 1.0]
+    );
+}
+
+#[itest]
+fn func_default_parameters_missing_required() {
+    let mut obj = FuncObj::new_gd();
+
+    let call_error = obj
+        .try_call("with_defaults".into(), &[])
+        .expect_err("required parameter must be passed");
+    assert!(
+        call_error
+            .to_string()
+            .ends_with("function has 3 parameters, but received 0 arguments"),
+        "unexpected error: {call_error}"
+    );
+}
+
+#[itest]
+fn func_default_parameters_static() {
+    let mut obj = FuncObj::new_gd();
+
+    assert_eq!(obj.call("static_with_default".into(), &[]), 7.to_variant());
+    assert_eq!(
+        obj.call("static_with_default".into(), &[3.to_variant()]),
+        3.to_variant()
+    );
+}
+
+#[itest]
+fn func_default_parameters_self() {
+    let mut obj = FuncObj::new_gd();
+
+    assert_eq!(obj.call("with_self_default".into(), &[]), 3.to_variant());
+    assert_eq!(
+        obj.call("with_self_default".into(), &[5.to_variant()]),
+        5.to_variant()
+    );
+}
+
+#[itest]
+fn func_default_parameters_registered() {
+    let method = ClassDb::singleton()
+        .class_get_method_list_ex(FuncObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|method| method.get_or_nil("name") == "with_defaults".to_variant())
+        .expect("method `with_defaults` not found");
+
+    let default_args = method.get_or_nil("default_args").to::<VariantArray>();
+    assert_eq!(default_args, varray!["default", 2.5]);
+}
+
+#[itest]
+fn func_varargs() {
+    let mut obj = FuncObj::new_gd();
+
+    let result = obj.call(
+        "varargs_join".into(),
+        &[
+            "-".to_variant(),
+            "a".to_variant(),
+            1.to_variant(),
+            true.to_variant(),
+        ],
+    );
+    assert_eq!(result, "a-1-true".to_variant());
+
+    let result = obj.call("varargs_join".into(), &["-".to_variant()]);
+    assert_eq!(result, "".to_variant());
+
+    assert_eq!(obj.call("varargs_count".into(), &[]), 0.to_variant());
+    assert_eq!(
+        obj.call("varargs_count".into(), &[1.to_variant(), 2.to_variant()]),
+        2.to_variant()
+    );
+}
+
+#[itest]
+fn func_varargs_with_lifetime() {
+    let mut obj = FuncObj::new_gd();
+
+    assert_eq!(
+        obj.call("varargs_first".into(), &[1.to_variant()]),
+        1.to_variant()
+    );
+    assert_eq!(
+        obj.call(
+            "varargs_first".into(),
+            &[1.to_variant(), "a".to_variant(), "b".to_variant()]
+        ),
+        "a".to_variant()
+    );
+}
+
+#[itest]
+fn func_varargs_too_few_args() {
+    let mut obj = FuncObj::new_gd();
+
+    let call_error = obj
+        .try_call("varargs_join".into(), &[])
+        .expect_err("fixed parameter must be passed");
+    assert!(
+        call_error
+            .to_string()
+            .ends_with("function has at least 1 parameter, but received 0 arguments"),
+        "unexpected error: {call_error}"
+    );
+}
+
+#[itest]
+fn func_varargs_registered() {
+    use godot::engine::global::MethodFlags;
+
+    let method = ClassDb::singleton()
+        .class_get_method_list_ex(FuncObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|method| method.get_or_nil("name") == "varargs_join".to_variant())
+        .expect("method `varargs_join` not found");
+
+    let flags = method.get_or_nil("flags").to::<u64>();
+    assert_ne!(flags & MethodFlags::VARARG.ord(), 0);
+
+    let args = method.get_or_nil("args").to::<VariantArray>();
+    assert_eq!(args.len(), 1, "only fixed parameters are registered");
+}
+
+#[itest]
+fn func_collection_parameters() {
+    let mut obj = FuncObj::new_gd();
+
+    let result = obj.call("sum_numbers".into(), &[array![1, 2, 3].to_variant()]);
+    assert_eq!(result, 6.to_variant());
+
+    let result = obj.call(
+        "count_words".into(),
+        &[array![GString::from("a"), GString::from("b"), GString::from("a")].to_variant()],
+    );
+    assert_eq!(
+        result.to::<HashMap<GString, i64>>(),
+        HashMap::from([(GString::from("a"), 2), (GString::from("b"), 1)])
+    );
+
+    let result = obj.call("swap_pair".into(), &[varray![1, "one"].to_variant()]);
+    assert_eq!(result, varray!["one", 1].to_variant());
+}
+
+#[itest]
+fn func_collection_parameters_invalid() {
+    let mut obj = FuncObj::new_gd();
+
+    // Tuples must have the exact length.
+    let call_error = obj
+        .try_call("swap_pair".into(), &[varray![1].to_variant()])
+        .expect_err("too short tuple must be rejected");
+    assert!(
+        call_error
+            .to_string()
+            .contains("expected array of length 2, got length 1"),
+        "unexpected error: {call_error}"
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
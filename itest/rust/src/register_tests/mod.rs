@@ -7,9 +7,11 @@
 
 mod constant_test;
 mod derive_variant_test;
+mod docs_test;
 mod func_test;
 mod gdscript_ffi_test;
 mod option_ffi_test;
+mod rpc_test;
 mod var_test;
 
 #[cfg(since_api = "4.3")]
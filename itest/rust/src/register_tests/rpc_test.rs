@@ -0,0 +1,146 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::{itest, suppress_godot_print, TestContext};
+use godot::builtin::meta::registration::rpc_config::RpcConfig;
+use godot::engine::global::Error;
+use godot::engine::multiplayer_api::RpcMode;
+use godot::engine::multiplayer_peer::TransferMode;
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct RpcNode {
+    last_damage: i64,
+    base: Base<Node>,
+}
+
+const SHARED_CONFIG: RpcConfig = RpcConfig {
+    rpc_mode: RpcMode::ANY_PEER,
+    transfer_mode: TransferMode::UNRELIABLE_ORDERED,
+    call_local: true,
+    channel: 1,
+};
+
+#[godot_api]
+impl RpcNode {
+    #[func]
+    #[rpc]
+    fn default_args(&mut self) {}
+
+    #[func]
+    #[rpc(any_peer, call_local, unreliable, channel = 2)]
+    fn all_args(&mut self, damage: i64, _source: GString) {
+        self.last_damage = damage;
+    }
+
+    #[func(rename = renamed_rpc)]
+    #[rpc(authority, reliable, call_remote)]
+    fn explicit_defaults(&self) {}
+
+    #[func(gd_self)]
+    #[rpc(unreliable_ordered)]
+    fn with_gd_self(_this: Gd<Self>) {}
+
+    #[func]
+    #[rpc(config = SHARED_CONFIG)]
+    fn with_config(&mut self) {}
+}
+
+#[itest]
+fn rpc_methods_registered() {
+    let class_name = StringName::from("RpcNode");
+
+    for method in [
+        "default_args",
+        "all_args",
+        "renamed_rpc",
+        "with_gd_self",
+        "with_config",
+    ] {
+        assert!(
+            ClassDb::singleton().class_has_method(class_name.clone(), method.into()),
+            "method `{method}` should be registered"
+        );
+    }
+}
+
+#[itest]
+fn rpc_config_construction() {
+    // RPCs are configured during construction; make sure that this works for both Rust- and Godot-side instantiation.
+    let node = RpcNode::new_alloc();
+    node.free();
+
+    let node = ClassDb::singleton()
+        .instantiate("RpcNode".into())
+        .to::<Gd<Node>>();
+    node.free();
+}
+
+#[itest]
+fn rpc_config_applied(ctx: &TestContext) {
+    // Inside the tree, nodes use the default `OfflineMultiplayerPeer`. Calling an RPC on the own peer ID only succeeds if the method
+    // is configured with `call_local`, in which case it is run directly. Godot reports an error for other methods.
+    let mut tree_root = ctx.scene_tree.clone();
+    let rust_node = RpcNode::new_alloc().upcast::<Node>();
+    let godot_node = ClassDb::singleton()
+        .instantiate("RpcNode".into())
+        .to::<Gd<Node>>();
+
+    for mut node in [rust_node, godot_node] {
+        tree_root.add_child(node.clone());
+        let own_id = node.get_multiplayer().unwrap().get_unique_id() as i64;
+
+        let args = [17.to_variant(), "source".to_variant()];
+        let result = node.rpc_id(own_id, "all_args".into(), &args);
+        assert_eq!(result, Error::OK);
+        assert_eq!(node.clone().cast::<RpcNode>().bind().last_damage, 17);
+
+        let result = node.rpc_id(own_id, "with_config".into(), &[]);
+        assert_eq!(result, Error::OK);
+
+        for method in ["default_args", "renamed_rpc", "with_gd_self"] {
+            let mut result = Error::OK;
+            suppress_godot_print(|| result = node.rpc_id(own_id, method.into(), &[]));
+            assert_eq!(result, Error::ERR_INVALID_PARAMETER, "{method}");
+        }
+
+        tree_root.remove_child(node.clone());
+        node.free();
+    }
+}
+
+#[itest]
+fn rpc_config_dictionary() {
+    let dict = RpcConfig::default().to_dictionary();
+    assert_eq!(dict.get("rpc_mode"), Some(RpcMode::AUTHORITY.to_variant()));
+    assert_eq!(
+        dict.get("transfer_mode"),
+        Some(TransferMode::RELIABLE.to_variant())
+    );
+    assert_eq!(dict.get("call_local"), Some(false.to_variant()));
+    assert_eq!(dict.get("channel"), Some(0.to_variant()));
+
+    let dict = SHARED_CONFIG.to_dictionary();
+    assert_eq!(dict.get("rpc_mode"), Some(RpcMode::ANY_PEER.to_variant()));
+    assert_eq!(
+        dict.get("transfer_mode"),
+        Some(TransferMode::UNRELIABLE_ORDERED.to_variant())
+    );
+    assert_eq!(dict.get("call_local"), Some(true.to_variant()));
+    assert_eq!(dict.get("channel"), Some(1.to_variant()));
+}
+
+#[itest]
+fn rpc_helpers_generated() {
+    // Helpers can't be invoked without a multiplayer peer, but their signatures are checked here.
+    let _: fn(&mut RpcNode, i64, GString) -> godot::engine::global::Error = RpcNode::rpc_all_args;
+    let _: fn(&mut RpcNode, i64, i64, GString) -> godot::engine::global::Error =
+        RpcNode::rpc_id_all_args;
+    let _: fn(&mut RpcNode) -> godot::engine::global::Error = RpcNode::rpc_explicit_defaults;
+}